@@ -1,13 +1,20 @@
 /** The state object for one of the components available. */
 export type ComponentStateJson =
     { BlankSpace: BlankSpaceComponentStateJson } |
+    { Clock: ClockComponentStateJson } |
+    { Counter: CounterComponentStateJson } |
     { CurrentComparison: CurrentComparisonComponentStateJson } |
     { CurrentPace: CurrentPaceComponentStateJson } |
+    { Custom: CustomComponentStateJson } |
     { Delta: DeltaComponentStateJson } |
     { DetailedTimer: DetailedTimerComponentStateJson } |
     { Graph: GraphComponentStateJson } |
+    { Image: ImageComponentStateJson } |
+    { Notes: NotesComponentStateJson } |
     { PossibleTimeSave: PossibleTimeSaveComponentStateJson } |
     { PreviousSegment: PreviousSegmentComponentStateJson } |
+    { RunStatistics: RunStatisticsComponentStateJson } |
+    { SegmentHistory: SegmentHistoryComponentStateJson } |
     { Separator: null } |
     { Splits: SplitsComponentStateJson } |
     { SumOfBest: SumOfBestComponentStateJson } |
@@ -44,12 +51,67 @@ export type ListGradient =
 /** Describes the Alignment of the Title in the Title Component. */
 export type Alignment = "Auto" | "Left" | "Center";
 
+/** Describes how an image is scaled to fill the area it is shown in. */
+export type ImageScaling = "Stretch" | "Fit";
+
+/** Describes how an image, such as a background image, is drawn. */
+export interface ImageStyle {
+    /**
+     * The opacity of the image, ranging from 0 (fully transparent) to 1 (fully
+     * opaque).
+     */
+    opacity: number,
+    /**
+     * The amount of blur to apply to the image, ranging from 0 (no blur) to 1
+     * (maximum blur).
+     */
+    blur: number,
+    /** How the image is scaled to fill the area it is shown in. */
+    scaling: ImageScaling,
+}
+
+/**
+ * The state object of a component within a layout along with the background
+ * image the layout shows behind it.
+ */
+export type LayoutComponentStateJson = ComponentStateJson & {
+    /**
+     * The unique ID of the background image. As the background image itself
+     * is only provided whenever it changes, renderers can use the ID to look
+     * up the image they cached. This is `null` if there is no background
+     * image.
+     */
+    background_image_id: number | null,
+    /**
+     * The background image to show behind the component. This value is only
+     * specified whenever the background image changes. If you explicitly want
+     * to query this value, remount the layout. The image is provided as a Data
+     * URL. An empty string means that the background image was removed.
+     */
+    background_image_change: string | null,
+    /** Describes how the background image is drawn. */
+    background_image_style: ImageStyle,
+};
+
 /** The state object describes the information to visualize for the layout. */
 export interface LayoutStateJson {
-    /** The state objects for all of the components in the layout. */
-    components: ComponentStateJson[],
+    /**
+     * The state objects for all of the components in the layout that are
+     * shown, along with their background images.
+     */
+    components: LayoutComponentStateJson[],
     /** The background to show behind the layout. */
     background: Gradient,
+    /**
+     * The background image to show on top of the background of the layout.
+     * This value is only specified whenever the background image changes. If
+     * you explicitly want to query this value, remount the layout. The image
+     * is provided as a Data URL. An empty string means that the background
+     * image was removed.
+     */
+    background_image_change: string | null,
+    /** Describes how the background image is drawn. */
+    background_image_style: ImageStyle,
     /** The color of thin separators. */
     thin_separators_color: Color,
     /** The color of normal separators. */
@@ -115,8 +177,16 @@ export interface TimerComponentStateJson {
     bottom_color: Color,
     /** The height of the timer. */
     height: number,
+    /**
+     * Specifies whether the time of a countdown ran out. This is never the
+     * case when counting up.
+     */
+    is_time_up: boolean,
 }
 
+/** Describes which time the Timer Component shows. */
+export type TimerMode = "CountUp" | "Countdown" | "RemainingToBeatComparison";
+
 /** The state object describes the information to visualize for this component. */
 export interface TitleComponentStateJson {
     /** The background shown behind the component. */
@@ -207,6 +277,17 @@ export interface SplitsComponentStateJson {
      * being the current segment.
      */
     current_split_gradient: Gradient,
+    /** Specifies whether subsplits are supposed to be indented. */
+    indent_subsplits: boolean,
+    /** The gradient to show behind subsplits. */
+    subsplit_gradient: Gradient,
+    /** The gradient to show behind the header rows of sections. */
+    section_header_gradient: Gradient,
+    /**
+     * The color of the names of the sections in the header rows. If `null` is
+     * specified, the color is taken from the layout.
+     */
+    section_header_text_color: Color | null,
 }
 
 /**
@@ -232,8 +313,13 @@ export interface SplitsComponentIconChangeJson {
 
 /** The state object that describes a single segment's information to visualize. */
 export interface SplitStateJson {
-    /** The name of the segment. */
+    /**
+     * The name of the segment. For sections and section headers this is the
+     * name of the section.
+     */
     name: string,
+    /** Describes what this row represents. */
+    kind: SplitKind,
     /**
      * The state of each column from right to left. The amount of columns is
      * not guaranteed to be the same across different splits.
@@ -241,18 +327,28 @@ export interface SplitStateJson {
     columns: SplitColumnState[],
     /**
      * Describes if this segment is the segment the active attempt is currently
-     * on.
+     * on. For collapsed sections this describes if the active attempt is
+     * currently on any of its segments.
      */
     is_current_split: boolean,
     /**
      * The index of the segment based on all the segments of the run. This may
      * differ from the index of this `SplitStateJson` in the
      * `SplitsComponentStateJson` object, as there can be a scrolling window,
-     * showing only a subset of segments. Each index is guaranteed to be unique.
+     * showing only a subset of segments. For collapsed sections this is the
+     * index of the last segment of the section. Each index is guaranteed to be
+     * unique.
      */
     index: number,
 }
 
+/**
+ * Describes what a single row of the Splits Component represents. Sections
+ * are collapsed into a single row, while the header rows of sections are shown
+ * above all of their subsplits.
+ */
+export type SplitKind = "Segment" | "Subsplit" | "SectionHeader" | "Section";
+
 /** Describes the state of a single segment's column to visualize. */
 export interface SplitColumnState {
     /** The value shown in the column. */
@@ -285,6 +381,29 @@ export interface PreviousSegmentComponentStateJson {
      * two separate rows.
      */
     display_two_rows: boolean,
+    /**
+     * The deltas to each of the comparisons chosen in the settings that
+     * exist. If this is not empty, these are shown in separate rows instead
+     * of the single delta described by the other fields.
+     */
+    comparisons: PreviousSegmentComparisonStateJson[],
+}
+
+/** The state object describing the delta to a single comparison. */
+export interface PreviousSegmentComparisonStateJson {
+    /** The label's text, including the shortened name of the comparison. */
+    text: string,
+    /**
+     * The shortened name of the comparison. This can be shown instead of the
+     * label's text if there is not enough space.
+     */
+    comparison: string,
+    /** The delta (and possibly the possible time save). */
+    time: string,
+    /** The semantic coloring information the delta time carries. */
+    semantic_color: SemanticColor,
+    /** The visual color of the delta time. */
+    visual_color: Color,
 }
 
 /** The state object describes the information to visualize for this component. */
@@ -398,8 +517,40 @@ export interface GraphComponentStateJson {
     best_segment_color: Color,
     /** The height of the graph. */
     height: number,
+    /**
+     * Describes what the graph visualizes. In the Histogram mode, the bars are
+     * visualized instead of the points.
+     */
+    mode: GraphMode,
+    /**
+     * The lines of the comparisons and attempts overlaid on top of the graph.
+     * Connect the points of each of them to visualize them. This is only used
+     * in the Delta mode.
+     */
+    overlays: GraphComponentStatePointJson[][],
+    /**
+     * The color of the lines of the comparisons and attempts overlaid on top of
+     * the graph.
+     */
+    overlay_lines_color: Color,
+    /**
+     * The heights of the histogram's bars from the fastest to the slowest
+     * segment times. The bars evenly divide the width of the graph and grow
+     * from the bottom, or the top if the graph is flipped. This is only used in
+     * the Histogram mode.
+     */
+    bars: number[],
+    /**
+     * The index of the bar that contains the most recent segment time. Use the
+     * graph lines color for it and the complete fill color for all the other
+     * bars.
+     */
+    highlighted_bar: number | null,
 }
 
+/** Describes what the Graph Component visualizes. */
+export type GraphMode = "Delta" | "Histogram" | "PersonalBestProgression";
+
 /** Describes a point on the graph to visualize. */
 export interface GraphComponentStatePointJson {
     /** The x coordinate of the point. */
@@ -515,6 +666,24 @@ export interface DeltaComponentStateJson {
      * two separate rows.
      */
     display_two_rows: boolean,
+    /**
+     * The deltas to each of the comparisons chosen in the settings that
+     * exist. If this is not empty, these are shown in separate rows instead
+     * of the single delta described by the other fields.
+     */
+    comparisons: DeltaComparisonStateJson[],
+}
+
+/** The state object describing the delta to a single comparison. */
+export interface DeltaComparisonStateJson {
+    /** The shortened name of the comparison. */
+    text: string,
+    /** The delta. */
+    time: string,
+    /** The semantic coloring information the delta time carries. */
+    semantic_color: SemanticColor,
+    /** The visual color of the delta time. */
+    visual_color: Color,
 }
 
 /** The state object describes the information to visualize for this component. */
@@ -545,6 +714,215 @@ export interface CurrentComparisonComponentStateJson {
     display_two_rows: boolean,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface CounterComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the value. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The name of the counter. */
+    text: string,
+    /** The value of the counter. */
+    value: string,
+    /**
+     * Specifies whether to display the name of the counter and its value in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface ImageComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The unique ID of the image. As the image itself is only provided
+     * whenever it changes, renderers can use the ID to look up the image they
+     * cached. This is `null` if there is no image.
+     */
+    image_id: number | null,
+    /**
+     * The image encoded as a Data URL. This value is only specified whenever
+     * the image changes. If you explicitly want to query this value, remount
+     * the component. The String itself may be empty. This indicates that there
+     * is no image.
+     */
+    image_change: string | null,
+    /** The size of the component. */
+    size: number,
+    /** Describes how the image is drawn. */
+    style: ImageStyle,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface NotesComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the segment's name. If `null` is specified, the color is
+     * taken from the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the notes. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    text_color: Color | null,
+    /**
+     * The name of the segment the notes belong to. This is `null` if the
+     * segment's name is not supposed to be shown.
+     */
+    segment_name: string | null,
+    /**
+     * The notes to show. They may contain line breaks and are supposed to be
+     * wrapped onto multiple lines.
+     */
+    notes: string,
+    /** The amount of lines of notes that are visible. */
+    lines: number,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface RunStatisticsComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the values. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /**
+     * The statistics to visualize, in the order they are supposed to be shown
+     * in.
+     */
+    statistics: RunStatisticsStateJson[],
+}
+
+/** The state object that describes a single statistic to visualize. */
+export interface RunStatisticsStateJson {
+    /** The name of the statistic. */
+    name: string,
+    /** The value of the statistic. */
+    value: string,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface ClockComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the values. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The times of day to visualize, in the order they are supposed to be shown in. */
+    times: ClockTimeStateJson[],
+}
+
+/**
+ * The state object describes the information to visualize for a component
+ * that is implemented outside of livesplit-core.
+ */
+export interface CustomComponentStateJson {
+    /** The unique ID of the component. */
+    id: string,
+    /**
+     * The preferred width of the component when the layout is shown
+     * horizontally, in the component coordinate space.
+     */
+    width: number,
+    /**
+     * The height of the component when the layout is shown vertically, in the
+     * component coordinate space.
+     */
+    height: number,
+    /**
+     * Additional information the component exposes to user interfaces that
+     * know about the component.
+     */
+    data: any,
+    /** The primitives to draw, in the order they are supposed to be drawn in. */
+    primitives: CustomPrimitiveJson[],
+}
+
+/**
+ * A single drawing primitive of a custom component. All the coordinates are
+ * in the component coordinate space.
+ */
+export type CustomPrimitiveJson =
+    { Rectangle: { top_left: number[], bottom_right: number[], background: Gradient } } |
+    { Text: { text: string, position: number[], size: number, align: number, color: Color } };
+
+/** The state object that describes a single time of day to visualize. */
+export interface ClockTimeStateJson {
+    /** The name of the time of day, such as "Current Time". */
+    name: string,
+    /** The formatted time of day. */
+    value: string,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface SegmentHistoryComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the segment's name and the segment times. If `null` is
+     * specified, the color is taken from the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The name of the current segment. This is `null` if the segment's name
+     * is not supposed to be shown.
+     */
+    segment_name: string | null,
+    /** The most recent segment times, starting with the most recent one. */
+    times: SegmentHistoryTimeStateJson[],
+    /**
+     * The points of the sparkline, starting with the oldest segment time.
+     * Each point ranges from 0.0 for the fastest segment time to 1.0 for the
+     * slowest segment time. This is `null` if the sparkline is not supposed
+     * to be shown.
+     */
+    sparkline: number[] | null,
+    /**
+     * The color of the sparkline. If `null` is specified, the color is taken
+     * from the layout.
+     */
+    sparkline_color: Color | null,
+}
+
+/** The state object that describes a single segment time to visualize. */
+export interface SegmentHistoryTimeStateJson {
+    /** The index of the attempt the segment time was achieved in. */
+    attempt_index: number,
+    /** The segment time. */
+    time: string,
+    /** The delta of the segment time to the best segment time. */
+    delta: string,
+    /** The semantic coloring information the segment time carries. */
+    semantic_color: SemanticColor,
+    /** The visual color of the delta. */
+    visual_color: Color,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface DetailedTimerComponentStateJson {
     /** The background shown behind the component. */
@@ -595,6 +973,16 @@ export interface LayoutEditorStateJson {
      * component and their current values.
      */
     component_settings: SettingsDescriptionJson,
+    /**
+     * A generic description of the visibility conditions of the selected
+     * component and their current values.
+     */
+    visibility_settings: SettingsDescriptionJson,
+    /**
+     * A generic description of the settings of the background image of the
+     * selected component and their current values.
+     */
+    background_image_settings: SettingsDescriptionJson,
     /**
      * A generic description of the general settings available for the layout
      * and their current values.
@@ -664,13 +1052,32 @@ export type SettingsDescriptionValueJson =
     { ColumnStartWith: ColumnStartWith } |
     { ColumnUpdateWith: ColumnUpdateWith } |
     { ColumnUpdateTrigger: ColumnUpdateTrigger } |
+    { SubsplitVisibility: SubsplitVisibility } |
     { Hotkey: string } |
     { LayoutDirection: LayoutDirection } |
+    { Image: string } |
+    { ImageScaling: ImageScaling } |
+    { RunningCondition: RunningCondition } |
+    { PaceCondition: PaceCondition } |
+    { GraphMode: GraphMode } |
+    { TimerMode: TimerMode } |
     { CustomCombobox: CustomCombobox };
 
 /** Describes the direction the components of a layout are laid out in. */
 export type LayoutDirection = "Vertical" | "Horizontal";
 
+/**
+ * Describes when a component is shown based on whether there is an active
+ * attempt.
+ */
+export type RunningCondition = "Always" | "WhileRunning" | "WhileNotRunning";
+
+/**
+ * Describes when a component is shown based on whether the current attempt is
+ * ahead of or behind the current comparison.
+ */
+export type PaceCondition = "Always" | "WhileAhead" | "WhileBehind";
+
 /**
  * A custom Combobox containing its current value and a list of possible
  * values.
@@ -689,7 +1096,14 @@ export type ColumnStartWith =
     "Empty" |
     "ComparisonTime" |
     "ComparisonSegmentTime" |
-    "PossibleTimeSave";
+    "PossibleTimeSave" |
+    "ResetCount" |
+    "GoldCount" |
+    "AverageSegmentTime" |
+    "MedianSegmentTime" |
+    "StandardDeviation" |
+    "SurvivalRate" |
+    "BestSplitTime";
 
 /**
  * Once a certain condition is met, which is usually being on the split or
@@ -711,6 +1125,12 @@ export type ColumnUpdateTrigger =
     "Contextual" |
     "OnEndingSegment";
 
+/** Specifies when the subsplits of a section are shown. */
+export type SubsplitVisibility =
+    "CurrentSection" |
+    "Always" |
+    "Never";
+
 /**
  * The Accuracy describes how many digits to show for the fractional part of a
  * time.
@@ -847,6 +1267,8 @@ export interface RunEditorRowJson {
     icon_change: string | null,
     /** The name of the segment. */
     name: string,
+    /** The notes of the segment. */
+    notes: string,
     /** The segment's split time for the active timing method. */
     split_time: string,
     /** The segment time for the active timing method. */
@@ -425,6 +425,9 @@ export async function load(path?: string) {
             Instant_now: function (): number {
                 return performance.now() / 1000;
             },
+            Date_timezone_offset: function (milliseconds: number): number {
+                return new Date(milliseconds).getTimezoneOffset();
+            },
             Date_now: function (ptr: number) {
                 const date = new Date();
                 const milliseconds = date.valueOf();
@@ -598,6 +601,9 @@ exports.load = async function (path) {
             Instant_now: function () {
                 return performance.now() / 1000;
             },
+            Date_timezone_offset: function (milliseconds) {
+                return new Date(milliseconds).getTimezoneOffset();
+            },
             Date_now: function (ptr) {
                 const date = new Date();
                 const milliseconds = date.valueOf();
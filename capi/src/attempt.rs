@@ -2,10 +2,11 @@
 //! by a specific runner in the past. Every time a new attempt is started and
 //! then reset, an Attempt describing general information about it is created.
 
-use super::{output_time, output_time_span};
+use super::{output_str, output_time, output_time_span};
 use crate::atomic_date_time::NullableOwnedAtomicDateTime;
 use crate::time_span::NullableTimeSpan;
 use livesplit_core::{Attempt, Time};
+use std::os::raw::c_char;
 use std::ptr;
 
 /// type
@@ -59,3 +60,41 @@ pub extern "C" fn Attempt_ended(this: &Attempt) -> NullableOwnedAtomicDateTime {
         None
     }
 }
+
+/// Accesses the notes the runner wrote about the attempt.
+#[no_mangle]
+pub extern "C" fn Attempt_notes(this: &Attempt) -> *const c_char {
+    output_str(this.notes())
+}
+
+/// Returns the amount of tags the attempt is tagged with.
+#[no_mangle]
+pub extern "C" fn Attempt_tags_len(this: &Attempt) -> usize {
+    this.tags().len()
+}
+
+/// Accesses a tag of the attempt by its index. You may not provide an out of
+/// bounds index.
+#[no_mangle]
+pub extern "C" fn Attempt_tag(this: &Attempt, index: usize) -> *const c_char {
+    output_str(&this.tags()[index])
+}
+
+/// Accesses the reason the attempt got reset. This returns <NULL> if no reason
+/// is known.
+#[no_mangle]
+pub extern "C" fn Attempt_reset_reason(this: &Attempt) -> *const c_char {
+    if let Some(reset_reason) = this.reset_reason() {
+        output_str(&reset_reason.reason)
+    } else {
+        ptr::null()
+    }
+}
+
+/// Accesses the index of the segment the attempt got reset on. This returns
+/// -1 if no reset reason is known.
+#[no_mangle]
+pub extern "C" fn Attempt_reset_reason_segment_index(this: &Attempt) -> isize {
+    this.reset_reason()
+        .map_or(-1, |reset_reason| reset_reason.segment_index as isize)
+}
@@ -0,0 +1,40 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::clock::State as ClockComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedClockComponentState = Box<ClockComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ClockComponentState_drop(this: OwnedClockComponentState) {
+    drop(this);
+}
+
+/// The amount of times of day to visualize.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_len(this: &ClockComponentState) -> usize {
+    this.times.len()
+}
+
+/// The label of the time of day with the specified index. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_name(
+    this: &ClockComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.times[index].name)
+}
+
+/// The time of day with the specified index. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_value(
+    this: &ClockComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.times[index].value)
+}
@@ -0,0 +1,58 @@
+//! The Counter Component shows a named integer counter, such as the amount of
+//! deaths or collectibles. The counter itself is kept track of by the Timer, so
+//! that the Hotkey System can increment, decrement and reset it.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::counter_component_state::OwnedCounterComponentState;
+use livesplit_core::component::counter::Component as CounterComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedCounterComponent = Box<CounterComponent>;
+
+/// Creates a new Counter Component.
+#[no_mangle]
+pub extern "C" fn CounterComponent_new() -> OwnedCounterComponent {
+    Box::new(CounterComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CounterComponent_drop(this: OwnedCounterComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn CounterComponent_into_generic(this: OwnedCounterComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn CounterComponent_state_as_json(
+    this: &mut CounterComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn CounterComponent_state(
+    this: &mut CounterComponent,
+    timer: &Timer,
+) -> OwnedCounterComponentState {
+    Box::new(this.state(timer))
+}
+
+/// Registers the counter described by this component with the timer provided.
+/// If a counter with the same name already exists, its value is kept.
+#[no_mangle]
+pub extern "C" fn CounterComponent_register(this: &CounterComponent, timer: &mut Timer) {
+    timer.add_counter(this.counter());
+}
@@ -31,3 +31,40 @@ pub extern "C" fn DeltaComponentState_time(this: &DeltaComponentState) -> *const
 pub extern "C" fn DeltaComponentState_semantic_color(this: &DeltaComponentState) -> *const c_char {
     output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
 }
+
+/// The amount of comparisons to show the deltas for in separate rows. If this
+/// is 0, only the single delta described by the other values is shown.
+#[no_mangle]
+pub extern "C" fn DeltaComponentState_len(this: &DeltaComponentState) -> usize {
+    this.comparisons.len()
+}
+
+/// The shortened name of the comparison with the specified index. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn DeltaComponentState_comparison_text(
+    this: &DeltaComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.comparisons[index].text)
+}
+
+/// The delta to the comparison with the specified index. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn DeltaComponentState_comparison_time(
+    this: &DeltaComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.comparisons[index].time)
+}
+
+/// The semantic coloring information the delta to the comparison with the
+/// specified index carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn DeltaComponentState_comparison_semantic_color(
+    this: &DeltaComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.comparisons[index].semantic_color).unwrap())
+}
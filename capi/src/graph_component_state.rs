@@ -1,7 +1,9 @@
 //! The state object describes the information to visualize for this component.
 //! All the coordinates are in the range 0..1.
 
-use livesplit_core::component::graph::State as GraphComponentState;
+use super::output_str;
+use livesplit_core::component::graph::{GraphMode, State as GraphComponentState};
+use std::os::raw::c_char;
 
 /// type
 pub type OwnedGraphComponentState = Box<GraphComponentState>;
@@ -102,3 +104,80 @@ pub extern "C" fn GraphComponentState_is_live_delta_active(this: &GraphComponent
 pub extern "C" fn GraphComponentState_is_flipped(this: &GraphComponentState) -> bool {
     this.is_flipped
 }
+
+/// Describes what the graph visualizes. This is either `Delta`, `Histogram` or
+/// `PersonalBestProgression`. In the `Histogram` mode, the bars are visualized
+/// instead of the points.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_mode(this: &GraphComponentState) -> *const c_char {
+    output_str(match this.mode {
+        GraphMode::Delta => "Delta",
+        GraphMode::Histogram => "Histogram",
+        GraphMode::PersonalBestProgression => "PersonalBestProgression",
+    })
+}
+
+/// Returns the amount of lines of comparisons and attempts overlaid on top of
+/// the graph.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_overlays_len(this: &GraphComponentState) -> usize {
+    this.overlays.len()
+}
+
+/// Returns the amount of points of the overlaid line specified. Connect all of
+/// them to visualize the line. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_overlay_points_len(
+    this: &GraphComponentState,
+    overlay_index: usize,
+) -> usize {
+    this.overlays[overlay_index].len()
+}
+
+/// Returns the x coordinate of the point of the overlaid line specified. You
+/// may not provide out of bounds indices.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_overlay_point_x(
+    this: &GraphComponentState,
+    overlay_index: usize,
+    point_index: usize,
+) -> f32 {
+    this.overlays[overlay_index][point_index].x
+}
+
+/// Returns the y coordinate of the point of the overlaid line specified. You
+/// may not provide out of bounds indices.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_overlay_point_y(
+    this: &GraphComponentState,
+    overlay_index: usize,
+    point_index: usize,
+) -> f32 {
+    this.overlays[overlay_index][point_index].y
+}
+
+/// Returns the amount of bars of the histogram. The bars evenly divide the
+/// width of the graph.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bars_len(this: &GraphComponentState) -> usize {
+    this.bars.len()
+}
+
+/// Returns the height of the bar specified, ranging from 0 to 1. The bars grow
+/// from the bottom, or the top if the graph is flipped. You may not provide an
+/// out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bar(this: &GraphComponentState, index: usize) -> f32 {
+    this.bars[index]
+}
+
+/// Describes whether the bar specified contains the most recent segment time.
+/// Use the graph lines color for it and the complete fill color for all the
+/// other bars. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bar_is_highlighted(
+    this: &GraphComponentState,
+    index: usize,
+) -> bool {
+    this.highlighted_bar == Some(index)
+}
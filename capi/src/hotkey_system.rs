@@ -68,3 +68,12 @@ pub extern "C" fn HotkeySystem_set_config(
 ) -> bool {
     this.set_config(*config).is_ok()
 }
+
+/// Registers the hotkeys of the individual counters of the timer, replacing the
+/// ones that were registered before. This needs to be called again whenever the
+/// counters of the timer change. This operation may fail if a hotkey is used for
+/// multiple operations. Returns <FALSE> if the operation failed.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_update_counter_hotkeys(this: &mut HotkeySystem) -> bool {
+    this.update_counter_hotkeys().is_ok()
+}
@@ -0,0 +1,56 @@
+//! The Image Component shows an image, such as a logo or a piece of artwork.
+//! It can also be used to show an image behind a part of the layout.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::image_component_state::OwnedImageComponentState;
+use livesplit_core::component::image::Component as ImageComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedImageComponent = Box<ImageComponent>;
+
+/// Creates a new Image Component.
+#[no_mangle]
+pub extern "C" fn ImageComponent_new() -> OwnedImageComponent {
+    Box::new(ImageComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ImageComponent_drop(this: OwnedImageComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn ImageComponent_into_generic(this: OwnedImageComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn ImageComponent_state_as_json(this: &mut ImageComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn ImageComponent_state(
+    this: &mut ImageComponent,
+    timer: &Timer,
+) -> OwnedImageComponentState {
+    Box::new(this.state(timer))
+}
+
+/// Remounts the component as if it was freshly initialized. The image shown by
+/// this component is only provided in the state objects whenever the image
+/// changes or whenever the component's state is first queried. Remounting
+/// returns the image again, whenever its state is queried the next time.
+#[no_mangle]
+pub extern "C" fn ImageComponent_remount(this: &mut ImageComponent) {
+    this.remount();
+}
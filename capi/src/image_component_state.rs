@@ -0,0 +1,67 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, Nullablec_char};
+use livesplit_core::component::image::State as ImageComponentState;
+use livesplit_core::settings::ImageScaling;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// type
+pub type OwnedImageComponentState = Box<ImageComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ImageComponentState_drop(this: OwnedImageComponentState) {
+    drop(this);
+}
+
+/// The unique ID of the image. As the image itself is only provided whenever it
+/// changes, the ID can be used to look up the image that was cached. If there
+/// is no image, -1 is returned instead.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_id(this: &ImageComponentState) -> isize {
+    this.image_id.map_or(-1, |id| id as isize)
+}
+
+/// The image encoded as a Data URL. This value is only specified whenever the
+/// image changes. If you explicitly want to query this value, remount the
+/// component. The String itself may be empty. This indicates that there is no
+/// image. If no change occurred, <NULL> is returned instead.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_change(
+    this: &ImageComponentState,
+) -> *const Nullablec_char {
+    this.image_change
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
+
+/// The size of the component.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_size(this: &ImageComponentState) -> u32 {
+    this.size
+}
+
+/// The opacity of the image, ranging from 0 (fully transparent) to 1 (fully
+/// opaque).
+#[no_mangle]
+pub extern "C" fn ImageComponentState_opacity(this: &ImageComponentState) -> f32 {
+    this.style.opacity
+}
+
+/// The amount of blur to apply to the image, ranging from 0 (no blur) to 1
+/// (maximum blur).
+#[no_mangle]
+pub extern "C" fn ImageComponentState_blur(this: &ImageComponentState) -> f32 {
+    this.style.blur
+}
+
+/// Describes how the image is scaled to fill the component. This is either
+/// `Stretch` or `Fit`.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_scaling(this: &ImageComponentState) -> *const c_char {
+    output_str(match this.style.scaling {
+        ImageScaling::Stretch => "Stretch",
+        ImageScaling::Fit => "Fit",
+    })
+}
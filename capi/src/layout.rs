@@ -3,9 +3,10 @@
 
 use super::{output_vec, str, Json};
 use crate::component::OwnedComponent;
-use livesplit_core::layout::{parser, LayoutSettings};
+use livesplit_core::layout::{parser, saver, LayoutSettings};
 use livesplit_core::{Layout, Timer};
 use std::io::Cursor;
+use std::os::raw::c_char;
 use std::slice;
 
 /// type
@@ -84,6 +85,16 @@ pub extern "C" fn Layout_settings_as_json(this: &Layout) -> Json {
     })
 }
 
+/// Saves the layout as a LiveSplit layout file (*.lsl). Settings that the
+/// original LiveSplit doesn't know about are stored in a way that the original
+/// LiveSplit ignores, but livesplit-core restores when parsing the layout.
+#[no_mangle]
+pub extern "C" fn Layout_save_as_lsl(this: &Layout) -> *const c_char {
+    output_vec(|o| {
+        saver::save_layout(this, o).unwrap();
+    })
+}
+
 /// Adds a new component to the end of the layout.
 #[no_mangle]
 pub extern "C" fn Layout_push(this: &mut Layout, component: OwnedComponent) {
@@ -110,3 +121,13 @@ pub extern "C" fn Layout_scroll_down(this: &mut Layout) {
 pub extern "C" fn Layout_remount(this: &mut Layout) {
     this.remount();
 }
+
+/// Adds the counters of all the Counter Components in the layout to the timer
+/// provided, so that the Hotkey System can modify them. This needs to be called
+/// again whenever the layout or the settings of its Counter Components change.
+/// Afterwards the Hotkey System needs to update the hotkeys of the individual
+/// counters.
+#[no_mangle]
+pub extern "C" fn Layout_register_counters(this: &Layout, timer: &mut Timer) {
+    this.register_counters(timer);
+}
@@ -117,6 +117,36 @@ pub extern "C" fn LayoutEditor_set_component_settings_value(
     this.set_component_settings_value(index, *value);
 }
 
+/// Sets a setting's value of the visibility conditions of the selected
+/// component by its setting index to the given value.
+///
+/// This panics if the type of the value to be set is not compatible with
+/// the type of the setting's value. A panic can also occur if the index of
+/// the setting provided is out of bounds.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_set_visibility_settings_value(
+    this: &mut LayoutEditor,
+    index: usize,
+    value: OwnedSettingValue,
+) {
+    this.set_visibility_settings_value(index, *value);
+}
+
+/// Sets a setting's value of the background image of the selected component
+/// by its setting index to the given value.
+///
+/// This panics if the type of the value to be set is not compatible with
+/// the type of the setting's value. A panic can also occur if the index of
+/// the setting provided is out of bounds.
+#[no_mangle]
+pub extern "C" fn LayoutEditor_set_background_image_settings_value(
+    this: &mut LayoutEditor,
+    index: usize,
+    value: OwnedSettingValue,
+) {
+    this.set_background_image_settings_value(index, *value);
+}
+
 /// Sets a setting's value of the general settings by its setting index to
 /// the given value.
 ///
@@ -13,7 +13,11 @@ pub mod atomic_date_time;
 pub mod attempt;
 pub mod blank_space_component;
 pub mod blank_space_component_state;
+pub mod clock_component;
+pub mod clock_component_state;
 pub mod component;
+pub mod counter_component;
+pub mod counter_component_state;
 pub mod current_comparison_component;
 pub mod current_comparison_component_state;
 pub mod current_pace_component;
@@ -28,8 +32,13 @@ pub mod graph_component;
 pub mod graph_component_state;
 pub mod hotkey_config;
 pub mod hotkey_system;
+pub mod image_component;
+pub mod image_component_state;
 pub mod layout;
 pub mod layout_editor;
+pub mod notes_component;
+pub mod notes_component_state;
+pub mod outlier_cleaner;
 pub mod parse_run_result;
 pub mod possible_time_save_component;
 pub mod possible_time_save_component_state;
@@ -41,8 +50,12 @@ pub mod run_editor;
 pub mod run_metadata;
 pub mod run_metadata_variable;
 pub mod run_metadata_variables_iter;
+pub mod run_statistics_component;
+pub mod run_statistics_component_state;
 pub mod segment;
 pub mod segment_history;
+pub mod segment_history_component;
+pub mod segment_history_component_state;
 pub mod segment_history_element;
 pub mod segment_history_iter;
 pub mod separator_component;
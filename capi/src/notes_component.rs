@@ -0,0 +1,47 @@
+//! The Notes Component is a component that shows the notes of the current
+//! segment or the segment that comes up next.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::notes_component_state::OwnedNotesComponentState;
+use livesplit_core::component::notes::Component as NotesComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedNotesComponent = Box<NotesComponent>;
+
+/// Creates a new Notes Component.
+#[no_mangle]
+pub extern "C" fn NotesComponent_new() -> OwnedNotesComponent {
+    Box::new(NotesComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn NotesComponent_drop(this: OwnedNotesComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn NotesComponent_into_generic(this: OwnedNotesComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn NotesComponent_state_as_json(this: &mut NotesComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn NotesComponent_state(
+    this: &mut NotesComponent,
+    timer: &Timer,
+) -> OwnedNotesComponentState {
+    Box::new(this.state(timer))
+}
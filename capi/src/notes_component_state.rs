@@ -0,0 +1,39 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, Nullablec_char};
+use livesplit_core::component::notes::State as NotesComponentState;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// type
+pub type OwnedNotesComponentState = Box<NotesComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn NotesComponentState_drop(this: OwnedNotesComponentState) {
+    drop(this);
+}
+
+/// The name of the segment the notes belong to. If the segment's name is not
+/// supposed to be shown, <NULL> is returned instead.
+#[no_mangle]
+pub extern "C" fn NotesComponentState_segment_name(
+    this: &NotesComponentState,
+) -> *const Nullablec_char {
+    this.segment_name
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
+
+/// The notes to show. They may contain line breaks and are supposed to be
+/// wrapped onto multiple lines.
+#[no_mangle]
+pub extern "C" fn NotesComponentState_notes(this: &NotesComponentState) -> *const c_char {
+    output_str(&this.notes)
+}
+
+/// The amount of lines of notes that are visible.
+#[no_mangle]
+pub extern "C" fn NotesComponentState_lines(this: &NotesComponentState) -> u32 {
+    this.lines
+}
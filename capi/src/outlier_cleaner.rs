@@ -0,0 +1,36 @@
+//! An Outlier Cleaner allows you to interactively remove segment times from
+//! the Segment History that are unusually fast or slow compared to all the
+//! other segment times of the same segment. Such segment times are often caused
+//! by splitting too early or too late. The Outlier Cleaner will point out all
+//! of these and allows you to delete them individually if any of them seem
+//! wrong.
+
+use crate::potential_clean_up::{NullableOwnedPotentialCleanUp, OwnedPotentialCleanUp};
+use livesplit_core::run::editor::cleaning::OutlierCleaner;
+
+/// type
+pub type OwnedOutlierCleaner = Box<OutlierCleaner<'static>>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn OutlierCleaner_drop(this: OwnedOutlierCleaner) {
+    drop(this);
+}
+
+/// Returns the next potential clean up. If there are no more potential
+/// clean ups, <NULL> is returned.
+#[no_mangle]
+pub extern "C" fn OutlierCleaner_next_potential_clean_up(
+    this: &'static mut OutlierCleaner<'static>,
+) -> NullableOwnedPotentialCleanUp {
+    this.next_potential_clean_up().map(Box::new)
+}
+
+/// Applies a clean up to the Run.
+#[no_mangle]
+pub extern "C" fn OutlierCleaner_apply(
+    this: &'static mut OutlierCleaner<'static>,
+    clean_up: OwnedPotentialCleanUp,
+) {
+    this.apply((*clean_up).into());
+}
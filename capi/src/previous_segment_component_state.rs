@@ -37,3 +37,50 @@ pub extern "C" fn PreviousSegmentComponentState_semantic_color(
 ) -> *const c_char {
     output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
 }
+
+/// The amount of comparisons to show the deltas for in separate rows. If this
+/// is 0, only the single delta described by the other values is shown.
+#[no_mangle]
+pub extern "C" fn PreviousSegmentComponentState_len(this: &PreviousSegmentComponentState) -> usize {
+    this.comparisons.len()
+}
+
+/// The label's text of the comparison with the specified index. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PreviousSegmentComponentState_comparison_text(
+    this: &PreviousSegmentComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.comparisons[index].text)
+}
+
+/// The shortened name of the comparison with the specified index. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PreviousSegmentComponentState_comparison_name(
+    this: &PreviousSegmentComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.comparisons[index].comparison)
+}
+
+/// The delta (and possibly the possible time save) to the comparison with the
+/// specified index. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PreviousSegmentComponentState_comparison_time(
+    this: &PreviousSegmentComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.comparisons[index].time)
+}
+
+/// The semantic coloring information the delta to the comparison with the
+/// specified index carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn PreviousSegmentComponentState_comparison_semantic_color(
+    this: &PreviousSegmentComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.comparisons[index].semantic_color).unwrap())
+}
@@ -4,8 +4,10 @@
 //! state objects that can be visualized by any kind of User Interface.
 
 use super::{output_vec, str, Json};
+use crate::outlier_cleaner::OwnedOutlierCleaner;
 use crate::run::OwnedRun;
 use crate::sum_of_best_cleaner::OwnedSumOfBestCleaner;
+use livesplit_core::run::ResetReason;
 use livesplit_core::{Run, RunEditor, TimingMethod};
 use std::os::raw::c_char;
 use std::slice;
@@ -249,6 +251,12 @@ pub unsafe extern "C" fn RunEditor_active_set_name(this: &mut RunEditor, name: *
     this.active_segment().set_name(str(name));
 }
 
+/// Sets the notes of the active segment.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_active_set_notes(this: &mut RunEditor, notes: *const c_char) {
+    this.active_segment().set_notes(str(notes));
+}
+
 /// Parses a split time from a string and sets it for the active segment with
 /// the chosen timing method.
 #[no_mangle]
@@ -298,6 +306,65 @@ pub unsafe extern "C" fn RunEditor_active_parse_and_set_comparison_time(
         .is_ok()
 }
 
+/// Sets the notes of the attempt with the index provided. If there is no
+/// attempt with that index, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_set_attempt_notes(
+    this: &mut RunEditor,
+    index: i32,
+    notes: *const c_char,
+) {
+    this.set_attempt_notes(index, str(notes));
+}
+
+/// Tags the attempt with the index provided. If there is no attempt with that
+/// index, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_add_attempt_tag(
+    this: &mut RunEditor,
+    index: i32,
+    tag: *const c_char,
+) {
+    this.add_attempt_tag(index, str(tag));
+}
+
+/// Removes the tag from the attempt with the index provided. If there is no
+/// attempt with that index, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_remove_attempt_tag(
+    this: &mut RunEditor,
+    index: i32,
+    tag: *const c_char,
+) {
+    this.remove_attempt_tag(index, str(tag));
+}
+
+/// Sets the reason the attempt with the index provided got reset on the
+/// segment with the given index. If there is no attempt with that index or
+/// there is no segment with the segment index, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_set_attempt_reset_reason(
+    this: &mut RunEditor,
+    index: i32,
+    segment_index: usize,
+    reason: *const c_char,
+) {
+    this.set_attempt_reset_reason(
+        index,
+        Some(ResetReason {
+            segment_index,
+            reason: str(reason).to_owned(),
+        }),
+    );
+}
+
+/// Removes the reset reason of the attempt with the index provided. If there
+/// is no attempt with that index, nothing happens.
+#[no_mangle]
+pub extern "C" fn RunEditor_clear_attempt_reset_reason(this: &mut RunEditor, index: i32) {
+    this.set_attempt_reset_reason(index, None);
+}
+
 /// Adds a new custom comparison. It can't be added if it starts with
 /// `[Race]` or already exists.
 #[no_mangle]
@@ -320,6 +387,20 @@ pub unsafe extern "C" fn RunEditor_import_comparison(
     this.import_comparison(run, str(comparison)).is_ok()
 }
 
+/// Imports the split times of an earlier Personal Best as a comparison. The
+/// attempt index refers to one of the Personal Bests in the Attempt History.
+/// The comparison can't be added if the attempt is not a Personal Best, its
+/// name starts with `[Race]` or it already exists.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_import_historical_personal_best(
+    this: &mut RunEditor,
+    attempt_index: i32,
+    comparison: *const c_char,
+) -> bool {
+    this.import_historical_personal_best(attempt_index, str(comparison))
+        .is_ok()
+}
+
 /// Removes the chosen custom comparison. You can't remove a Comparison
 /// Generator's Comparison or the Personal Best.
 #[no_mangle]
@@ -400,3 +481,14 @@ pub extern "C" fn RunEditor_clean_sum_of_best(
 ) -> OwnedSumOfBestCleaner {
     Box::new(this.clean_sum_of_best())
 }
+
+/// Creates an Outlier Cleaner which allows you to interactively remove
+/// segment times from the segment history that are unusually fast or slow
+/// compared to all the other segment times of the same segment. These are
+/// often caused by splitting too early or too late. The Outlier Cleaner
+/// will point out all of these and allows you to delete them individually
+/// if any of them seem wrong.
+#[no_mangle]
+pub extern "C" fn RunEditor_clean_outliers(this: &'static mut RunEditor) -> OwnedOutlierCleaner {
+    Box::new(this.clean_outliers())
+}
@@ -0,0 +1,52 @@
+//! The Run Statistics Component shows a configurable list of statistics that
+//! are calculated from the Attempt History and the Segment History of the run.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::run_statistics_component_state::OwnedRunStatisticsComponentState;
+use livesplit_core::component::run_statistics::Component as RunStatisticsComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedRunStatisticsComponent = Box<RunStatisticsComponent>;
+
+/// Creates a new Run Statistics Component.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponent_new() -> OwnedRunStatisticsComponent {
+    Box::new(RunStatisticsComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponent_drop(this: OwnedRunStatisticsComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponent_into_generic(
+    this: OwnedRunStatisticsComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponent_state_as_json(
+    this: &mut RunStatisticsComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponent_state(
+    this: &mut RunStatisticsComponent,
+    timer: &Timer,
+) -> OwnedRunStatisticsComponentState {
+    Box::new(this.state(timer))
+}
@@ -0,0 +1,40 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::run_statistics::State as RunStatisticsComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedRunStatisticsComponentState = Box<RunStatisticsComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponentState_drop(this: OwnedRunStatisticsComponentState) {
+    drop(this);
+}
+
+/// The amount of statistics to visualize.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponentState_len(this: &RunStatisticsComponentState) -> usize {
+    this.statistics.len()
+}
+
+/// The name of the statistic with the specified index. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponentState_name(
+    this: &RunStatisticsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.statistics[index].name)
+}
+
+/// The value of the statistic with the specified index. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunStatisticsComponentState_value(
+    this: &RunStatisticsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.statistics[index].value)
+}
@@ -27,6 +27,12 @@ pub extern "C" fn Segment_name(this: &Segment) -> *const c_char {
     output_str(this.name())
 }
 
+/// Accesses the notes of the segment.
+#[no_mangle]
+pub extern "C" fn Segment_notes(this: &Segment) -> *const c_char {
+    output_str(this.notes())
+}
+
 /// Accesses the icon of the segment encoded as a Data URL storing the image's
 /// data. If the image's data is empty, this returns an empty string instead of
 /// a URL.
@@ -0,0 +1,94 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, output_vec, Nullablec_char};
+use livesplit_core::component::segment_history::State as SegmentHistoryComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// type
+pub type OwnedSegmentHistoryComponentState = Box<SegmentHistoryComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_drop(this: OwnedSegmentHistoryComponentState) {
+    drop(this);
+}
+
+/// The name of the current segment. If the segment's name is not supposed to
+/// be shown, <NULL> is returned instead.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_segment_name(
+    this: &SegmentHistoryComponentState,
+) -> *const Nullablec_char {
+    this.segment_name
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
+
+/// The amount of segment times to visualize.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_len(this: &SegmentHistoryComponentState) -> usize {
+    this.times.len()
+}
+
+/// The index of the attempt the segment time with the specified index was
+/// achieved in. The segment times start with the most recent one. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_attempt_index(
+    this: &SegmentHistoryComponentState,
+    index: usize,
+) -> i32 {
+    this.times[index].attempt_index
+}
+
+/// The segment time with the specified index. You may not provide an out of
+/// bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_time(
+    this: &SegmentHistoryComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.times[index].time)
+}
+
+/// The delta to the best segment time of the segment time with the specified
+/// index. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_delta(
+    this: &SegmentHistoryComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.times[index].delta)
+}
+
+/// The semantic coloring information the segment time with the specified
+/// index carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_semantic_color(
+    this: &SegmentHistoryComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.times[index].semantic_color).unwrap())
+}
+
+/// The amount of points of the sparkline. This is 0 if the sparkline is not
+/// supposed to be shown.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_sparkline_len(
+    this: &SegmentHistoryComponentState,
+) -> usize {
+    this.sparkline.as_ref().map_or(0, Vec::len)
+}
+
+/// The point of the sparkline with the specified index. The points start with
+/// the oldest segment time and range from 0.0 for the fastest segment time to
+/// 1.0 for the slowest one. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SegmentHistoryComponentState_sparkline_point(
+    this: &SegmentHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.sparkline.as_ref().unwrap()[index]
+}
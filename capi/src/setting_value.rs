@@ -2,11 +2,19 @@
 //! types.
 
 use crate::str;
-use livesplit_core::component::splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith};
-use livesplit_core::settings::{Alignment, Color, Gradient, ListGradient, Value as SettingValue};
+use livesplit_core::component::graph::GraphMode;
+use livesplit_core::component::splits::{
+    ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, SubsplitVisibility,
+};
+use livesplit_core::component::timer::TimerMode;
+use livesplit_core::layout::{LayoutDirection, PaceCondition, RunningCondition};
+use livesplit_core::settings::{
+    Alignment, Color, Gradient, ImageScaling, ListGradient, Value as SettingValue,
+};
 use livesplit_core::timing::formatter::{Accuracy, DigitsFormat};
-use livesplit_core::{layout::LayoutDirection, TimingMethod};
+use livesplit_core::{Image, TimingMethod};
 use std::os::raw::c_char;
+use std::slice;
 
 /// type
 pub type OwnedSettingValue = Box<SettingValue>;
@@ -240,6 +248,13 @@ pub unsafe extern "C" fn SettingValue_from_column_start_with(
         "ComparisonTime" => ColumnStartWith::ComparisonTime,
         "ComparisonSegmentTime" => ColumnStartWith::ComparisonSegmentTime,
         "PossibleTimeSave" => ColumnStartWith::PossibleTimeSave,
+        "ResetCount" => ColumnStartWith::ResetCount,
+        "GoldCount" => ColumnStartWith::GoldCount,
+        "AverageSegmentTime" => ColumnStartWith::AverageSegmentTime,
+        "MedianSegmentTime" => ColumnStartWith::MedianSegmentTime,
+        "StandardDeviation" => ColumnStartWith::StandardDeviation,
+        "SurvivalRate" => ColumnStartWith::SurvivalRate,
+        "BestSplitTime" => ColumnStartWith::BestSplitTime,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -281,6 +296,22 @@ pub unsafe extern "C" fn SettingValue_from_column_update_trigger(
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from the subsplit visibility. If it doesn't
+/// match a known subsplit visibility, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_subsplit_visibility(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "CurrentSection" => SubsplitVisibility::CurrentSection,
+        "Always" => SubsplitVisibility::Always,
+        "Never" => SubsplitVisibility::Never,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
 /// Creates a new setting value from the layout direction. If it doesn't
 /// match a known layout direction, <NULL> is returned.
 #[no_mangle]
@@ -295,3 +326,101 @@ pub unsafe extern "C" fn SettingValue_from_layout_direction(
     };
     Some(Box::new(value.into()))
 }
+
+/// Creates a new setting value from the image data provided. If the image is
+/// larger than what is reasonable for a background image, it gets shrunk down.
+/// Empty image data means that no image is used.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image(
+    data: *const u8,
+    length: usize,
+) -> OwnedSettingValue {
+    let image = if length == 0 {
+        Image::default()
+    } else {
+        Image::with_max_size(
+            slice::from_raw_parts(data, length),
+            Image::MAX_BACKGROUND_SIZE,
+        )
+    };
+    Box::new(image.into())
+}
+
+/// Creates a new setting value from the image scaling provided. If it doesn't
+/// match a known image scaling, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image_scaling(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Stretch" => ImageScaling::Stretch,
+        "Fit" => ImageScaling::Fit,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the running condition provided. If it
+/// doesn't match a known running condition, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_running_condition(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Always" => RunningCondition::Always,
+        "WhileRunning" => RunningCondition::WhileRunning,
+        "WhileNotRunning" => RunningCondition::WhileNotRunning,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the pace condition provided. If it doesn't
+/// match a known pace condition, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_pace_condition(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Always" => PaceCondition::Always,
+        "WhileAhead" => PaceCondition::WhileAhead,
+        "WhileBehind" => PaceCondition::WhileBehind,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the graph mode provided. If it doesn't
+/// match a known graph mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_graph_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Delta" => GraphMode::Delta,
+        "Histogram" => GraphMode::Histogram,
+        "PersonalBestProgression" => GraphMode::PersonalBestProgression,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the timer mode provided. If it doesn't
+/// match a known timer mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_timer_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "CountUp" => TimerMode::CountUp,
+        "Countdown" => TimerMode::Countdown,
+        "RemainingToBeatComparison" => TimerMode::RemainingToBeatComparison,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
@@ -118,3 +118,14 @@ pub extern "C" fn SplitsComponentState_is_current_split(
 ) -> bool {
     this.splits[index].is_current_split
 }
+
+/// Describes what the row with the specified index represents. This is either
+/// `Segment`, `Subsplit`, `SectionHeader` or `Section`. You may not provide an
+/// out of bounds index.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_kind(
+    this: &SplitsComponentState,
+    index: usize,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.splits[index].kind).unwrap())
+}
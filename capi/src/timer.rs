@@ -1,6 +1,6 @@
 //! A Timer provides all the capabilities necessary for doing speedrun attempts.
 
-use super::{output_str, output_time, output_time_span, output_vec};
+use super::{output_str, output_time, output_time_span, output_vec, str};
 use crate::run::{NullableOwnedRun, OwnedRun};
 use crate::shared_timer::OwnedSharedTimer;
 use livesplit_core::run::saver;
@@ -292,6 +292,45 @@ pub extern "C" fn Timer_mark_as_unmodified(this: &mut Timer) {
     this.mark_as_unmodified();
 }
 
+/// Increments all the counters of the Timer by 1.
+#[no_mangle]
+pub extern "C" fn Timer_increment_counters(this: &mut Timer) {
+    this.increment_counters();
+}
+
+/// Decrements all the counters of the Timer by 1.
+#[no_mangle]
+pub extern "C" fn Timer_decrement_counters(this: &mut Timer) {
+    this.decrement_counters();
+}
+
+/// Resets all the counters of the Timer to 0.
+#[no_mangle]
+pub extern "C" fn Timer_reset_counters(this: &mut Timer) {
+    this.reset_counters();
+}
+
+/// Increments the counter with the name provided by 1. If there is no such
+/// counter, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn Timer_increment_counter(this: &mut Timer, name: *const c_char) {
+    this.increment_counter(str(name));
+}
+
+/// Decrements the counter with the name provided by 1. If there is no such
+/// counter, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn Timer_decrement_counter(this: &mut Timer, name: *const c_char) {
+    this.decrement_counter(str(name));
+}
+
+/// Resets the counter with the name provided to 0. If there is no such
+/// counter, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn Timer_reset_counter(this: &mut Timer, name: *const c_char) {
+    this.reset_counter(str(name));
+}
+
 /// Prints out debug information representing the whole state of the Timer. This
 /// is being written to stdout.
 #[no_mangle]
@@ -31,3 +31,10 @@ pub extern "C" fn TimerComponentState_fraction(this: &TimerComponentState) -> *c
 pub extern "C" fn TimerComponentState_semantic_color(this: &TimerComponentState) -> *const c_char {
     output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
 }
+
+/// Specifies whether the time of a countdown ran out. This is never the case
+/// when counting up.
+#[no_mangle]
+pub extern "C" fn TimerComponentState_is_time_up(this: &TimerComponentState) -> bool {
+    this.is_time_up
+}
@@ -0,0 +1,230 @@
+//! Provides functionality for querying the attempts of a Run. The general
+//! information about an attempt, like when it started and ended, is stored in
+//! the Attempt History of the Run, while the segment times of the attempt are
+//! stored in the Segment History of each segment. This module joins both of
+//! them together, so that each attempt can be looked at as a whole.
+//!
+//! # Examples
+//!
+//! ```
+//! use livesplit_core::analysis::attempts::{self, Filter};
+//! use livesplit_core::{Run, Segment};
+//!
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! let filter = Filter {
+//!     finished_only: true,
+//!     ..Default::default()
+//! };
+//!
+//! for attempt in attempts::query(&run, filter) {
+//!     println!("{:?}", attempt.segments()[0].split_time.real_time);
+//! }
+//! ```
+
+use crate::{AtomicDateTime, Attempt, DateTime, Run, Segment, Time, TimeSpan, TimingMethod, Utc};
+use std::slice;
+
+/// Describes which attempts to query.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filter {
+    /// Specifies whether only attempts that reached the end of the run should
+    /// be queried.
+    pub finished_only: bool,
+    /// If specified, only attempts that started at or after this point in time
+    /// are queried. Attempts without a known start date are not queried then.
+    pub started_after: Option<DateTime<Utc>>,
+    /// If specified, only attempts that started before this point in time are
+    /// queried. Attempts without a known start date are not queried then.
+    pub started_before: Option<DateTime<Utc>>,
+    /// If specified, only attempts that made it past the segment with this
+    /// index are queried. Skipping the segment counts as making it past the
+    /// segment.
+    pub reached_segment: Option<usize>,
+    /// If specified, only attempts that are tagged with this tag are queried.
+    pub tag: Option<String>,
+}
+
+/// The segment and split times of an attempt for a single segment.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SegmentTimes {
+    /// The segment time of the attempt. A timing method is empty if the segment
+    /// got skipped, the attempt didn't reach the segment or the segment time is
+    /// combined with the segment time of skipped segments before it.
+    pub segment_time: Time,
+    /// The split time of the attempt. A timing method is empty if the segment
+    /// got skipped or the attempt didn't reach the segment.
+    pub split_time: Time,
+}
+
+/// An attempt along with all of its segment and split times.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FullAttempt<'a> {
+    attempt: &'a Attempt,
+    segments: Vec<SegmentTimes>,
+    reached: usize,
+}
+
+impl<'a> FullAttempt<'a> {
+    /// Accesses the general information about the attempt that is stored in
+    /// the Attempt History.
+    pub fn attempt(&self) -> &'a Attempt {
+        self.attempt
+    }
+
+    /// Accesses the unique index of the attempt.
+    pub fn index(&self) -> i32 {
+        self.attempt.index()
+    }
+
+    /// Accesses the final time of the attempt. If the attempt got reset early
+    /// and didn't finish, this may be empty.
+    pub fn time(&self) -> Time {
+        self.attempt.time()
+    }
+
+    /// Accesses the point in time the attempt was started at. This returns
+    /// `None` if this information is not known.
+    pub fn started(&self) -> Option<AtomicDateTime> {
+        self.attempt.started()
+    }
+
+    /// Accesses the point in time the attempt was ended at. This returns `None`
+    /// if this information is not known.
+    pub fn ended(&self) -> Option<AtomicDateTime> {
+        self.attempt.ended()
+    }
+
+    /// Accesses the amount of time the attempt has been paused for. This
+    /// returns `None` if this information is not known.
+    pub fn pause_time(&self) -> Option<TimeSpan> {
+        self.attempt.pause_time()
+    }
+
+    /// Returns whether the attempt reached the end of the run.
+    pub fn is_finished(&self) -> bool {
+        is_finished(self.attempt)
+    }
+
+    /// Returns the amount of segments the attempt made it past, either by
+    /// splitting or skipping them.
+    pub fn reached_segments(&self) -> usize {
+        self.reached
+    }
+
+    /// Accesses the segment and split times of the attempt for each segment.
+    pub fn segments(&self) -> &[SegmentTimes] {
+        &self.segments
+    }
+}
+
+/// The iterator that iterates over the queried attempts.
+pub struct Attempts<'a> {
+    segments: &'a [Segment],
+    attempts: slice::Iter<'a, Attempt>,
+    filter: Filter,
+}
+
+impl<'a> Iterator for Attempts<'a> {
+    type Item = FullAttempt<'a>;
+
+    fn next(&mut self) -> Option<FullAttempt<'a>> {
+        let (segments, filter) = (self.segments, &self.filter);
+
+        self.attempts.find_map(|attempt| {
+            if filter.finished_only && !is_finished(attempt) {
+                return None;
+            }
+
+            if let Some(tag) = &filter.tag {
+                if !attempt.has_tag(tag) {
+                    return None;
+                }
+            }
+
+            if filter.started_after.is_some() || filter.started_before.is_some() {
+                let started = attempt.started()?.time;
+                if filter.started_after.map_or(false, |after| started < after)
+                    || filter
+                        .started_before
+                        .map_or(false, |before| started >= before)
+                {
+                    return None;
+                }
+            }
+
+            let reached = reached_segments(segments, attempt.index());
+            if filter
+                .reached_segment
+                .map_or(false, |segment| reached <= segment)
+            {
+                return None;
+            }
+
+            Some(FullAttempt {
+                attempt,
+                segments: reconstruct(segments, attempt.index()),
+                reached,
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.attempts.size_hint().1)
+    }
+}
+
+/// Queries all the attempts of the Run that match the filter provided. The
+/// attempts are yielded in the order they are stored in the Attempt History.
+pub fn query(run: &Run, filter: Filter) -> Attempts<'_> {
+    Attempts {
+        segments: run.segments(),
+        attempts: run.attempt_history().iter(),
+        filter,
+    }
+}
+
+/// Reconstructs the segment and split times of the attempt with the given
+/// index from the Segment History of the segments provided. A segment without a
+/// segment time is considered skipped, as long as the attempt reached a later
+/// segment. Otherwise the attempt is considered reset before the segment.
+pub fn reconstruct(segments: &[Segment], index: i32) -> Vec<SegmentTimes> {
+    let reached = reached_segments(segments, index);
+    let mut times = vec![SegmentTimes::default(); segments.len()];
+
+    for &method in &TimingMethod::all() {
+        let mut split_time = TimeSpan::zero();
+        let mut previous_segment_skipped = false;
+
+        for (segment, times) in segments.iter().zip(&mut times).take(reached) {
+            if let Some(segment_time) = segment.segment_history().get(index).and_then(|t| t[method])
+            {
+                split_time += segment_time;
+                if !previous_segment_skipped {
+                    times.segment_time[method] = Some(segment_time);
+                }
+                times.split_time[method] = Some(split_time);
+                previous_segment_skipped = false;
+            } else {
+                previous_segment_skipped = true;
+            }
+        }
+    }
+
+    times
+}
+
+pub(crate) fn reached_segments(segments: &[Segment], index: i32) -> usize {
+    segments
+        .iter()
+        .rposition(|s| s.segment_history().get(index).is_some())
+        .map_or(0, |i| i + 1)
+}
+
+/// Returns whether the attempt reached the end of the run. An attempt counts
+/// as finished if it has a final time for any of the timing methods.
+pub fn is_finished(attempt: &Attempt) -> bool {
+    let time = attempt.time();
+    time.real_time.is_some() || time.game_time.is_some()
+}
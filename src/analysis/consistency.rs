@@ -0,0 +1,207 @@
+//! Provides functionality for calculating how consistent the runner is on each
+//! of the segments. This is based on the segment times stored in the Segment
+//! History of each segment. Combined segments, where the previous segment got
+//! skipped, are not taken into account. While the Possible Time Save only looks
+//! at how much time could be saved compared to the best segments, the
+//! consistency statistics provide information about how much the segment times
+//! vary between the attempts and how much time is lost on average.
+
+use crate::{Segment, TimeSpan, TimingMethod};
+use ordered_float::OrderedFloat;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+/// The factor of the interquartile range that a segment time may be away from
+/// the first or the third quartile before it is considered an outlier.
+const OUTLIER_FACTOR: f64 = 1.5;
+
+/// Describes how consistent the runner is on a single segment.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SegmentConsistency {
+    /// The index of the segment these statistics are about.
+    pub segment_index: usize,
+    /// The amount of segment times that the statistics are based on.
+    pub sample_count: usize,
+    /// The arithmetic mean of all the segment times.
+    pub mean: TimeSpan,
+    /// The median of all the segment times.
+    pub median: TimeSpan,
+    /// The standard deviation of the segment times. The lower this is, the more
+    /// consistent the runner is on this segment.
+    pub standard_deviation: TimeSpan,
+    /// The difference between the third and the first quartile of the segment
+    /// times. Unlike the standard deviation, this is not influenced by a few
+    /// very slow or very fast segment times.
+    pub interquartile_range: TimeSpan,
+    /// The amount of time that is lost on average compared to the best segment
+    /// time.
+    pub average_time_lost: TimeSpan,
+}
+
+/// Collects all the segment times of the segment with the given index that
+/// were actually achieved by the runner. Combined segment times, where the
+/// previous segment got skipped, are filtered out. The segment times are
+/// yielded along with the index of the attempt they belong to, in the order
+/// they were achieved in.
+///
+/// # Panics
+///
+/// This panics if the segment index provided is out of bounds.
+pub fn segment_times(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> impl Iterator<Item = (i32, TimeSpan)> + '_ {
+    let previous_segment = segment_index.checked_sub(1).map(|i| &segments[i]);
+
+    segments[segment_index]
+        .segment_history()
+        .iter_actual_runs()
+        .filter_map(move |&(index, time)| {
+            let time = time[method]?;
+
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(index)?[method].is_none()
+            }
+            .unwrap_or(false);
+
+            if skip {
+                None
+            } else {
+                Some((index, time))
+            }
+        })
+}
+
+/// Collects the segment and attempt indices of all the segment times that
+/// were faster than all the segment times of the same segment before them.
+/// These are the segment times that were a new best segment at the time they
+/// were achieved.
+pub fn golds(segments: &[Segment], method: TimingMethod) -> HashSet<(usize, i32)> {
+    (0..segments.len())
+        .flat_map(|segment_index| {
+            segment_golds(segments, segment_index, method).map(move |index| (segment_index, index))
+        })
+        .collect()
+}
+
+/// Counts the segment times of the segment with the given index that were
+/// faster than all the segment times of the same segment before them.
+///
+/// # Panics
+///
+/// This panics if the segment index provided is out of bounds.
+pub fn gold_count(segments: &[Segment], segment_index: usize, method: TimingMethod) -> usize {
+    segment_golds(segments, segment_index, method).count()
+}
+
+fn segment_golds(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> impl Iterator<Item = i32> + '_ {
+    let mut best = None;
+    segment_times(segments, segment_index, method).filter_map(move |(index, time)| {
+        if best.map_or(true, |best| time < best) {
+            best = Some(time);
+            Some(index)
+        } else {
+            None
+        }
+    })
+}
+
+/// Calculates the consistency statistics for the segment with the given index.
+/// If outliers are excluded, every segment time that is further away than 1.5
+/// times the interquartile range from the first or the third quartile is
+/// ignored. `None` is returned if there are no segment times to base the
+/// statistics on.
+///
+/// # Panics
+///
+/// This panics if the segment index provided is out of bounds.
+pub fn calculate(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+    exclude_outliers: bool,
+) -> Option<SegmentConsistency> {
+    let mut times = segment_times(segments, segment_index, method)
+        .map(|(_, time)| time.total_seconds())
+        .collect::<Vec<_>>();
+
+    times.sort_unstable_by_key(|&time| OrderedFloat(time));
+
+    if exclude_outliers {
+        remove_outliers(&mut times);
+    }
+
+    if times.is_empty() {
+        return None;
+    }
+
+    let sample_count = times.len();
+    let mean = times.iter().sum::<f64>() / sample_count as f64;
+    let variance = if sample_count > 1 {
+        times.iter().map(|t| (t - mean) * (t - mean)).sum::<f64>() / (sample_count - 1) as f64
+    } else {
+        0.0
+    };
+
+    let best_segment =
+        segments[segment_index].best_segment_time()[method].map_or(times[0], |t| t.total_seconds());
+
+    Some(SegmentConsistency {
+        segment_index,
+        sample_count,
+        mean: TimeSpan::from_seconds(mean),
+        median: TimeSpan::from_seconds(percentile(&times, 0.5)),
+        standard_deviation: TimeSpan::from_seconds(variance.sqrt()),
+        interquartile_range: TimeSpan::from_seconds(
+            percentile(&times, 0.75) - percentile(&times, 0.25),
+        ),
+        average_time_lost: TimeSpan::from_seconds((mean - best_segment).max(0.0)),
+    })
+}
+
+/// Calculates the consistency statistics for all the segments. The statistics
+/// are ranked by the amount of time that is lost on average compared to the
+/// best segment time, so the segments where the most time could be saved come
+/// first. Segments without any segment times are not part of the result.
+pub fn calculate_ranked(
+    segments: &[Segment],
+    method: TimingMethod,
+    exclude_outliers: bool,
+) -> Vec<SegmentConsistency> {
+    let mut ranked = (0..segments.len())
+        .filter_map(|index| calculate(segments, index, method, exclude_outliers))
+        .collect::<Vec<_>>();
+
+    ranked.sort_by_key(|s| Reverse(s.average_time_lost));
+
+    ranked
+}
+
+/// Calculates the given percentile of the sorted values by linearly
+/// interpolating between the two closest values.
+fn percentile(sorted: &[f64], percentile: f64) -> f64 {
+    let position = percentile * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    let fraction = position - lower as f64;
+    sorted[lower] + fraction * (sorted[upper] - sorted[lower])
+}
+
+/// Removes all the values from the sorted values that lie outside of the
+/// fences based on the interquartile range.
+fn remove_outliers(sorted: &mut Vec<f64>) {
+    if sorted.len() < 4 {
+        return;
+    }
+
+    let (first_quartile, third_quartile) = (percentile(sorted, 0.25), percentile(sorted, 0.75));
+    let range = OUTLIER_FACTOR * (third_quartile - first_quartile);
+    let (lower_fence, upper_fence) = (first_quartile - range, third_quartile + range);
+
+    sorted.retain(|&t| t >= lower_fence && t <= upper_fence);
+}
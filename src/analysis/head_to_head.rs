@@ -0,0 +1,140 @@
+//! Provides functionality for comparing two attempts with each other. The split
+//! and segment times of both attempts are reconstructed from the Segment
+//! History, so any two attempts that are stored in the Run can be compared,
+//! not just the Personal Best. This is useful for figuring out where one attempt
+//! gained time on the other.
+
+use super::attempts;
+use crate::{Segment, TimeSpan, TimingMethod};
+
+/// Describes which of the two attempts was faster on a segment.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Winner {
+    /// The first attempt was faster.
+    First,
+    /// The second attempt was faster.
+    Second,
+    /// Both attempts had the same segment time.
+    Tie,
+}
+
+/// The split and segment times of a single attempt for a single segment.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AttemptTimes {
+    /// The segment time of the attempt. This is `None` if the segment got
+    /// skipped, the attempt didn't reach the segment or the segment time is
+    /// combined with the segment time of skipped segments before it.
+    pub segment_time: Option<TimeSpan>,
+    /// The split time of the attempt. This is `None` if the segment got skipped
+    /// or the attempt didn't reach the segment.
+    pub split_time: Option<TimeSpan>,
+}
+
+/// Compares the two attempts on a single segment.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SegmentComparison {
+    /// The times of the first attempt.
+    pub first: AttemptTimes,
+    /// The times of the second attempt.
+    pub second: AttemptTimes,
+    /// The segment time of the first attempt minus the segment time of the
+    /// second attempt. A negative delta means that the first attempt was
+    /// faster.
+    pub segment_delta: Option<TimeSpan>,
+    /// The split time of the first attempt minus the split time of the second
+    /// attempt. This is the cumulative difference between the attempts up to
+    /// this segment.
+    pub split_delta: Option<TimeSpan>,
+    /// The attempt that was faster on this segment. This is `None` if either of
+    /// the attempts doesn't have a segment time for this segment.
+    pub winner: Option<Winner>,
+}
+
+/// The result of comparing two attempts with each other.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeadToHead {
+    /// The comparison of the two attempts for each segment.
+    pub segments: Vec<SegmentComparison>,
+    /// The amount of segments the first attempt was faster on.
+    pub first_wins: usize,
+    /// The amount of segments the second attempt was faster on.
+    pub second_wins: usize,
+    /// The amount of segments both attempts had the same segment time on.
+    pub ties: usize,
+}
+
+impl HeadToHead {
+    /// Returns the last cumulative difference between the two attempts. If both
+    /// attempts finished, this is the difference between their final times.
+    pub fn final_delta(&self) -> Option<TimeSpan> {
+        self.segments
+            .iter()
+            .rev()
+            .filter_map(|s| s.split_delta)
+            .next()
+    }
+}
+
+/// Compares the attempts with the two attempt indices provided. The split and
+/// segment times of each attempt are reconstructed from the Segment History of
+/// the segments. A segment without a segment time in the Segment History is
+/// considered skipped, as long as the attempt reached a later segment.
+/// Otherwise the attempt is considered reset before the segment.
+pub fn calculate(
+    segments: &[Segment],
+    first_index: i32,
+    second_index: i32,
+    method: TimingMethod,
+) -> HeadToHead {
+    let first = attempt_times(segments, first_index, method);
+    let second = attempt_times(segments, second_index, method);
+
+    let (mut first_wins, mut second_wins, mut ties) = (0, 0, 0);
+
+    let segments = first
+        .into_iter()
+        .zip(second)
+        .map(|(first, second)| {
+            let segment_delta = catch! { first.segment_time? - second.segment_time? };
+            let split_delta = catch! { first.split_time? - second.split_time? };
+
+            let winner = segment_delta.map(|delta| {
+                if delta < TimeSpan::zero() {
+                    first_wins += 1;
+                    Winner::First
+                } else if delta > TimeSpan::zero() {
+                    second_wins += 1;
+                    Winner::Second
+                } else {
+                    ties += 1;
+                    Winner::Tie
+                }
+            });
+
+            SegmentComparison {
+                first,
+                second,
+                segment_delta,
+                split_delta,
+                winner,
+            }
+        })
+        .collect();
+
+    HeadToHead {
+        segments,
+        first_wins,
+        second_wins,
+        ties,
+    }
+}
+
+fn attempt_times(segments: &[Segment], index: i32, method: TimingMethod) -> Vec<AttemptTimes> {
+    attempts::reconstruct(segments, index)
+        .into_iter()
+        .map(|times| AttemptTimes {
+            segment_time: times.segment_time[method],
+            split_time: times.split_time[method],
+        })
+        .collect()
+}
@@ -1,12 +1,19 @@
 //! The analysis module provides a variety of functions for calculating
 //! information about runs.
 
+pub mod attempts;
+pub mod consistency;
 pub mod current_pace;
 pub mod delta;
+pub mod head_to_head;
+pub mod pb_history;
+pub mod playtime_breakdown;
 pub mod possible_time_save;
 pub mod state_helper;
 pub mod sum_of_segments;
+pub mod survival;
 pub mod total_playtime;
+pub mod trend;
 
 pub use self::state_helper::*;
 
@@ -0,0 +1,63 @@
+//! Provides functionality for reconstructing the history of the Personal Best.
+//! A Run only stores the split times of its current Personal Best, but every
+//! finished attempt in the Attempt History that was faster than all the
+//! finished attempts before it used to be the Personal Best at the time. Those
+//! attempts make up the progression of the Personal Best. Their split times can
+//! be reconstructed from the Segment History, so that they can be used as a
+//! comparison.
+
+use super::attempts;
+use crate::{AtomicDateTime, Run, Time, TimeSpan, TimingMethod};
+
+/// Describes a single attempt that was a Personal Best at the time it was
+/// achieved.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PersonalBest {
+    /// The index of the attempt. This is the amount of attempts that have been
+    /// started at the time the Personal Best was achieved.
+    pub attempt_index: i32,
+    /// The point in time the attempt ended at, or if that is not known, the
+    /// point in time it started at. This is `None` if no dates are stored for
+    /// the attempt.
+    pub date: Option<AtomicDateTime>,
+    /// The final time of the attempt.
+    pub time: TimeSpan,
+    /// The amount of time the attempt improved the previous Personal Best by.
+    /// This is `None` for the first Personal Best.
+    pub improvement: Option<TimeSpan>,
+}
+
+/// Calculates the progression of the Personal Best for the timing method
+/// provided. The Personal Bests are ordered from the oldest to the most recent
+/// one, so the last one is the current Personal Best, as long as it is still
+/// stored in the Attempt History.
+pub fn calculate(run: &Run, method: TimingMethod) -> Vec<PersonalBest> {
+    let mut history = Vec::<PersonalBest>::new();
+
+    for attempt in run.attempt_history() {
+        if let Some(time) = attempt.time()[method] {
+            let previous = history.last().map(|pb| pb.time);
+            if previous.map_or(true, |previous| time < previous) {
+                history.push(PersonalBest {
+                    attempt_index: attempt.index(),
+                    date: attempt.ended().or_else(|| attempt.started()),
+                    time,
+                    improvement: previous.map(|previous| previous - time),
+                });
+            }
+        }
+    }
+
+    history
+}
+
+/// Reconstructs the split times of the attempt with the given index from the
+/// Segment History. This is meant to be used with the attempt indices of the
+/// Personal Best progression, so that an earlier Personal Best can be compared
+/// against. Skipped segments don't have a split time.
+pub fn split_times(run: &Run, attempt_index: i32) -> Vec<Time> {
+    attempts::reconstruct(run.segments(), attempt_index)
+        .into_iter()
+        .map(|times| times.split_time)
+        .collect()
+}
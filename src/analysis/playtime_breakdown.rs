@@ -0,0 +1,118 @@
+//! Provides functionality for breaking down the playtime of a Run. While the
+//! total playtime sums up the playtime of all the attempts, the breakdown
+//! groups the attempts by calendar day, by calendar week or by session, so that
+//! the runner can see how much they played at which point in time. A session is
+//! a sequence of attempts where each attempt started less than a certain idle
+//! gap after the previous attempt ended.
+//!
+//! The attempts are grouped based on the point in time they started at.
+//! Attempts without a known start date can't be grouped and are therefore not
+//! part of the breakdown.
+
+use super::total_playtime::attempt_playtime;
+use crate::{Attempt, DateTime, Run, TimeSpan, Utc};
+use chrono::{Datelike, Duration, NaiveDate, TimeZone};
+use std::collections::BTreeMap;
+
+/// The playtime and the amount of attempts of a group of attempts.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Playtime {
+    /// The sum of the playtime of all the attempts in the group.
+    pub playtime: TimeSpan,
+    /// The amount of attempts in the group.
+    pub attempt_count: usize,
+}
+
+impl Playtime {
+    fn add(&mut self, playtime: TimeSpan) {
+        self.playtime += playtime;
+        self.attempt_count += 1;
+    }
+}
+
+/// A sequence of attempts without a large idle gap between them.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Session {
+    /// The point in time the first attempt of the session started at.
+    pub started: DateTime<Utc>,
+    /// The point in time the last attempt of the session ended at. If that is
+    /// not known, this is the point in time it started at instead.
+    pub ended: DateTime<Utc>,
+    /// The playtime and the amount of attempts of the session.
+    pub playtime: Playtime,
+}
+
+/// Groups the attempts by the calendar day they started on in the time zone
+/// provided. The days are ordered chronologically. Days without any attempts
+/// are not part of the result.
+pub fn by_day<Tz: TimeZone>(run: &Run, time_zone: &Tz) -> Vec<(NaiveDate, Playtime)> {
+    group_by(run, |started| {
+        started.with_timezone(time_zone).naive_local().date()
+    })
+}
+
+/// Groups the attempts by the calendar week they started in, in the time zone
+/// provided. Each week starts on a Monday and is identified by the date of
+/// that Monday. The weeks are ordered chronologically. Weeks without any
+/// attempts are not part of the result.
+pub fn by_week<Tz: TimeZone>(run: &Run, time_zone: &Tz) -> Vec<(NaiveDate, Playtime)> {
+    group_by(run, |started| {
+        let date = started.with_timezone(time_zone).naive_local().date();
+        date - Duration::days(date.weekday().num_days_from_monday().into())
+    })
+}
+
+/// Groups the attempts into sessions. An attempt belongs to the same session
+/// as the previous attempt if it started less than the idle gap provided after
+/// the previous attempt ended. The sessions are ordered chronologically.
+pub fn by_session(run: &Run, idle_gap: TimeSpan) -> Vec<Session> {
+    let mut attempts = dated_attempts(run).collect::<Vec<_>>();
+    attempts.sort_by_key(|&(started, _)| started);
+
+    let mut sessions = Vec::<Session>::new();
+
+    for (started, attempt) in attempts {
+        let ended = attempt.ended().map_or(started, |ended| ended.time);
+        let playtime = attempt_playtime(run, attempt);
+
+        match sessions.last_mut() {
+            Some(session) if TimeSpan::from(started - session.ended) < idle_gap => {
+                session.ended = session.ended.max(ended);
+                session.playtime.add(playtime);
+            }
+            _ => {
+                let mut session = Session {
+                    started,
+                    ended,
+                    playtime: Playtime::default(),
+                };
+                session.playtime.add(playtime);
+                sessions.push(session);
+            }
+        }
+    }
+
+    sessions
+}
+
+fn group_by<F>(run: &Run, mut key: F) -> Vec<(NaiveDate, Playtime)>
+where
+    F: FnMut(DateTime<Utc>) -> NaiveDate,
+{
+    let mut groups = BTreeMap::<_, Playtime>::new();
+
+    for (started, attempt) in dated_attempts(run) {
+        groups
+            .entry(key(started))
+            .or_default()
+            .add(attempt_playtime(run, attempt));
+    }
+
+    groups.into_iter().collect()
+}
+
+fn dated_attempts(run: &Run) -> impl Iterator<Item = (DateTime<Utc>, &Attempt)> {
+    run.attempt_history()
+        .iter()
+        .filter_map(|attempt| Some((attempt.started()?.time, attempt)))
+}
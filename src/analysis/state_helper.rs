@@ -69,30 +69,42 @@ pub fn comparison_segment_time(
     comparison: &str,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    let current_comparison_time = run.segment(segment_index).comparison(comparison)[method]?;
+    comparison_section_time(run, segment_index, segment_index, comparison, method)
+}
 
-    let previous_comparison_time = find_previous_non_empty_comparison_time(
-        &run.segments()[..segment_index],
-        comparison,
-        method,
-    )
-    .unwrap_or_default();
+/// Calculates the comparison's time for a section of segments with the timing
+/// method specified. The section starts at the segment with the start index
+/// and ends with the segment with the end index, both inclusive. This is not
+/// calculating the current attempt's section times.
+pub fn comparison_section_time(
+    run: &Run,
+    start_index: usize,
+    end_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let current_comparison_time = run.segment(end_index).comparison(comparison)[method]?;
+
+    let previous_comparison_time =
+        find_previous_non_empty_comparison_time(&run.segments()[..start_index], comparison, method)
+            .unwrap_or_default();
 
     Some(current_comparison_time - previous_comparison_time)
 }
 
-fn segment_delta(
+fn section_delta(
     run: &Run,
-    segment_index: usize,
+    start_index: usize,
+    end_index: usize,
     current_time: TimeSpan,
     comparison: &str,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    let segment_index_comparison = run.segment(segment_index).comparison(comparison)[method]?;
+    let segment_index_comparison = run.segment(end_index).comparison(comparison)[method]?;
 
     Some(
         find_previous_non_empty_split_and_comparison_time(
-            &run.segments()[..segment_index],
+            &run.segments()[..start_index],
             comparison,
             method,
         )
@@ -103,13 +115,13 @@ fn segment_delta(
     )
 }
 
-fn segment_time(
+fn section_time(
     run: &Run,
-    segment_index: usize,
+    start_index: usize,
     current_time: TimeSpan,
     method: TimingMethod,
 ) -> TimeSpan {
-    find_previous_non_empty_split_time(&run.segments()[..segment_index], method)
+    find_previous_non_empty_split_time(&run.segments()[..start_index], method)
         .map(|split_time| current_time - split_time)
         .unwrap_or(current_time)
 }
@@ -128,13 +140,7 @@ pub fn previous_segment_time(
     segment_index: usize,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    segment_time(
-        timer.run(),
-        segment_index,
-        timer.run().segment(segment_index).split_time()[method]?,
-        method,
-    )
-    .into()
+    previous_section_time(timer, segment_index, segment_index, method)
 }
 
 /// Gets the length of the last segment that leads up to a certain split, using
@@ -152,13 +158,7 @@ pub fn live_segment_time(
     segment_index: usize,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    segment_time(
-        timer.run(),
-        segment_index,
-        timer.current_time()[method]?,
-        method,
-    )
-    .into()
+    live_section_time(timer, segment_index, method)
 }
 
 /// Gets the amount of time lost or gained on a certain split.
@@ -176,13 +176,7 @@ pub fn previous_segment_delta(
     comparison: &str,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    segment_delta(
-        timer.run(),
-        segment_index,
-        timer.run().segment(segment_index).split_time()[method]?,
-        comparison,
-        method,
-    )
+    previous_section_delta(timer, segment_index, segment_index, comparison, method)
 }
 
 /// Gets the amount of time lost or gained on a certain split, using the live
@@ -201,9 +195,105 @@ pub fn live_segment_delta(
     comparison: &str,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    segment_delta(
+    live_section_delta(timer, segment_index, segment_index, comparison, method)
+}
+
+/// Gets the length of a section of segments that ends with a certain split.
+///
+/// - `timer`: The current timer.
+/// - `start_index`: The index of the first segment of the section.
+/// - `end_index`: The index of the split that represents the end of the
+///   section.
+/// - `method`: The timing method that you are using.
+///
+/// Returns the length of the section, returning None if the split at the end
+/// of the section is not completed yet.
+pub fn previous_section_time(
+    timer: &Timer,
+    start_index: usize,
+    end_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    section_time(
+        timer.run(),
+        start_index,
+        timer.run().segment(end_index).split_time()[method]?,
+        method,
+    )
+    .into()
+}
+
+/// Gets the length of a section of segments up until the current time.
+///
+/// - `timer`: The current timer.
+/// - `start_index`: The index of the first segment of the section.
+/// - `method`: The timing method that you are using.
+///
+/// Returns the live length of the section.
+pub fn live_section_time(
+    timer: &Timer,
+    start_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    section_time(
+        timer.run(),
+        start_index,
+        timer.current_time()[method]?,
+        method,
+    )
+    .into()
+}
+
+/// Gets the amount of time lost or gained on a section of segments.
+///
+/// - `timer`: The current timer.
+/// - `start_index`: The index of the first segment of the section.
+/// - `end_index`: The index of the split that represents the end of the
+///   section.
+/// - `comparison`: The comparison that you are comparing with.
+/// - `method`: The timing method that you are using.
+///
+/// Returns the delta of the section, returning None if the split at the end of
+/// the section is not completed yet.
+pub fn previous_section_delta(
+    timer: &Timer,
+    start_index: usize,
+    end_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    section_delta(
+        timer.run(),
+        start_index,
+        end_index,
+        timer.run().segment(end_index).split_time()[method]?,
+        comparison,
+        method,
+    )
+}
+
+/// Gets the amount of time lost or gained on a section of segments up until
+/// the current time.
+///
+/// - `timer`: The current timer.
+/// - `start_index`: The index of the first segment of the section.
+/// - `end_index`: The index of the split that represents the end of the
+///   section.
+/// - `comparison`: The comparison that you are comparing with.
+/// - `method`: The timing method that you are using.
+///
+/// Returns the live delta of the section.
+pub fn live_section_delta(
+    timer: &Timer,
+    start_index: usize,
+    end_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    section_delta(
         timer.run(),
-        segment_index,
+        start_index,
+        end_index,
         timer.current_time()[method]?,
         comparison,
         method,
@@ -8,6 +8,7 @@
 //! therefore a bit misleading, but sticks around for historical reasons.
 
 pub mod best;
+pub mod origin;
 pub mod worst;
 
 #[cfg(test)]
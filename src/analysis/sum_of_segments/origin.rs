@@ -0,0 +1,195 @@
+//! Provides functionality for figuring out where the Sum of Best Segments
+//! comes from. The Sum of Best Segments is made up of the best segment times
+//! and possibly some combined segment times of attempts where segments got
+//! skipped. For each of these segment times, the attempt that achieved it is
+//! looked up, so that the runner can sanity check the segment times that their
+//! Sum of Best Segments is based on.
+
+use super::{best, Prediction};
+use crate::{AtomicDateTime, Run, Segment, Time, TimeSpan, TimingMethod};
+
+/// Describes where the segment time that is part of the Sum of Best Segments
+/// was achieved.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Source {
+    /// The segment time was achieved in an attempt that is stored in the
+    /// Segment History.
+    Attempt {
+        /// The index of the attempt. Indices below 1 don't belong to an actual
+        /// attempt, but were created by route changes and similar algorithmic
+        /// changes.
+        index: i32,
+        /// The point in time the attempt ended at, or if that is not known,
+        /// the point in time it started at. This is `None` if the attempt is
+        /// not part of the Attempt History or no dates are stored for it.
+        date: Option<AtomicDateTime>,
+    },
+    /// The segment time is based on the split times of the Personal Best.
+    PersonalBest,
+    /// The segment time is based on the split times of the current attempt.
+    CurrentAttempt,
+    /// The segment time couldn't be associated with any attempt. This may be
+    /// the case if the best segment time has been edited manually.
+    Unknown,
+}
+
+/// Describes a single segment time that the Sum of Best Segments is made up
+/// of. If segments got skipped, this may be a combined segment time that spans
+/// multiple segments.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Origin {
+    /// The index of the first segment that is covered by the segment time.
+    pub start_index: usize,
+    /// The index of the last segment that is covered by the segment time. This
+    /// is the same as the start index, unless it is a combined segment time.
+    pub end_index: usize,
+    /// The segment time that is part of the Sum of Best Segments.
+    pub time: TimeSpan,
+    /// Where the segment time was achieved.
+    pub source: Source,
+}
+
+/// Calculates the Sum of Best Segments for the timing method provided and
+/// returns the segment times it is made up of, along with the attempts they
+/// were achieved in. The segment times are ordered from the start of the run to
+/// the end of the run. The parameters are the same as the ones for
+/// `calculate_best`. If there is no Sum of Best Segments, no segment times are
+/// returned. If multiple attempts achieved the same segment time, the earliest
+/// attempt is considered the one that set it.
+pub fn calculate_best(
+    run: &Run,
+    simple_calculation: bool,
+    use_current_run: bool,
+    method: TimingMethod,
+) -> Vec<Origin> {
+    let segments = run.segments();
+    let mut predictions = vec![None; segments.len() + 1];
+    best::calculate(
+        segments,
+        &mut predictions,
+        simple_calculation,
+        use_current_run,
+        method,
+    );
+
+    let mut origins = Vec::new();
+    let mut end = segments.len();
+
+    while let Some(Prediction { time, predecessor }) = predictions[end] {
+        if end == 0 {
+            break;
+        }
+
+        let start_time = predictions[predecessor].map_or(TimeSpan::zero(), |p| p.time);
+        let time = time - start_time;
+        let (start_index, end_index) = (predecessor, end - 1);
+
+        origins.push(Origin {
+            start_index,
+            end_index,
+            time,
+            source: find_source(run, start_index, end_index, time, use_current_run, method),
+        });
+
+        end = predecessor;
+    }
+
+    origins.reverse();
+    origins
+}
+
+fn find_source(
+    run: &Run,
+    start_index: usize,
+    end_index: usize,
+    time: TimeSpan,
+    use_current_run: bool,
+    method: TimingMethod,
+) -> Source {
+    let segments = run.segments();
+
+    let history = segments[end_index].segment_history();
+
+    let found_attempt = history.iter().find(|&&(index, segment_time)| {
+        segment_time[method] == Some(time)
+            && covers_range(segments, start_index, end_index, method, |s| {
+                s.segment_history().get(index)
+            })
+    });
+
+    if let Some(&(index, _)) = found_attempt {
+        let date = run
+            .attempt_history()
+            .iter()
+            .find(|a| a.index() == index)
+            .and_then(|a| a.ended().or_else(|| a.started()));
+
+        Source::Attempt { index, date }
+    } else if split_times_match(segments, start_index, end_index, time, method, |s| {
+        s.personal_best_split_time()
+    }) {
+        Source::PersonalBest
+    } else if use_current_run
+        && split_times_match(segments, start_index, end_index, time, method, |s| {
+            s.split_time()
+        })
+    {
+        Source::CurrentAttempt
+    } else {
+        Source::Unknown
+    }
+}
+
+/// Checks whether the segment times provided by the closure describe a single
+/// segment time that starts at the first segment and ends at the last segment.
+/// All the segments in between need to be skipped, while the segment before
+/// the first segment may not be skipped.
+fn covers_range<F>(
+    segments: &[Segment],
+    start_index: usize,
+    end_index: usize,
+    method: TimingMethod,
+    get_time: F,
+) -> bool
+where
+    F: Fn(&Segment) -> Option<Time>,
+{
+    let previous_skipped = catch! {
+        get_time(&segments[start_index.checked_sub(1)?])?[method].is_none()
+    }
+    .unwrap_or(false);
+
+    !previous_skipped
+        && segments[start_index..end_index]
+            .iter()
+            .all(|s| get_time(s).map_or(false, |t| t[method].is_none()))
+}
+
+/// Checks whether the split times provided by the closure result in the given
+/// segment time between the start of the first segment and the end of the last
+/// segment, with all the segments in between being skipped.
+fn split_times_match<F>(
+    segments: &[Segment],
+    start_index: usize,
+    end_index: usize,
+    time: TimeSpan,
+    method: TimingMethod,
+    get_split_time: F,
+) -> bool
+where
+    F: Fn(&Segment) -> Time,
+{
+    let start_time = match start_index.checked_sub(1) {
+        Some(index) => get_split_time(&segments[index])[method],
+        None => Some(TimeSpan::zero()),
+    };
+
+    catch! {
+        let end_time = get_split_time(&segments[end_index])[method]?;
+        end_time - start_time? == time
+            && segments[start_index..end_index]
+                .iter()
+                .all(|s| get_split_time(s)[method].is_none())
+    }
+    .unwrap_or(false)
+}
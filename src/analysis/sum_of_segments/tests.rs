@@ -1,3 +1,4 @@
+use super::origin::{self, Origin, Source};
 use super::{best, Prediction};
 use crate::{
     comparison::best_segments,
@@ -127,3 +128,67 @@ pub fn sum_of_best() {
         [(5.0, 0, true), (8.0, 1, true), (13.0, 2, true)],
     );
 }
+
+#[test]
+pub fn origins_of_sum_of_best() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0), Some(60.0)]);
+    run_with_splits_opt(&mut timer, &[None, Some(10.0), None]);
+    run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(30.0)]);
+
+    let origins = origin::calculate_best(timer.run(), false, false, TimingMethod::GameTime);
+    let indices = origins
+        .iter()
+        .map(|o| match o.source {
+            Source::Attempt { index, date } => {
+                assert!(date.is_some());
+                index
+            }
+            _ => panic!("The segment time should come from an attempt"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        origins
+            .iter()
+            .map(|o| (o.start_index, o.end_index, o.time))
+            .collect::<Vec<_>>(),
+        [(0, 0, span(5.0)), (1, 2, span(20.0))]
+    );
+    assert_eq!(indices, [1, 3]);
+}
+
+#[test]
+pub fn origins_of_personal_best() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0)]);
+    let mut run = timer.into_run(true);
+    run.clear_history();
+
+    let origins = origin::calculate_best(&run, false, false, TimingMethod::GameTime);
+    assert_eq!(
+        origins,
+        [
+            Origin {
+                start_index: 0,
+                end_index: 0,
+                time: span(5.0),
+                source: Source::PersonalBest,
+            },
+            Origin {
+                start_index: 1,
+                end_index: 1,
+                time: span(15.0),
+                source: Source::PersonalBest,
+            },
+        ]
+    );
+}
+
+#[test]
+pub fn no_origins_without_sum_of_best() {
+    let timer = create_timer(&["A", "B"]);
+    assert!(origin::calculate_best(timer.run(), false, false, TimingMethod::GameTime).is_empty());
+}
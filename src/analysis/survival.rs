@@ -0,0 +1,54 @@
+//! Provides functionality for calculating how many of the attempts survive a
+//! segment or a section of segments, which means that they don't get reset
+//! before finishing it. This is based on the Attempt History and on how far
+//! each of the attempts made it according to the Segment History.
+
+use super::attempts::reached_segments;
+use crate::Run;
+
+/// Describes how many of the attempts survived a section of segments.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Survival {
+    /// The amount of attempts that made it to the start of the section.
+    pub started: usize,
+    /// The amount of attempts that got reset during the section.
+    pub resets: usize,
+}
+
+impl Survival {
+    /// The amount of attempts that made it past the end of the section.
+    pub fn survived(&self) -> usize {
+        self.started - self.resets
+    }
+
+    /// The percentage of the attempts that started the section and made it
+    /// past its end. `None` is returned if no attempt made it to the section.
+    pub fn rate(&self) -> Option<f64> {
+        if self.started == 0 {
+            None
+        } else {
+            Some(100.0 * self.survived() as f64 / self.started as f64)
+        }
+    }
+}
+
+/// Calculates how many of the attempts survived the section of segments from
+/// the start index up to and including the end index. For individual
+/// segments, the start index is the index of the segment itself. Skipping a
+/// segment counts as surviving it.
+pub fn calculate(run: &Run, start_index: usize, end_index: usize) -> Survival {
+    let segments = run.segments();
+    let mut survival = Survival::default();
+
+    for attempt in run.attempt_history() {
+        let reached = reached_segments(segments, attempt.index());
+        if reached >= start_index {
+            survival.started += 1;
+            if reached <= end_index {
+                survival.resets += 1;
+            }
+        }
+    }
+
+    survival
+}
@@ -0,0 +1,106 @@
+use super::super::attempts::{query, Filter};
+use crate::tests_helper::{create_run, create_timer, run_with_splits_opt, span};
+use crate::{AtomicDateTime, Time, TimeSpan, Utc};
+use chrono::TimeZone;
+
+#[test]
+fn reconstructs_attempts() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(30.0)]);
+    run_with_splits_opt(&mut timer, &[Some(6.0)]);
+
+    let run = timer.run();
+    let attempts = query(run, Filter::default()).collect::<Vec<_>>();
+    assert_eq!(attempts.len(), 2);
+
+    let first = &attempts[0];
+    assert_eq!(first.index(), 1);
+    assert!(first.is_finished());
+    assert_eq!(first.reached_segments(), 3);
+    assert!(first.started().is_some() && first.ended().is_some());
+
+    let game_times = first
+        .segments()
+        .iter()
+        .map(|s| (s.segment_time.game_time, s.split_time.game_time))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        game_times,
+        [
+            (Some(span(5.0)), Some(span(5.0))),
+            (None, None),
+            (None, Some(span(30.0))),
+        ]
+    );
+    assert!(first.segments()[0].split_time.real_time.is_some());
+
+    let second = &attempts[1];
+    assert!(!second.is_finished());
+    assert_eq!(second.reached_segments(), 1);
+    assert_eq!(second.segments()[0].split_time.game_time, Some(span(6.0)));
+    assert_eq!(second.segments()[1].split_time.game_time, None);
+}
+
+#[test]
+fn filters_attempts() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0), Some(30.0)]);
+    run_with_splits_opt(&mut timer, &[Some(6.0)]);
+    run_with_splits_opt(&mut timer, &[Some(6.0), Some(12.0)]);
+
+    let indices = |filter| {
+        query(timer.run(), filter)
+            .map(|a| a.index())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        indices(Filter {
+            finished_only: true,
+            ..Default::default()
+        }),
+        [1]
+    );
+    assert_eq!(
+        indices(Filter {
+            reached_segment: Some(1),
+            ..Default::default()
+        }),
+        [1, 3]
+    );
+    assert_eq!(
+        indices(Filter {
+            reached_segment: Some(0),
+            ..Default::default()
+        }),
+        [1, 2, 3]
+    );
+}
+
+#[test]
+fn filters_by_date() {
+    let mut run = create_run(&["A"]);
+    let date = |day| AtomicDateTime::new(Utc.ymd(2019, 5, day).and_hms(12, 0, 0), false);
+
+    for day in 1..=3 {
+        run.add_attempt(
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(10.0))),
+            Some(date(day)),
+            Some(date(day)),
+            None,
+        );
+    }
+    run.add_attempt(Time::new(), None, None, None);
+
+    let filter = Filter {
+        started_after: Some(date(2).time),
+        started_before: Some(date(3).time),
+        ..Default::default()
+    };
+    let indices = query(&run, filter).map(|a| a.index()).collect::<Vec<_>>();
+    assert_eq!(indices, [2]);
+
+    assert_eq!(query(&run, Filter::default()).count(), 4);
+}
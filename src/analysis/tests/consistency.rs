@@ -0,0 +1,77 @@
+use super::super::consistency::{calculate, calculate_ranked};
+use crate::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, span};
+use crate::{TimeSpan, TimingMethod};
+
+#[test]
+fn statistics() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    for &first in &[1.0, 2.0, 1.5, 1.5, 10.0] {
+        run_with_splits(&mut timer, &[first, first + 2.0]);
+    }
+
+    let segments = timer.run().segments();
+
+    let first = calculate(segments, 0, TimingMethod::GameTime, false).unwrap();
+    assert_eq!(first.segment_index, 0);
+    assert_eq!(first.sample_count, 5);
+    assert_eq!(first.mean, span(3.2));
+    assert_eq!(first.median, span(1.5));
+    assert_eq!(first.interquartile_range, span(0.5));
+    assert_eq!(first.average_time_lost, span(2.2));
+    assert!(first.standard_deviation > span(3.0));
+
+    let second = calculate(segments, 1, TimingMethod::GameTime, false).unwrap();
+    assert_eq!(second.sample_count, 5);
+    assert_eq!(second.mean, span(2.0));
+    assert_eq!(second.standard_deviation, TimeSpan::zero());
+    assert_eq!(second.average_time_lost, TimeSpan::zero());
+}
+
+#[test]
+fn excluding_outliers() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    for &first in &[1.0, 2.0, 1.5, 1.5, 10.0] {
+        run_with_splits(&mut timer, &[first, first + 2.0]);
+    }
+
+    let first = calculate(timer.run().segments(), 0, TimingMethod::GameTime, true).unwrap();
+    assert_eq!(first.sample_count, 4);
+    assert_eq!(first.mean, span(1.5));
+    assert_eq!(first.median, span(1.5));
+    assert_eq!(first.interquartile_range, span(0.25));
+    assert_eq!(first.average_time_lost, span(0.5));
+}
+
+#[test]
+fn skips_combined_segments() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits_opt(&mut timer, &[Some(1.0), Some(3.0)]);
+    run_with_splits_opt(&mut timer, &[None, Some(2.0)]);
+
+    let segments = timer.run().segments();
+    let second = calculate(segments, 1, TimingMethod::GameTime, false).unwrap();
+    assert_eq!(second.sample_count, 1);
+    assert_eq!(second.mean, span(2.0));
+}
+
+#[test]
+fn ranked_by_time_lost() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0]);
+    run_with_splits(&mut timer, &[1.0, 4.0, 6.0]);
+
+    let ranked = calculate_ranked(timer.run().segments(), TimingMethod::GameTime, false);
+    let order = ranked.iter().map(|s| s.segment_index).collect::<Vec<_>>();
+    assert_eq!(order, [1, 2, 0]);
+}
+
+#[test]
+fn empty_history() {
+    let timer = create_timer(&["A"]);
+    assert!(calculate(timer.run().segments(), 0, TimingMethod::GameTime, false).is_none());
+    assert!(calculate_ranked(timer.run().segments(), TimingMethod::GameTime, false).is_empty());
+}
@@ -0,0 +1,91 @@
+use super::super::head_to_head::{calculate, Winner};
+use crate::tests_helper::{create_timer, run_with_splits_opt, span};
+use crate::TimingMethod;
+
+#[test]
+fn compares_attempts() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0), Some(30.0)]);
+    run_with_splits_opt(&mut timer, &[Some(6.0), Some(18.0), Some(30.0)]);
+
+    let result = calculate(timer.run().segments(), 1, 2, TimingMethod::GameTime);
+
+    let segment_deltas = result
+        .segments
+        .iter()
+        .map(|s| s.segment_delta)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        segment_deltas,
+        [Some(span(-1.0)), Some(span(3.0)), Some(span(-2.0))]
+    );
+
+    let split_deltas = result
+        .segments
+        .iter()
+        .map(|s| s.split_delta)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        split_deltas,
+        [Some(span(-1.0)), Some(span(2.0)), Some(span(0.0))]
+    );
+
+    let winners = result.segments.iter().map(|s| s.winner).collect::<Vec<_>>();
+    assert_eq!(
+        winners,
+        [
+            Some(Winner::First),
+            Some(Winner::Second),
+            Some(Winner::First)
+        ]
+    );
+
+    assert_eq!(
+        (result.first_wins, result.second_wins, result.ties),
+        (2, 1, 0)
+    );
+    assert_eq!(result.final_delta(), Some(span(0.0)));
+}
+
+#[test]
+fn handles_skipped_segments_and_resets() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[None, Some(20.0), Some(30.0)]);
+    run_with_splits_opt(&mut timer, &[Some(6.0), Some(18.0)]);
+
+    let result = calculate(timer.run().segments(), 1, 2, TimingMethod::GameTime);
+
+    let first = &result.segments[1].first;
+    assert_eq!(first.segment_time, None);
+    assert_eq!(first.split_time, Some(span(20.0)));
+    assert_eq!(result.segments[1].split_delta, Some(span(2.0)));
+    assert_eq!(result.segments[1].winner, None);
+
+    assert_eq!(result.segments[2].second.split_time, None);
+    assert_eq!(result.segments[2].winner, None);
+
+    assert_eq!(
+        (result.first_wins, result.second_wins, result.ties),
+        (0, 0, 0)
+    );
+    assert_eq!(result.final_delta(), Some(span(2.0)));
+}
+
+#[test]
+fn missing_history_entries_are_skipped_segments() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0), Some(30.0)]);
+    run_with_splits_opt(&mut timer, &[Some(6.0), None, Some(31.0)]);
+
+    let result = calculate(timer.run().segments(), 1, 2, TimingMethod::GameTime);
+
+    assert_eq!(result.segments[1].second.segment_time, None);
+    assert_eq!(result.segments[1].second.split_time, None);
+    assert_eq!(result.segments[2].second.segment_time, None);
+    assert_eq!(result.segments[2].second.split_time, Some(span(31.0)));
+    assert_eq!(result.segments[2].split_delta, Some(span(-1.0)));
+    assert_eq!(result.segments[2].winner, None);
+}
@@ -1 +1,8 @@
+mod attempts;
+mod consistency;
 mod empty_run;
+mod head_to_head;
+mod pb_history;
+mod playtime_breakdown;
+mod survival;
+mod trend;
@@ -0,0 +1,70 @@
+use super::super::pb_history::{calculate, split_times};
+use crate::run::{Editor, ImportError};
+use crate::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, span};
+use crate::TimingMethod;
+
+#[test]
+fn progression() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits(&mut timer, &[5.0, 20.0]);
+    run_with_splits_opt(&mut timer, &[Some(4.0)]);
+    run_with_splits(&mut timer, &[6.0, 25.0]);
+    run_with_splits(&mut timer, &[4.0, 18.0]);
+    run_with_splits(&mut timer, &[4.0, 18.5]);
+    run_with_splits(&mut timer, &[3.0, 15.0]);
+
+    let history = calculate(timer.run(), TimingMethod::GameTime);
+    let pbs = history
+        .iter()
+        .map(|pb| (pb.attempt_index, pb.time, pb.improvement))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        pbs,
+        [
+            (1, span(20.0), None),
+            (4, span(18.0), Some(span(2.0))),
+            (6, span(15.0), Some(span(3.0))),
+        ]
+    );
+    assert!(history.iter().all(|pb| pb.date.is_some()));
+}
+
+#[test]
+fn historical_split_times() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(30.0)]);
+    run_with_splits(&mut timer, &[4.0, 10.0, 25.0]);
+    run_with_splits_opt(&mut timer, &[Some(3.0)]);
+
+    let run = timer.into_run(true);
+    let times = split_times(&run, 1)
+        .into_iter()
+        .map(|t| t.game_time)
+        .collect::<Vec<_>>();
+    assert_eq!(times, [Some(span(5.0)), None, Some(span(30.0))]);
+
+    let mut editor = Editor::new(run).unwrap();
+    for &attempt_index in &[3, 4] {
+        assert_eq!(
+            editor.import_historical_personal_best(attempt_index, "Unknown PB"),
+            Err(ImportError::NotAPersonalBest)
+        );
+    }
+    editor
+        .import_historical_personal_best(1, "First PB")
+        .unwrap();
+    assert!(editor
+        .import_historical_personal_best(1, "First PB")
+        .is_err());
+
+    let run = editor.close();
+    assert!(!run.custom_comparisons().iter().any(|c| c == "Unknown PB"));
+    assert_eq!(
+        run.segment(2).comparison("First PB").game_time,
+        Some(span(30.0))
+    );
+    assert_eq!(run.segment(1).comparison("First PB").game_time, None);
+}
@@ -0,0 +1,98 @@
+use super::super::playtime_breakdown::{by_day, by_session, by_week};
+use crate::tests_helper::{create_run, span};
+use crate::{AtomicDateTime, DateTime, Run, Time, Utc};
+use chrono::{FixedOffset, NaiveDate};
+
+fn date_time(text: &str) -> AtomicDateTime {
+    AtomicDateTime::new(text.parse::<DateTime<Utc>>().unwrap(), false)
+}
+
+fn run() -> Run {
+    let mut run = create_run(&["A"]);
+    for &(started, ended) in &[
+        ("2020-01-05T23:00:00Z", "2020-01-05T23:10:00Z"),
+        ("2020-01-05T23:20:00Z", "2020-01-05T23:50:00Z"),
+        ("2020-01-07T10:00:00Z", "2020-01-07T10:05:00Z"),
+    ] {
+        run.add_attempt(
+            Time::default(),
+            Some(date_time(started)),
+            Some(date_time(ended)),
+            None,
+        );
+    }
+    run.add_attempt(Time::default(), None, None, None);
+    run
+}
+
+#[test]
+fn grouped_by_day() {
+    let run = run();
+
+    let utc = by_day(&run, &Utc)
+        .into_iter()
+        .map(|(date, p)| (date, p.attempt_count, p.playtime))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        utc,
+        [
+            (
+                NaiveDate::from_ymd_opt(2020, 1, 5).unwrap(),
+                2,
+                span(2400.0)
+            ),
+            (NaiveDate::from_ymd_opt(2020, 1, 7).unwrap(), 1, span(300.0)),
+        ]
+    );
+
+    let time_zone = FixedOffset::east_opt(3600).unwrap();
+    let dates = by_day(&run, &time_zone)
+        .into_iter()
+        .map(|(date, p)| (date, p.attempt_count))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dates,
+        [
+            (NaiveDate::from_ymd_opt(2020, 1, 6).unwrap(), 2),
+            (NaiveDate::from_ymd_opt(2020, 1, 7).unwrap(), 1),
+        ]
+    );
+}
+
+#[test]
+fn grouped_by_week() {
+    let run = run();
+
+    let utc = by_week(&run, &Utc)
+        .into_iter()
+        .map(|(date, p)| (date, p.attempt_count))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        utc,
+        [
+            (NaiveDate::from_ymd_opt(2019, 12, 30).unwrap(), 2),
+            (NaiveDate::from_ymd_opt(2020, 1, 6).unwrap(), 1),
+        ]
+    );
+
+    let time_zone = FixedOffset::east_opt(3600).unwrap();
+    let weeks = by_week(&run, &time_zone);
+    assert_eq!(weeks.len(), 1);
+    assert_eq!(weeks[0].0, NaiveDate::from_ymd_opt(2020, 1, 6).unwrap());
+    assert_eq!(weeks[0].1.attempt_count, 3);
+}
+
+#[test]
+fn grouped_by_session() {
+    let run = run();
+
+    let sessions = by_session(&run, span(15.0 * 60.0));
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].started, date_time("2020-01-05T23:00:00Z").time);
+    assert_eq!(sessions[0].ended, date_time("2020-01-05T23:50:00Z").time);
+    assert_eq!(sessions[0].playtime.attempt_count, 2);
+    assert_eq!(sessions[0].playtime.playtime, span(2400.0));
+    assert_eq!(sessions[1].playtime.attempt_count, 1);
+
+    assert_eq!(by_session(&run, span(5.0 * 60.0)).len(), 3);
+}
@@ -0,0 +1,31 @@
+use super::super::survival::{calculate, Survival};
+use crate::tests_helper::{create_timer, run_with_splits_opt};
+
+fn survival(started: usize, resets: usize) -> Survival {
+    Survival { started, resets }
+}
+
+#[test]
+fn sections() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits_opt(&mut timer, &[Some(1.0), Some(2.0), Some(3.0)]);
+    run_with_splits_opt(&mut timer, &[Some(1.0)]);
+    // Skipping the last segment before resetting doesn't count as making it
+    // past the segment.
+    run_with_splits_opt(&mut timer, &[Some(1.0), None]);
+    run_with_splits_opt(&mut timer, &[None, Some(2.0), Some(3.0)]);
+
+    let run = timer.run();
+
+    assert_eq!(calculate(run, 0, 0), survival(4, 0));
+    assert_eq!(calculate(run, 1, 1), survival(4, 2));
+    assert_eq!(calculate(run, 0, 1), survival(4, 2));
+    assert_eq!(calculate(run, 2, 2), survival(2, 0));
+    assert_eq!(calculate(run, 1, 1).rate(), Some(50.0));
+}
+
+#[test]
+fn no_attempts() {
+    let timer = create_timer(&["A"]);
+    assert_eq!(calculate(timer.run(), 0, 0).rate(), None);
+}
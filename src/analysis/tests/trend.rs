@@ -0,0 +1,64 @@
+use super::super::trend::{final_time, segment, segments, Axis, Settings, Status};
+use crate::tests_helper::{create_timer, run_with_splits};
+use crate::TimingMethod;
+
+#[test]
+fn improving_then_plateaued() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    for &first in &[20.0, 18.0, 16.0, 14.0, 12.0, 10.0] {
+        run_with_splits(&mut timer, &[first, first + 5.0]);
+    }
+
+    let settings = Settings {
+        rolling_window: 2,
+        recent_window: 4,
+        ..Default::default()
+    };
+    let run = timer.run();
+
+    let first = segment(run, 0, TimingMethod::GameTime, &settings);
+    assert_eq!(first.status, Status::Improving);
+    assert_eq!(first.points.len(), 6);
+    assert_eq!(first.points[0].x, 1.0);
+    assert_eq!(first.points[1].rolling_average, 19.0);
+    let regression = first.regression.unwrap();
+    assert!((regression.slope + 2.0).abs() < 1e-9);
+    assert!((regression.r_squared - 1.0).abs() < 1e-9);
+
+    let second = segment(run, 1, TimingMethod::GameTime, &settings);
+    assert_eq!(second.status, Status::Plateaued);
+    assert_eq!(second.regression.unwrap().slope, 0.0);
+
+    let final_times = final_time(run, TimingMethod::GameTime, &settings);
+    assert_eq!(final_times.status, Status::Improving);
+    assert_eq!(final_times.points.last().unwrap().time, 15.0);
+
+    assert_eq!(segments(run, TimingMethod::GameTime, &settings).len(), 2);
+}
+
+#[test]
+fn insufficient_data() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let trend = final_time(timer.run(), TimingMethod::GameTime, &Settings::default());
+    assert_eq!(trend.status, Status::InsufficientData);
+    assert!(trend.regression.is_none());
+}
+
+#[test]
+fn plotted_against_dates() {
+    let mut timer = create_timer(&["A"]);
+    for _ in 0..3 {
+        run_with_splits(&mut timer, &[10.0]);
+    }
+
+    let settings = Settings {
+        axis: Axis::Date,
+        ..Default::default()
+    };
+    let trend = final_time(timer.run(), TimingMethod::GameTime, &settings);
+    assert_eq!(trend.points.len(), 3);
+    assert!(trend.points.iter().all(|p| p.x > 10_000.0));
+}
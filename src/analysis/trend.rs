@@ -0,0 +1,266 @@
+//! Provides functionality for analyzing how the times of a runner develop over
+//! the course of their attempts. For either the final times or the segment
+//! times of a segment, a rolling average and a linear regression are
+//! calculated. The linear regression of the most recent times is then used to
+//! determine whether the runner is still improving or has plateaued.
+//!
+//! All the times are provided in seconds, so that the trends can easily be
+//! serialized and graphed.
+
+use super::consistency;
+use crate::{Run, TimingMethod};
+use ordered_float::OrderedFloat;
+use std::collections::HashMap;
+
+const SECONDS_PER_DAY: f64 = 24.0 * 60.0 * 60.0;
+
+/// Describes what the times are plotted against.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Axis {
+    /// The times are plotted against the index of the attempt they were
+    /// achieved in.
+    AttemptIndex,
+    /// The times are plotted against the point in time they were achieved at,
+    /// measured in days since the Unix epoch. Times of attempts without a known
+    /// date are not taken into account.
+    Date,
+}
+
+/// The settings for the trend analysis.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    /// What the times are plotted against.
+    pub axis: Axis,
+    /// The amount of times the rolling average is calculated over.
+    pub rolling_window: usize,
+    /// The amount of most recent times that are used for determining the
+    /// current status.
+    pub recent_window: usize,
+    /// The relative change of the most recent times, compared to their average,
+    /// that is still considered a plateau. A value of `0.01` means that the
+    /// times need to change by more than 1% over the recent window in order to
+    /// be considered improving or declining.
+    pub plateau_threshold: f64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            axis: Axis::AttemptIndex,
+            rolling_window: 10,
+            recent_window: 20,
+            plateau_threshold: 0.01,
+        }
+    }
+}
+
+/// A single time that is part of a trend.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    /// The index of the attempt the time was achieved in.
+    pub attempt_index: i32,
+    /// The position of the time on the axis the trend is plotted against.
+    pub x: f64,
+    /// The time in seconds.
+    pub time: f64,
+    /// The average of this time and the times before it that are within the
+    /// rolling window, in seconds.
+    pub rolling_average: f64,
+}
+
+/// A linear regression that describes the times as a linear function of the
+/// axis they are plotted against.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Regression {
+    /// The amount of seconds the times change by per unit on the axis. A
+    /// negative slope means the times are getting faster.
+    pub slope: f64,
+    /// The time in seconds at the origin of the axis.
+    pub intercept: f64,
+    /// The coefficient of determination, which describes how well the
+    /// regression fits the times. It ranges from 0 to 1.
+    pub r_squared: f64,
+}
+
+impl Regression {
+    /// Calculates the time in seconds the regression predicts at the position
+    /// on the axis provided.
+    pub fn predict(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+/// Describes how the most recent times are developing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Status {
+    /// There are not enough times to determine a trend.
+    InsufficientData,
+    /// The times are getting faster.
+    Improving,
+    /// The times are neither getting notably faster nor slower.
+    Plateaued,
+    /// The times are getting slower.
+    Declining,
+}
+
+/// The trend of a series of times.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Trend {
+    /// All the times the trend is based on, ordered by their position on the
+    /// axis.
+    pub points: Vec<Point>,
+    /// The regression over all the times. This is `None` if there are less
+    /// than two distinct positions on the axis.
+    pub regression: Option<Regression>,
+    /// The regression over the most recent times.
+    pub recent_regression: Option<Regression>,
+    /// How the most recent times are developing.
+    pub status: Status,
+}
+
+/// Calculates the trend of the final times of all the finished attempts.
+pub fn final_time(run: &Run, method: TimingMethod, settings: &Settings) -> Trend {
+    let times = run
+        .attempt_history()
+        .iter()
+        .filter_map(|a| Some((a.index(), a.time()[method]?.total_seconds())));
+
+    calculate(run, times, settings)
+}
+
+/// Calculates the trend of the segment times of the segment with the given
+/// index. Combined segment times, where the previous segment got skipped, are
+/// not taken into account.
+///
+/// # Panics
+///
+/// This panics if the segment index provided is out of bounds.
+pub fn segment(
+    run: &Run,
+    segment_index: usize,
+    method: TimingMethod,
+    settings: &Settings,
+) -> Trend {
+    let times = consistency::segment_times(run.segments(), segment_index, method)
+        .map(|(index, time)| (index, time.total_seconds()));
+
+    calculate(run, times, settings)
+}
+
+/// Calculates the trends of the segment times of all the segments.
+pub fn segments(run: &Run, method: TimingMethod, settings: &Settings) -> Vec<Trend> {
+    (0..run.len())
+        .map(|index| segment(run, index, method, settings))
+        .collect()
+}
+
+fn calculate<I>(run: &Run, times: I, settings: &Settings) -> Trend
+where
+    I: IntoIterator<Item = (i32, f64)>,
+{
+    let dates = match settings.axis {
+        Axis::AttemptIndex => HashMap::new(),
+        Axis::Date => run
+            .attempt_history()
+            .iter()
+            .filter_map(|a| {
+                let date = a.ended().or_else(|| a.started())?.time;
+                Some((a.index(), date.timestamp() as f64 / SECONDS_PER_DAY))
+            })
+            .collect(),
+    };
+
+    let mut points = times
+        .into_iter()
+        .filter_map(|(attempt_index, time)| {
+            let x = match settings.axis {
+                Axis::AttemptIndex => f64::from(attempt_index),
+                Axis::Date => *dates.get(&attempt_index)?,
+            };
+            Some(Point {
+                attempt_index,
+                x,
+                time,
+                rolling_average: time,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    points.sort_by_key(|p| OrderedFloat(p.x));
+
+    let window = settings.rolling_window.max(1);
+    let (mut sum, mut count) = (0.0, 0);
+    for i in 0..points.len() {
+        sum += points[i].time;
+        if count == window {
+            sum -= points[i - window].time;
+        } else {
+            count += 1;
+        }
+        points[i].rolling_average = sum / count as f64;
+    }
+
+    let regression = fit(&points);
+    let recent = &points[points.len().saturating_sub(settings.recent_window)..];
+    let recent_regression = fit(recent);
+
+    let status = match (recent_regression, recent.first(), recent.last()) {
+        (Some(regression), Some(first), Some(last)) if recent.len() >= 3 => {
+            let mean = recent.iter().map(|p| p.time).sum::<f64>() / recent.len() as f64;
+            let change = regression.predict(last.x) - regression.predict(first.x);
+            let threshold = settings.plateau_threshold * mean.abs();
+            if change < -threshold {
+                Status::Improving
+            } else if change > threshold {
+                Status::Declining
+            } else {
+                Status::Plateaued
+            }
+        }
+        _ => Status::InsufficientData,
+    };
+
+    Trend {
+        points,
+        regression,
+        recent_regression,
+        status,
+    }
+}
+
+/// Calculates the least squares regression of the times. `None` is returned if
+/// the times are not spread across at least two distinct positions.
+fn fit(points: &[Point]) -> Option<Regression> {
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.time).sum::<f64>() / n;
+
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    for p in points {
+        let (dx, dy) = (p.x - mean_x, p.time - mean_y);
+        sxx += dx * dx;
+        sxy += dx * dy;
+        syy += dy * dy;
+    }
+
+    if sxx <= 0.0 {
+        return None;
+    }
+
+    let slope = sxy / sxx;
+    let r_squared = if syy > 0.0 {
+        sxy * sxy / (sxx * syy)
+    } else {
+        1.0
+    };
+
+    Some(Regression {
+        slope,
+        intercept: mean_y - slope * mean_x,
+        r_squared,
+    })
+}
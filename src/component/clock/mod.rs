@@ -0,0 +1,304 @@
+//! Provides the Clock Component and relevant types for using it. The Clock
+//! Component shows the current time of day, along with the time of day at
+//! which the current attempt is estimated to finish based on the current pace.
+//! This is useful for marathons and races, where the schedule depends on when
+//! a run ends.
+
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+use crate::analysis::current_pace;
+use crate::platform::local_utc_offset;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::timing::formatter::DASH;
+use crate::{comparison, AtomicDateTime, Timer, TimerPhase};
+use chrono::{DateTime, FixedOffset, Utc};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Clock Component shows the current time of day, along with the time of
+/// day at which the current attempt is estimated to finish based on the
+/// current pace.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen for estimating when the attempt finishes. Uses
+    /// the Timer's current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// The time zone to show the times of day in, specified as a fixed offset
+    /// to UTC, such as `+02:00`, `-0530` or `UTC-5`. Named time zones, such as
+    /// `Europe/Berlin`, are not supported. As the offset is fixed, it doesn't
+    /// change with daylight saving time. Uses the local time zone, including
+    /// its daylight saving time, if set to `None` or if the offset can't be
+    /// parsed.
+    pub time_zone: Option<String>,
+    /// Specifies whether the times of day are shown in the 12-hour format
+    /// instead of the 24-hour format.
+    pub twelve_hour_format: bool,
+    /// Specifies whether the seconds of the times of day are shown.
+    pub show_seconds: bool,
+    /// Specifies whether the current time of day is shown.
+    pub show_current_time: bool,
+    /// Specifies whether the time of day at which the current attempt is
+    /// estimated to finish is shown.
+    pub show_estimated_finish: bool,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the times of day. If `None` is specified, the color is
+    /// taken from the layout.
+    pub value_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            comparison_override: None,
+            time_zone: None,
+            twelve_hour_format: false,
+            show_seconds: false,
+            show_current_time: true,
+            show_estimated_finish: true,
+            label_color: None,
+            value_color: None,
+        }
+    }
+}
+
+/// The state object that describes a single time of day to visualize.
+#[derive(Serialize, Deserialize)]
+pub struct TimeState {
+    /// The label describing the time of day.
+    pub name: String,
+    /// The time of day.
+    pub value: String,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the times of day. If `None` is specified, the color is
+    /// taken from the layout.
+    pub value_color: Option<Color>,
+    /// The times of day to visualize, in the order they are supposed to be
+    /// shown in.
+    pub times: Vec<TimeState>,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Clock Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Clock Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Clock".into()
+    }
+
+    /// Calculates the component's state based on the timer provided. If no
+    /// attempt is in progress, the estimated finish is based on starting an
+    /// attempt right now. Once the attempt ended, the time of day it finished
+    /// at is shown instead.
+    pub fn state(&self, timer: &Timer) -> State {
+        self.state_at(timer, AtomicDateTime::now())
+    }
+
+    fn state_at(&self, timer: &Timer, now: AtomicDateTime) -> State {
+        let mut times = Vec::with_capacity(2);
+
+        if self.settings.show_current_time {
+            times.push(TimeState {
+                name: "Current Time".into(),
+                value: self.format(now.time),
+            });
+        }
+
+        if self.settings.show_estimated_finish {
+            let (name, finish) = if timer.current_phase() == TimerPhase::Ended {
+                ("Finished At", timer.attempt_ended().map(|ended| ended.time))
+            } else {
+                let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+                let comparison = comparison::or_current(comparison, timer);
+                let current_time = timer.current_time()[timer.current_timing_method()];
+                let remaining = catch! {
+                    current_pace::calculate(timer, comparison)? - current_time?
+                };
+                (
+                    "Estimated Finish",
+                    remaining.map(|remaining| now.time + remaining.to_duration()),
+                )
+            };
+
+            times.push(TimeState {
+                name: name.into(),
+                value: finish.map_or_else(|| DASH.into(), |finish| self.format(finish)),
+            });
+        }
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            times,
+        }
+    }
+
+    fn format(&self, time: DateTime<Utc>) -> String {
+        // The local time zone's offset may differ between the times of day, as
+        // daylight saving time may start or end in between them.
+        let offset = self
+            .settings
+            .time_zone
+            .as_ref()
+            .and_then(|zone| parse_utc_offset(zone))
+            .unwrap_or_else(|| local_utc_offset(time));
+
+        let format = match (self.settings.twelve_hour_format, self.settings.show_seconds) {
+            (false, false) => "%H:%M",
+            (false, true) => "%H:%M:%S",
+            (true, false) => "%-I:%M %p",
+            (true, true) => "%-I:%M:%S %p",
+        };
+        time.with_timezone(&offset).format(format).to_string()
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new("Time Zone".into(), self.settings.time_zone.clone().into()),
+            Field::new(
+                "12-Hour Format".into(),
+                self.settings.twelve_hour_format.into(),
+            ),
+            Field::new("Show Seconds".into(), self.settings.show_seconds.into()),
+            Field::new(
+                "Show Current Time".into(),
+                self.settings.show_current_time.into(),
+            ),
+            Field::new(
+                "Show Estimated Finish".into(),
+                self.settings.show_estimated_finish.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.time_zone = value.into(),
+            3 => self.settings.twelve_hour_format = value.into(),
+            4 => self.settings.show_seconds = value.into(),
+            5 => self.settings.show_current_time = value.into(),
+            6 => self.settings.show_estimated_finish = value.into(),
+            7 => self.settings.label_color = value.into(),
+            8 => self.settings.value_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// Parses an offset to UTC, such as `+02:00`, `-0530`, `+9` or `UTC-5`. A
+/// plain `UTC` or `GMT` describes UTC itself.
+fn parse_utc_offset(text: &str) -> Option<FixedOffset> {
+    let text = text.trim();
+    let text = match text.get(..3) {
+        Some(prefix)
+            if prefix.eq_ignore_ascii_case("UTC") || prefix.eq_ignore_ascii_case("GMT") =>
+        {
+            text[3..].trim_start()
+        }
+        _ => text,
+    };
+
+    if text.is_empty() {
+        return FixedOffset::east_opt(0);
+    }
+
+    let (sign, text) = if text.starts_with('+') {
+        (1, &text[1..])
+    } else if text.starts_with('-') {
+        (-1, &text[1..])
+    } else if text.starts_with('−') {
+        (-1, &text['−'.len_utf8()..])
+    } else {
+        return None;
+    };
+
+    if !text.is_ascii() {
+        return None;
+    }
+
+    let (hours, minutes) = if let Some(colon) = text.find(':') {
+        (&text[..colon], &text[colon + 1..])
+    } else if text.len() > 2 {
+        text.split_at(text.len() - 2)
+    } else {
+        (text, "0")
+    };
+
+    let hours = hours.parse::<i32>().ok()?;
+    let minutes = minutes.parse::<i32>().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
@@ -0,0 +1,76 @@
+use super::{parse_utc_offset, Component, Settings};
+use crate::tests_helper::{create_timer, run_with_splits, start_run};
+use crate::{AtomicDateTime, TimeSpan};
+use chrono::{TimeZone, Utc};
+
+fn now() -> AtomicDateTime {
+    AtomicDateTime::new(Utc.ymd(2019, 5, 4).and_hms(13, 37, 0), false)
+}
+
+fn component(settings: Settings) -> Component {
+    Component::with_settings(Settings {
+        time_zone: Some("UTC".into()),
+        ..settings
+    })
+}
+
+#[test]
+fn utc_offsets() {
+    let offset = |text| parse_utc_offset(text).map(|o| o.local_minus_utc());
+    assert_eq!(offset("UTC"), Some(0));
+    assert_eq!(offset("+02:00"), Some(2 * 3600));
+    assert_eq!(offset("-0530"), Some(-(5 * 3600 + 30 * 60)));
+    assert_eq!(offset("UTC-5"), Some(-5 * 3600));
+    assert_eq!(offset("gmt +9"), Some(9 * 3600));
+    assert_eq!(offset("Europe/Berlin"), None);
+    assert_eq!(offset("+25"), None);
+}
+
+#[test]
+fn formats() {
+    let timer = create_timer(&["A"]);
+    let mut component = component(Settings {
+        show_estimated_finish: false,
+        ..Default::default()
+    });
+    assert_eq!(component.state_at(&timer, now()).times[0].value, "13:37");
+
+    component.settings_mut().twelve_hour_format = true;
+    component.settings_mut().show_seconds = true;
+    assert_eq!(
+        component.state_at(&timer, now()).times[0].value,
+        "1:37:00 PM"
+    );
+
+    component.settings_mut().time_zone = Some("+05:30".into());
+    assert_eq!(
+        component.state_at(&timer, now()).times[0].value,
+        "7:07:00 PM"
+    );
+
+    // Named time zones aren't supported, so the local time zone is used.
+    component.settings_mut().time_zone = Some("Europe/Berlin".into());
+    let named = component.state_at(&timer, now()).times[0].value.clone();
+    component.settings_mut().time_zone = None;
+    assert_eq!(component.state_at(&timer, now()).times[0].value, named);
+}
+
+#[test]
+fn estimated_finish() {
+    let mut timer = create_timer(&["A", "B"]);
+    let component = component(Settings {
+        show_current_time: false,
+        ..Default::default()
+    });
+
+    let state = component.state_at(&timer, now());
+    assert_eq!(state.times[0].name, "Estimated Finish");
+    assert_eq!(state.times[0].value, "—");
+
+    run_with_splits(&mut timer, &[600.0, 1800.0]);
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(300.0));
+
+    let state = component.state_at(&timer, now());
+    assert_eq!(state.times[0].value, "14:02");
+}
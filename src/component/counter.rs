@@ -0,0 +1,220 @@
+//! Provides the Counter Component and relevant types for using it. The Counter
+//! Component shows a named integer counter, such as the amount of deaths or
+//! collectibles. The counter itself is kept track of by the Timer, so that the
+//! Hotkey System can increment, decrement and reset it. Use
+//! [`Layout::register_counters`](crate::Layout::register_counters) to register
+//! the counters of a layout with a Timer.
+
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+use crate::hotkey::KeyCode;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::{Counter, Timer};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// The Counter Component shows a named integer counter, such as the amount of
+/// deaths or collectibles. The counter itself is kept track of by the Timer,
+/// so that the Hotkey System can increment, decrement and reset it.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The name of the counter. This is shown as the label of the component
+    /// and identifies the counter in the Timer.
+    pub name: String,
+    /// Specifies whether the counter is reset to 0 whenever the Timer is reset.
+    pub reset_on_timer_reset: bool,
+    /// Specifies whether the value of the counter is stored in the Run's
+    /// metadata variables, so that it persists across sessions when the
+    /// splits are saved.
+    pub store_in_metadata: bool,
+    /// The key to use for incrementing only this counter.
+    pub increment_hotkey: Option<KeyCode>,
+    /// The key to use for decrementing only this counter.
+    pub decrement_hotkey: Option<KeyCode>,
+    /// The key to use for resetting only this counter to 0.
+    pub reset_hotkey: Option<KeyCode>,
+    /// Specifies whether to display the name of the counter and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            name: String::from("Counter"),
+            reset_on_timer_reset: false,
+            store_in_metadata: false,
+            increment_hotkey: None,
+            decrement_hotkey: None,
+            reset_hotkey: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The name of the counter.
+    pub text: String,
+    /// The value of the counter.
+    pub value: String,
+    /// Specifies whether to display the name of the counter and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Counter Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Counter Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        if self.settings.name.is_empty() {
+            "Counter".into()
+        } else {
+            format!("Counter ({})", self.settings.name).into()
+        }
+    }
+
+    /// Creates the counter described by this component, so that it can be
+    /// added to a Timer.
+    pub fn counter(&self) -> Counter {
+        Counter {
+            name: self.settings.name.clone(),
+            value: 0,
+            reset_on_timer_reset: self.settings.reset_on_timer_reset,
+            store_in_metadata: self.settings.store_in_metadata,
+            increment_hotkey: self.settings.increment_hotkey,
+            decrement_hotkey: self.settings.decrement_hotkey,
+            reset_hotkey: self.settings.reset_hotkey,
+        }
+    }
+
+    /// Calculates the component's state based on the timer provided. If the
+    /// counter was not added to the timer, its value is shown as 0.
+    pub fn state(&self, timer: &Timer) -> State {
+        let value = timer
+            .counter(&self.settings.name)
+            .map_or(0, |counter| counter.value);
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            text: self.settings.name.clone(),
+            value: value.to_string(),
+            display_two_rows: self.settings.display_two_rows,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Name".into(), self.settings.name.clone().into()),
+            Field::new(
+                "Reset On Timer Reset".into(),
+                self.settings.reset_on_timer_reset.into(),
+            ),
+            Field::new(
+                "Store In Metadata".into(),
+                self.settings.store_in_metadata.into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new(
+                "Increment Hotkey".into(),
+                self.settings.increment_hotkey.into(),
+            ),
+            Field::new(
+                "Decrement Hotkey".into(),
+                self.settings.decrement_hotkey.into(),
+            ),
+            Field::new("Reset Hotkey".into(), self.settings.reset_hotkey.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value. The counter
+    /// needs to be registered with the Timer again for changes to the name,
+    /// the behavior and the hotkeys of the counter to take effect.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.name = value.into(),
+            2 => self.settings.reset_on_timer_reset = value.into(),
+            3 => self.settings.store_in_metadata = value.into(),
+            4 => self.settings.display_two_rows = value.into(),
+            5 => self.settings.label_color = value.into(),
+            6 => self.settings.value_color = value.into(),
+            7 => self.settings.increment_hotkey = value.into(),
+            8 => self.settings.decrement_hotkey = value.into(),
+            9 => self.settings.reset_hotkey = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
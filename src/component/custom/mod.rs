@@ -0,0 +1,217 @@
+//! Provides the extension point for components that are not part of this
+//! crate. A custom component implements the `CustomComponent` trait and can
+//! then be added to a layout like any of the built-in components. In order for
+//! a custom component to survive saving and loading the layout, a constructor
+//! for it needs to be registered with `register`.
+
+use crate::layout::GeneralSettings;
+use crate::settings::{Color, Gradient, SettingsDescription, Value};
+use crate::Timer;
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+use std::sync::RwLock;
+
+#[cfg(test)]
+mod tests;
+
+/// A component that is implemented outside of this crate. The component is
+/// identified by its ID, which is stored alongside its settings when the
+/// layout is saved, so it needs to be unique and stable across versions.
+pub trait CustomComponent: Send {
+    /// The unique ID of the component. This is used to find the constructor
+    /// for the component when loading a layout.
+    fn id(&self) -> &str;
+
+    /// Accesses the name of the component.
+    fn name(&self) -> Cow<'_, str>;
+
+    /// Calculates the component's state based on the timer and the layout
+    /// settings provided.
+    fn state(&mut self, timer: &Timer, layout_settings: &GeneralSettings) -> State;
+
+    /// Encodes the settings of the component as JSON, so that they can be
+    /// stored in the layout. The constructor registered for the component
+    /// receives these settings when the layout is loaded again.
+    fn settings(&self) -> serde_json::Value;
+
+    /// Provides a general description of the settings. Such a Settings
+    /// Description entirely describes all the settings that are available, what
+    /// type they are and what value they currently have. This provides a user
+    /// interface independent way of changing the settings.
+    fn settings_description(&self) -> SettingsDescription;
+
+    /// Changes a setting of the component based on its Settings Description
+    /// index.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the index doesn't match any setting provided by the
+    /// Settings Description of this component. Additionally, the value needs to
+    /// have a compatible type.
+    fn set_value(&mut self, index: usize, value: Value);
+
+    /// Tells the component to provide absolute information again, as if it
+    /// provides the state for the first time.
+    fn remount(&mut self) {}
+
+    /// Creates a copy of the component.
+    fn box_clone(&self) -> Box<dyn CustomComponent>;
+}
+
+impl Clone for Box<dyn CustomComponent> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+
+/// A constructor that creates a custom component out of the settings it
+/// previously stored in the layout.
+pub type Constructor = fn(serde_json::Value) -> Box<dyn CustomComponent>;
+
+static CONSTRUCTORS: RwLock<Vec<(String, Constructor)>> = RwLock::new(Vec::new());
+
+/// Registers the constructor for the custom component with the ID provided.
+/// Layouts that contain a component with this ID use the constructor to
+/// create the component when they are loaded. Registering a constructor for an
+/// ID that is already registered replaces the previous constructor.
+pub fn register<S: Into<String>>(id: S, constructor: Constructor) {
+    let id = id.into();
+    let mut constructors = CONSTRUCTORS.write().unwrap();
+    if let Some((_, c)) = constructors.iter_mut().find(|(i, _)| *i == id) {
+        *c = constructor;
+    } else {
+        constructors.push((id, constructor));
+    }
+}
+
+/// Creates the custom component described by the settings provided. If no
+/// constructor is registered for the component's ID, a placeholder component
+/// is created that keeps the settings around, so that they are not lost when
+/// the layout is saved again.
+pub fn from_settings(settings: Settings) -> Box<dyn CustomComponent> {
+    let constructor = CONSTRUCTORS
+        .read()
+        .unwrap()
+        .iter()
+        .find(|(id, _)| *id == settings.id)
+        .map(|&(_, constructor)| constructor);
+
+    if let Some(constructor) = constructor {
+        constructor(settings.settings)
+    } else {
+        Box::new(Unregistered { settings })
+    }
+}
+
+/// The Settings of a custom component, as they are stored in the layout.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Settings {
+    /// The unique ID of the component.
+    pub id: String,
+    /// The settings of the component, encoded by the component itself.
+    pub settings: serde_json::Value,
+}
+
+/// The state object describes the information to visualize for a custom
+/// component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The unique ID of the component.
+    pub id: String,
+    /// The preferred width of the component when the layout is shown
+    /// horizontally, in the component coordinate space.
+    pub width: f32,
+    /// The height of the component when the layout is shown vertically, in
+    /// the component coordinate space. The default height of a component is 1.
+    pub height: f32,
+    /// Additional information the component wants to expose to user
+    /// interfaces that know about the component.
+    pub data: serde_json::Value,
+    /// The primitives to draw, in the order they are supposed to be drawn in.
+    pub primitives: Vec<Primitive>,
+}
+
+/// A single drawing primitive of a custom component. All the coordinates are
+/// in the component coordinate space, so (0, 0) is the top left corner of the
+/// component.
+#[derive(Serialize, Deserialize)]
+pub enum Primitive {
+    /// A rectangle filled with a gradient.
+    Rectangle {
+        /// The top left corner of the rectangle.
+        top_left: [f32; 2],
+        /// The bottom right corner of the rectangle.
+        bottom_right: [f32; 2],
+        /// The gradient to fill the rectangle with.
+        background: Gradient,
+    },
+    /// A line of text.
+    Text {
+        /// The text to draw.
+        text: String,
+        /// The position of the text's baseline.
+        position: [f32; 2],
+        /// The size of the text. The default text size is 0.8.
+        size: f32,
+        /// How the text is aligned around its position. 0 means the text
+        /// starts at the position, 0.5 means it is centered and 1 means it
+        /// ends at the position.
+        align: f32,
+        /// The color of the text.
+        color: Color,
+    },
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+/// Stands in for a custom component whose constructor is not registered. It
+/// doesn't show anything, but keeps its settings for when the layout is saved.
+#[derive(Clone)]
+struct Unregistered {
+    settings: Settings,
+}
+
+impl CustomComponent for Unregistered {
+    fn id(&self) -> &str {
+        &self.settings.id
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        format!("Unknown Component ({})", self.settings.id).into()
+    }
+
+    fn state(&mut self, _timer: &Timer, _layout_settings: &GeneralSettings) -> State {
+        State {
+            id: self.settings.id.clone(),
+            width: 0.0,
+            height: 0.0,
+            data: serde_json::Value::Null,
+            primitives: Vec::new(),
+        }
+    }
+
+    fn settings(&self) -> serde_json::Value {
+        self.settings.settings.clone()
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::default()
+    }
+
+    fn set_value(&mut self, _index: usize, _value: Value) {
+        panic!("Unsupported Setting Index")
+    }
+
+    fn box_clone(&self) -> Box<dyn CustomComponent> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,146 @@
+use super::{register, CustomComponent, Primitive, State};
+use crate::layout::{ComponentState, GeneralSettings, Layout, LayoutSettings};
+use crate::settings::{Field, SettingsDescription, Value};
+use crate::tests_helper::create_timer;
+use crate::Timer;
+use serde_json::json;
+use std::borrow::Cow;
+
+#[derive(Clone)]
+struct Attempts {
+    label: String,
+}
+
+impl CustomComponent for Attempts {
+    fn id(&self) -> &str {
+        "tests.attempts"
+    }
+
+    fn name(&self) -> Cow<'_, str> {
+        "Attempts".into()
+    }
+
+    fn state(&mut self, timer: &Timer, layout_settings: &GeneralSettings) -> State {
+        let attempts = timer.run().attempt_count();
+        State {
+            id: self.id().to_owned(),
+            width: 6.0,
+            height: 1.0,
+            data: json!({ "attempts": attempts }),
+            primitives: vec![Primitive::Text {
+                text: format!("{}: {}", self.label, attempts),
+                position: [0.35, 0.7],
+                size: 0.8,
+                align: 0.0,
+                color: layout_settings.text_color,
+            }],
+        }
+    }
+
+    fn settings(&self) -> serde_json::Value {
+        json!({ "label": self.label })
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new(
+            "Label".into(),
+            self.label.clone().into(),
+        )])
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.label = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+
+    fn box_clone(&self) -> Box<dyn CustomComponent> {
+        Box::new(self.clone())
+    }
+}
+
+fn attempts(settings: serde_json::Value) -> Box<dyn CustomComponent> {
+    Box::new(Attempts {
+        label: settings["label"].as_str().unwrap_or("Attempts").to_owned(),
+    })
+}
+
+fn save_and_load(layout: &Layout) -> Layout {
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    Layout::from_settings(LayoutSettings::from_json(&buf[..]).unwrap())
+}
+
+fn label(state: &ComponentState) -> &str {
+    match state {
+        ComponentState::Custom(state) => match &state.primitives[0] {
+            Primitive::Text { text, .. } => text,
+            _ => panic!("Unexpected primitive"),
+        },
+        _ => panic!("Unexpected component"),
+    }
+}
+
+#[test]
+fn settings_can_be_changed() {
+    let timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(Box::new(Attempts {
+        label: "Attempts".into(),
+    }) as Box<dyn CustomComponent>);
+
+    let component = &mut layout.components[0].component;
+    assert_eq!(component.name(), "Attempts");
+    assert_eq!(component.settings_description().fields[0].text, "Label");
+    component.set_value(0, String::from("Tries").into());
+
+    let state = layout.state(&timer);
+    assert_eq!(label(&state.components[0].state), "Tries: 0");
+}
+
+#[test]
+fn registered_components_survive_saving_and_loading() {
+    register("tests.attempts", attempts);
+
+    let timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(Box::new(Attempts {
+        label: "Resets".into(),
+    }) as Box<dyn CustomComponent>);
+
+    let mut layout = save_and_load(&layout);
+
+    assert_eq!(layout.components[0].component.name(), "Attempts");
+    let state = layout.state(&timer);
+    assert_eq!(label(&state.components[0].state), "Resets: 0");
+    match &state.components[0].state {
+        ComponentState::Custom(state) => assert_eq!(state.data["attempts"], 0),
+        _ => panic!("Unexpected component"),
+    }
+}
+
+#[test]
+fn unregistered_components_keep_their_settings() {
+    let mut buf = Vec::new();
+    let mut layout = Layout::new();
+    layout.push(Box::new(Attempts {
+        label: "Resets".into(),
+    }) as Box<dyn CustomComponent>);
+    let mut settings = layout.settings();
+    settings.write_json(&mut buf).unwrap();
+    let json = String::from_utf8(buf)
+        .unwrap()
+        .replace("tests.attempts", "tests.unknown");
+    settings = LayoutSettings::from_json(json.as_bytes()).unwrap();
+
+    let layout = save_and_load(&Layout::from_settings(settings));
+
+    let component = &layout.components[0].component;
+    assert_eq!(component.name(), "Unknown Component (tests.unknown)");
+    assert!(component.settings_description().fields.is_empty());
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    let json = String::from_utf8(buf).unwrap();
+    assert!(json.contains(r#"{"Custom":{"id":"tests.unknown","settings":{"label":"Resets"}}"#));
+}
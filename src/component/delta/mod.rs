@@ -1,12 +1,13 @@
 //! Provides the Delta Component and relevant types for using it. The Delta
 //! Component is a component that shows the how far ahead or behind the current
-//! attempt is compared to the chosen comparison.
+//! attempt is compared to the chosen comparison. It can also show the deltas
+//! to multiple comparisons at once.
 
 use super::DEFAULT_INFO_TEXT_GRADIENT;
 use crate::analysis::{delta, state_helper};
 use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
 use crate::timing::formatter::{Accuracy, Delta, TimeFormatter};
-use crate::{comparison, GeneralLayoutSettings, Timer};
+use crate::{comparison, GeneralLayoutSettings, TimeSpan, Timer};
 use serde_json::{to_writer, Result};
 use std::borrow::Cow;
 use std::io::Write;
@@ -14,6 +15,8 @@ use std::io::Write;
 #[cfg(test)]
 mod tests;
 
+const SETTINGS_BEFORE_COMPARISONS: usize = 7;
+
 /// The Delta Component is a component that shows the how far ahead or behind
 /// the current attempt is compared to the chosen comparison.
 #[derive(Default, Clone)]
@@ -41,6 +44,10 @@ pub struct Settings {
     pub drop_decimals: bool,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The comparisons to show the deltas for, each in its own row. If this is
+    /// empty, only the delta to the comparison chosen by
+    /// `comparison_override` is shown.
+    pub comparisons: Vec<String>,
 }
 
 impl Default for Settings {
@@ -52,6 +59,7 @@ impl Default for Settings {
             label_color: None,
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
+            comparisons: Vec::new(),
         }
     }
 }
@@ -75,6 +83,23 @@ pub struct State {
     /// Specifies whether to display the name of the component and its value in
     /// two separate rows.
     pub display_two_rows: bool,
+    /// The deltas to each of the comparisons chosen in the settings that
+    /// exist. If this is not empty, these are shown in separate rows instead
+    /// of the single delta described by the other fields.
+    pub comparisons: Vec<ComparisonState>,
+}
+
+/// The state object describing the delta to a single comparison.
+#[derive(Serialize, Deserialize)]
+pub struct ComparisonState {
+    /// The shortened name of the comparison.
+    pub text: String,
+    /// The delta.
+    pub time: String,
+    /// The semantic coloring information the delta time carries.
+    pub semantic_color: SemanticColor,
+    /// The visual color of the delta time.
+    pub visual_color: Color,
 }
 
 impl State {
@@ -110,7 +135,15 @@ impl Component {
 
     /// Accesses the name of the component.
     pub fn name(&self) -> Cow<'_, str> {
-        if let Some(comparison) = &self.settings.comparison_override {
+        if !self.settings.comparisons.is_empty() {
+            let comparisons = self
+                .settings
+                .comparisons
+                .iter()
+                .map(|c| comparison::shorten(c))
+                .collect::<Vec<_>>();
+            format!("Delta ({})", comparisons.join(", ")).into()
+        } else if let Some(comparison) = &self.settings.comparison_override {
             format!("Delta ({})", comparison).into()
         } else {
             "Delta".into()
@@ -124,46 +157,42 @@ impl Component {
         let text = comparison.unwrap_or_else(|| timer.current_comparison());
         let comparison = comparison::or_current(comparison, timer);
 
-        let (delta, use_live_delta) = delta::calculate(timer, comparison);
-
-        let mut index = timer.current_split_index();
-        if !use_live_delta {
-            index = index.and_then(|i| i.checked_sub(1));
-        }
-
-        let semantic_color = if let Some(index) = index {
-            state_helper::split_color(
-                timer,
-                delta,
-                index,
-                true,
-                false,
-                comparison,
-                timer.current_timing_method(),
-            )
-        } else {
-            SemanticColor::Default
-        };
-
+        let (delta, semantic_color) = calculate(timer, comparison);
         let visual_color = semantic_color.visualize(layout_settings);
+        let formatter = Delta::custom(self.settings.drop_decimals, self.settings.accuracy);
+
+        let comparisons = self
+            .settings
+            .comparisons
+            .iter()
+            .filter_map(|c| timer.run().comparisons().find(|&rc| c == rc))
+            .map(|name| {
+                let (delta, semantic_color) = calculate(timer, name);
+                ComparisonState {
+                    text: comparison::shorten(name).to_owned(),
+                    time: formatter.format(delta).to_string(),
+                    semantic_color,
+                    visual_color: semantic_color.visualize(layout_settings),
+                }
+            })
+            .collect();
 
         State {
             background: self.settings.background,
             label_color: self.settings.label_color,
             text: text.to_string(),
-            time: Delta::custom(self.settings.drop_decimals, self.settings.accuracy)
-                .format(delta)
-                .to_string(),
+            time: formatter.format(delta).to_string(),
             semantic_color,
             visual_color,
             display_two_rows: self.settings.display_two_rows,
+            comparisons,
         }
     }
 
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut settings = SettingsDescription::with_fields(vec![
             Field::new("Background".into(), self.settings.background.into()),
             Field::new(
                 "Comparison".into(),
@@ -176,7 +205,20 @@ impl Component {
             Field::new("Label Color".into(), self.settings.label_color.into()),
             Field::new("Drop Decimals".into(), self.settings.drop_decimals.into()),
             Field::new("Accuracy".into(), self.settings.accuracy.into()),
-        ])
+            Field::new(
+                "Comparisons".into(),
+                Value::UInt(self.settings.comparisons.len() as _),
+            ),
+        ]);
+
+        settings.fields.extend(
+            self.settings
+                .comparisons
+                .iter()
+                .map(|c| Field::new("Comparison".into(), c.clone().into())),
+        );
+
+        settings
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -194,7 +236,43 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
-            _ => panic!("Unsupported Setting Index"),
+            6 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings.comparisons.resize(new_len, String::new());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_COMPARISONS;
+                if let Some(comparison) = self.settings.comparisons.get_mut(index) {
+                    *comparison = value.into();
+                } else {
+                    panic!("Unsupported Setting Index")
+                }
+            }
         }
     }
 }
+
+fn calculate(timer: &Timer, comparison: &str) -> (Option<TimeSpan>, SemanticColor) {
+    let (delta, use_live_delta) = delta::calculate(timer, comparison);
+
+    let mut index = timer.current_split_index();
+    if !use_live_delta {
+        index = index.and_then(|i| i.checked_sub(1));
+    }
+
+    let semantic_color = if let Some(index) = index {
+        state_helper::split_color(
+            timer,
+            delta,
+            index,
+            true,
+            false,
+            comparison,
+            timer.current_timing_method(),
+        )
+    } else {
+        SemanticColor::Default
+    };
+
+    (delta, semantic_color)
+}
@@ -45,3 +45,30 @@ fn comparison_text() {
         timer.current_comparison()
     );
 }
+
+#[test]
+fn multiple_comparisons() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Ok"));
+
+    let timer = Timer::new(run).unwrap();
+    let mut delta_comp = Component::new();
+    let settings = GeneralLayoutSettings::default();
+
+    assert!(delta_comp.state(&timer, &settings).comparisons.is_empty());
+
+    delta_comp.set_value(6, 3u64.into());
+    delta_comp.set_value(7, String::from("Personal Best").into());
+    delta_comp.set_value(8, String::from("Fake Comparison").into());
+    delta_comp.set_value(9, String::from("Best Segments").into());
+    assert_eq!(delta_comp.settings_description().fields.len(), 10);
+    assert_eq!(delta_comp.name(), "Delta (PB, Fake Comparison, Best)");
+
+    let state = delta_comp.state(&timer, &settings);
+    let texts = state
+        .comparisons
+        .iter()
+        .map(|c| c.text.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(texts, ["PB", "Best"]);
+}
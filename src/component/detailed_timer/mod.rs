@@ -249,6 +249,7 @@ impl Component {
                 top_color,
                 bottom_color,
                 height: self.settings.segment_timer.height,
+                is_time_up: false,
             },
             None => timer::State {
                 background,
@@ -258,6 +259,7 @@ impl Component {
                 top_color,
                 bottom_color,
                 height: self.settings.segment_timer.height,
+                is_time_up: false,
             },
         };
 
@@ -1,13 +1,19 @@
 //! Provides the Graph Component and relevant types for using it. The Graph
 //! Component visualizes how far the current attempt has been ahead or behind
 //! the chosen comparison throughout the whole attempt. All the individual
-//! deltas are shown as points in a graph.
+//! deltas are shown as points in a graph. Alternatively, the Graph Component
+//! can visualize how the segment times of the current segment are distributed
+//! or how the personal best improved over time.
 
 use crate::settings::{Color, Field, SettingsDescription, Value};
 use crate::{analysis, comparison, GeneralLayoutSettings, TimeSpan, Timer, TimerPhase};
 use serde_json::{to_writer, Result};
 use std::borrow::Cow;
 use std::io::Write;
+use std::iter;
+
+#[cfg(test)]
+mod tests;
 
 const GRAPH_EDGE_VALUE: f32 = 200.0;
 const GRAPH_EDGE_MIN: f32 = 5.0;
@@ -15,6 +21,28 @@ const GRAPH_EDGE_MIN: f32 = 5.0;
 const WIDTH: f32 = 180.0;
 const HEIGHT: f32 = 120.0;
 
+const SETTINGS_BEFORE_OVERLAY_COMPARISONS: usize = 16;
+
+/// Describes what the Graph Component visualizes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphMode {
+    /// Visualizes how far the current attempt has been ahead or behind the
+    /// chosen comparison throughout the whole attempt. Additional comparisons
+    /// and past attempts can be overlaid on top of it.
+    Delta,
+    /// Visualizes how the segment times of the current segment are
+    /// distributed.
+    Histogram,
+    /// Visualizes how the personal best improved over all the attempts.
+    PersonalBestProgression,
+}
+
+impl Default for GraphMode {
+    fn default() -> Self {
+        GraphMode::Delta
+    }
+}
+
 /// The Graph Component visualizes how far the current attempt has been ahead or
 /// behind the chosen comparison throughout the whole attempt. All the
 /// individual deltas are shown as points in a graph.
@@ -58,6 +86,21 @@ pub struct Settings {
     pub complete_fill_color: Color,
     /// The height of the graph.
     pub height: u32,
+    /// Describes what the graph visualizes.
+    pub mode: GraphMode,
+    /// The comparisons whose deltas to the chosen comparison are overlaid on
+    /// top of the graph. Only used in the Delta mode.
+    pub overlay_comparisons: Vec<String>,
+    /// The amount of the most recent attempts whose deltas to the chosen
+    /// comparison are overlaid on top of the graph. Only used in the Delta
+    /// mode.
+    pub overlay_attempts: u32,
+    /// The color of the lines of the comparisons and attempts overlaid on top
+    /// of the graph.
+    pub overlay_lines_color: Color,
+    /// The amount of bars the segment times are divided into. Only used in the
+    /// Histogram mode.
+    pub histogram_bars: u32,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -104,6 +147,25 @@ pub struct State {
     pub best_segment_color: Color,
     /// The height of the graph.
     pub height: u32,
+    /// Describes what the graph visualizes. In the Histogram mode, the bars
+    /// are visualized instead of the points.
+    pub mode: GraphMode,
+    /// The lines of the comparisons and attempts overlaid on top of the graph.
+    /// Connect the points of each of them to visualize them. This is only
+    /// used in the Delta mode.
+    pub overlays: Vec<Vec<Point>>,
+    /// The color of the lines of the comparisons and attempts overlaid on top
+    /// of the graph.
+    pub overlay_lines_color: Color,
+    /// The heights of the histogram's bars from the fastest to the slowest
+    /// segment times. The bars evenly divide the width of the graph and grow
+    /// from the bottom, or the top if the graph is flipped. This is only used
+    /// in the Histogram mode.
+    pub bars: Vec<f32>,
+    /// The index of the bar that contains the most recent segment time. Use the
+    /// graph lines color for it and the complete fill color for all the other
+    /// bars.
+    pub highlighted_bar: Option<usize>,
 }
 
 /// Describes a point on the graph to visualize.
@@ -132,6 +194,11 @@ impl Default for Settings {
             partial_fill_color: (1.0, 1.0, 1.0, 0.25).into(),
             complete_fill_color: (1.0, 1.0, 1.0, 0.4).into(),
             height: 80,
+            mode: GraphMode::Delta,
+            overlay_comparisons: Vec::new(),
+            overlay_attempts: 0,
+            overlay_lines_color: (1.0, 1.0, 1.0, 0.35).into(),
+            histogram_bars: 10,
         }
     }
 }
@@ -153,6 +220,7 @@ struct DrawInfo {
     max_delta: TimeSpan,
     min_delta: TimeSpan,
     is_live_delta_active: bool,
+    overlays: Vec<Vec<(TimeSpan, TimeSpan)>>,
 }
 
 impl Component {
@@ -187,6 +255,11 @@ impl Component {
     }
 
     fn text(&self, comparison: Option<&str>) -> Cow<'_, str> {
+        match self.settings.mode {
+            GraphMode::Delta => {}
+            GraphMode::Histogram => return "Graph (Histogram)".into(),
+            GraphMode::PersonalBestProgression => return "Graph (PB Progression)".into(),
+        }
         if let Some(comparison) = comparison {
             format!("Graph ({})", comparison::shorten(comparison)).into()
         } else {
@@ -197,6 +270,19 @@ impl Component {
     /// Calculates the component's state based on the timer and layout settings
     /// provided.
     pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut state = match self.settings.mode {
+            GraphMode::Delta => self.delta_state(timer, layout_settings),
+            GraphMode::Histogram => self.histogram_state(timer, layout_settings),
+            GraphMode::PersonalBestProgression => self.progression_state(timer, layout_settings),
+        };
+
+        self.make_uniform(&mut state);
+        self.flip(&mut state);
+
+        state
+    }
+
+    fn delta_state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(comparison, timer);
 
@@ -208,19 +294,150 @@ impl Component {
         self.calculate_final_split(timer, &mut draw_info);
         self.calculate_deltas(timer, comparison, &mut draw_info);
         self.check_live_segment_delta(timer, comparison, &mut draw_info);
+        self.calculate_overlays(timer, comparison, &mut draw_info);
 
-        let mut state = self.calculate_points(timer, &draw_info, layout_settings);
+        self.calculate_points(timer, &draw_info, layout_settings)
+    }
 
-        self.make_uniform(&mut state);
-        self.flip(&mut state);
+    fn histogram_state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let run = timer.run();
+        let method = timer.current_timing_method();
+        let segment_index = timer
+            .current_split_index()
+            .unwrap_or(0)
+            .min(run.len().saturating_sub(1));
 
-        state
+        let times = if segment_index < run.len() {
+            analysis::consistency::segment_times(run.segments(), segment_index, method)
+                .map(|(index, time)| (index, time.total_seconds()))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
+        let bar_count = self.settings.histogram_bars as usize;
+        let (mut bars, mut highlighted_bar) = (Vec::new(), None);
+
+        if !times.is_empty() && bar_count > 0 {
+            let min = times.iter().map(|&(_, t)| t).fold(f64::INFINITY, f64::min);
+            let max = times
+                .iter()
+                .map(|&(_, t)| t)
+                .fold(f64::NEG_INFINITY, f64::max);
+            let bar_index = |time: f64| {
+                if max > min {
+                    (((time - min) / (max - min) * bar_count as f64) as usize).min(bar_count - 1)
+                } else {
+                    bar_count / 2
+                }
+            };
+
+            let mut counts = vec![0u32; bar_count];
+            for &(_, time) in &times {
+                counts[bar_index(time)] += 1;
+            }
+            let highest = counts.iter().cloned().max().unwrap_or(0).max(1);
+
+            bars = counts
+                .into_iter()
+                .map(|count| count as f32 / highest as f32)
+                .collect();
+            highlighted_bar = times
+                .iter()
+                .max_by_key(|&&(index, _)| index)
+                .map(|&(_, time)| bar_index(time));
+        }
+
+        State {
+            bars,
+            highlighted_bar,
+            ..self.single_region_state(layout_settings)
+        }
+    }
+
+    fn progression_state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let attempts = timer.run().attempt_history();
+        let method = timer.current_timing_method();
+
+        let improvements = analysis::pb_history::calculate(timer.run(), method)
+            .into_iter()
+            .map(|pb| (pb.attempt_index, pb.time.total_seconds()))
+            .collect::<Vec<_>>();
+
+        let mut points = Vec::new();
+        if let (Some(&(first_index, slowest)), Some(&(last_index, fastest)), Some(last_attempt)) =
+            (improvements.first(), improvements.last(), attempts.last())
+        {
+            let attempt_range = (last_attempt.index() - first_index) as f32;
+            let x = |index: i32| {
+                if attempt_range > 0.0 {
+                    (index - first_index) as f32 / attempt_range * WIDTH
+                } else {
+                    0.0
+                }
+            };
+            let y = |time: f64| {
+                if slowest > fastest {
+                    ((slowest - time) / (slowest - fastest)) as f32
+                        * (HEIGHT - 2.0 * GRAPH_EDGE_MIN)
+                        + GRAPH_EDGE_MIN
+                } else {
+                    HEIGHT / 2.0
+                }
+            };
+
+            points.extend(improvements.iter().map(|&(index, time)| Point {
+                x: x(index),
+                y: y(time),
+                is_best_segment: false,
+            }));
+
+            if last_index != last_attempt.index() || attempt_range == 0.0 {
+                points.push(Point {
+                    x: WIDTH,
+                    y: y(fastest),
+                    is_best_segment: false,
+                });
+            }
+        }
+
+        State {
+            points,
+            ..self.single_region_state(layout_settings)
+        }
+    }
+
+    /// The Histogram and the PB Progression mode don't distinguish between
+    /// being ahead and behind, so the whole graph uses the ahead background
+    /// color and the points are filled towards the bottom.
+    fn single_region_state(&self, layout_settings: &GeneralLayoutSettings) -> State {
+        State {
+            points: Vec::new(),
+            horizontal_grid_lines: Vec::new(),
+            vertical_grid_lines: Vec::new(),
+            middle: HEIGHT,
+            is_live_delta_active: false,
+            is_flipped: self.settings.flip_graph,
+            top_background_color: self.settings.ahead_background_color,
+            bottom_background_color: self.settings.ahead_background_color,
+            grid_lines_color: self.settings.grid_lines_color,
+            graph_lines_color: self.settings.graph_lines_color,
+            partial_fill_color: self.settings.partial_fill_color,
+            complete_fill_color: self.settings.complete_fill_color,
+            best_segment_color: layout_settings.best_segment_color,
+            height: self.settings.height,
+            mode: self.settings.mode,
+            overlays: Vec::new(),
+            overlay_lines_color: self.settings.overlay_lines_color,
+            bars: Vec::new(),
+            highlighted_bar: None,
+        }
     }
 
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut settings = SettingsDescription::with_fields(vec![
             Field::new(
                 "Comparison".into(),
                 self.settings.comparison_override.clone().into(),
@@ -256,7 +473,33 @@ impl Component {
                 "Complete Fill Color".into(),
                 self.settings.complete_fill_color.into(),
             ),
-        ])
+            Field::new("Mode".into(), self.settings.mode.into()),
+            Field::new(
+                "Overlay Attempts".into(),
+                u64::from(self.settings.overlay_attempts).into(),
+            ),
+            Field::new(
+                "Overlay Lines Color".into(),
+                self.settings.overlay_lines_color.into(),
+            ),
+            Field::new(
+                "Histogram Bars".into(),
+                u64::from(self.settings.histogram_bars).into(),
+            ),
+            Field::new(
+                "Overlay Comparisons".into(),
+                Value::UInt(self.settings.overlay_comparisons.len() as _),
+            ),
+        ]);
+
+        settings.fields.extend(
+            self.settings
+                .overlay_comparisons
+                .iter()
+                .map(|c| Field::new("Comparison".into(), c.clone().into())),
+        );
+
+        settings
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -279,7 +522,24 @@ impl Component {
             8 => self.settings.graph_lines_color = value.into(),
             9 => self.settings.partial_fill_color = value.into(),
             10 => self.settings.complete_fill_color = value.into(),
-            _ => panic!("Unsupported Setting Index"),
+            11 => self.settings.mode = value.into(),
+            12 => self.settings.overlay_attempts = value.into_uint().unwrap() as _,
+            13 => self.settings.overlay_lines_color = value.into(),
+            14 => self.settings.histogram_bars = value.into_uint().unwrap() as _,
+            15 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .overlay_comparisons
+                    .resize(new_len, String::new());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_OVERLAY_COMPARISONS;
+                if let Some(comparison) = self.settings.overlay_comparisons.get_mut(index) {
+                    *comparison = value.into();
+                } else {
+                    panic!("Unsupported Setting Index")
+                }
+            }
         }
     }
 
@@ -294,7 +554,11 @@ impl Component {
 
         state.middle /= HEIGHT;
 
-        for point in &mut state.points {
+        for point in state
+            .points
+            .iter_mut()
+            .chain(state.overlays.iter_mut().flatten())
+        {
             point.x /= WIDTH;
             point.y /= HEIGHT;
         }
@@ -306,7 +570,11 @@ impl Component {
                 *y = 1.0 - *y;
             }
 
-            for point in &mut state.points {
+            for point in state
+                .points
+                .iter_mut()
+                .chain(state.overlays.iter_mut().flatten())
+            {
                 point.y = 1.0 - point.y;
             }
 
@@ -332,6 +600,7 @@ impl Component {
             self.make_grid_lines_list(graph_height, middle, grid_value_x, grid_value_y);
 
         let points = self.make_points_list(draw_info, timer, total_delta, graph_edge, graph_height);
+        let overlays = self.make_overlays(draw_info, total_delta, graph_edge, graph_height);
 
         let (top_background_color, bottom_background_color) = if self.settings.flip_graph {
             (
@@ -360,9 +629,55 @@ impl Component {
             complete_fill_color: self.settings.complete_fill_color,
             best_segment_color: layout_settings.best_segment_color,
             height: self.settings.height,
+            mode: GraphMode::Delta,
+            overlays,
+            overlay_lines_color: self.settings.overlay_lines_color,
+            bars: Vec::new(),
+            highlighted_bar: None,
         }
     }
 
+    fn make_overlays(
+        &self,
+        draw_info: &DrawInfo,
+        total_delta: TimeSpan,
+        graph_edge: f32,
+        graph_height: f32,
+    ) -> Vec<Vec<Point>> {
+        let height = |delta: TimeSpan| {
+            if total_delta != TimeSpan::zero() {
+                (delta.total_milliseconds() as f32
+                    - draw_info.max_delta.total_milliseconds() as f32)
+                    / total_delta.total_milliseconds() as f32
+                    * (graph_height - graph_edge)
+                    * 2.0
+                    + graph_edge
+            } else {
+                graph_height
+            }
+        };
+
+        draw_info
+            .overlays
+            .iter()
+            .map(|line| {
+                let start = Point {
+                    x: 0.0,
+                    y: height(TimeSpan::zero()),
+                    is_best_segment: false,
+                };
+                let points = line.iter().map(|&(split_time, delta)| Point {
+                    x: (split_time.total_milliseconds() as f32
+                        / draw_info.final_split.total_milliseconds() as f32)
+                        * WIDTH,
+                    y: height(delta),
+                    is_best_segment: false,
+                });
+                iter::once(start).chain(points).collect()
+            })
+            .collect()
+    }
+
     fn make_points_list(
         &self,
         draw_info: &DrawInfo,
@@ -676,4 +991,63 @@ impl Component {
             }
         }
     }
+
+    fn calculate_overlays(&self, timer: &Timer, comparison: &str, draw_info: &mut DrawInfo) {
+        let run = timer.run();
+        let timing_method = timer.current_timing_method();
+
+        for name in &self.settings.overlay_comparisons {
+            if let Some(other) = run.comparisons().find(|&c| c == name) {
+                let line = run
+                    .segments()
+                    .iter()
+                    .filter_map(|segment| {
+                        let split_time = segment.comparison(other)[timing_method]?;
+                        let delta = split_time - segment.comparison(comparison)[timing_method]?;
+                        Some((split_time, delta))
+                    })
+                    .collect();
+                draw_info.overlays.push(line);
+            }
+        }
+
+        let attempts = run.attempt_history();
+        let skip = attempts
+            .len()
+            .saturating_sub(self.settings.overlay_attempts as usize);
+        for attempt in &attempts[skip..] {
+            let line = analysis::attempts::reconstruct(run.segments(), attempt.index())
+                .into_iter()
+                .zip(run.segments())
+                .filter_map(|(times, segment)| {
+                    let split_time = times.split_time[timing_method]?;
+                    let delta = split_time - segment.comparison(comparison)[timing_method]?;
+                    Some((split_time, delta))
+                })
+                .collect();
+            draw_info.overlays.push(line);
+        }
+
+        if draw_info.final_split == TimeSpan::zero() {
+            draw_info.final_split = draw_info
+                .overlays
+                .iter()
+                .filter_map(|line| line.last())
+                .map(|&(split_time, _)| split_time)
+                .max()
+                .unwrap_or_else(TimeSpan::zero);
+        }
+
+        for line in &mut draw_info.overlays {
+            let final_split = draw_info.final_split;
+            line.retain(|&(split_time, _)| split_time <= final_split);
+            for &(_, delta) in line.iter() {
+                if delta > draw_info.max_delta {
+                    draw_info.max_delta = delta;
+                } else if delta < draw_info.min_delta {
+                    draw_info.min_delta = delta;
+                }
+            }
+        }
+    }
 }
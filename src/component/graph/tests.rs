@@ -0,0 +1,84 @@
+use super::{Component, GraphMode, Settings};
+use crate::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, start_run};
+use crate::{GeneralLayoutSettings, TimeSpan};
+
+#[test]
+fn histogram() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[12.0]);
+    run_with_splits(&mut timer, &[20.0]);
+
+    let component = Component::with_settings(Settings {
+        mode: GraphMode::Histogram,
+        histogram_bars: 2,
+        ..Default::default()
+    });
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    assert_eq!(state.bars, [1.0, 0.5]);
+    assert_eq!(state.highlighted_bar, Some(1));
+    assert!(state.points.is_empty());
+}
+
+#[test]
+fn histogram_ignores_combined_segment_times() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[5.0, 10.0]);
+    run_with_splits(&mut timer, &[5.0, 12.0]);
+    run_with_splits(&mut timer, &[5.0, 20.0]);
+    run_with_splits_opt(&mut timer, &[None, Some(100.0)]);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    timer.split();
+
+    let component = Component::with_settings(Settings {
+        mode: GraphMode::Histogram,
+        histogram_bars: 2,
+        ..Default::default()
+    });
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    assert_eq!(state.bars, [1.0, 0.5]);
+    assert_eq!(state.highlighted_bar, Some(1));
+}
+
+#[test]
+fn personal_best_progression() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[12.0]);
+    run_with_splits(&mut timer, &[8.0]);
+    run_with_splits(&mut timer, &[9.0]);
+
+    let component = Component::with_settings(Settings {
+        mode: GraphMode::PersonalBestProgression,
+        ..Default::default()
+    });
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    let points = state.points.iter().map(|p| p.x).collect::<Vec<_>>();
+    assert_eq!(points, [0.0, 2.0 / 3.0, 1.0]);
+    assert!(state.points[0].y < state.points[1].y);
+    assert_eq!(state.points[1].y, state.points[2].y);
+}
+
+#[test]
+fn overlaid_attempts() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[9.0, 21.0]);
+    run_with_splits(&mut timer, &[11.0, 19.0]);
+
+    let component = Component::with_settings(Settings {
+        overlay_attempts: 2,
+        overlay_comparisons: vec!["Best Segments".into(), "Fake Comparison".into()],
+        ..Default::default()
+    });
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    assert_eq!(state.overlays.len(), 3);
+    assert!(state.overlays.iter().all(|overlay| overlay.len() == 3));
+    assert_eq!(state.overlays[1].last().unwrap().x, 1.0);
+}
@@ -0,0 +1,164 @@
+//! Provides the Image Component and relevant types for using it. The Image
+//! Component shows an image, such as a logo or a piece of artwork. It can also
+//! be used to show an image behind a part of the layout.
+
+use crate::settings::{Field, Gradient, ImageScaling, ImageStyle, SettingsDescription, Value};
+use crate::{CachedImageId, Image, Timer};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// The Image Component shows an image, such as a logo or a piece of artwork.
+/// It can also be used to show an image behind a part of the layout.
+#[derive(Default, Clone)]
+pub struct Component {
+    image_id: CachedImageId,
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The image to show. If the image is empty, only the background is shown.
+    pub image: Image,
+    /// The size of the component.
+    pub size: u32,
+    /// Describes how the image is drawn.
+    pub style: ImageStyle,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            image: Image::default(),
+            size: 72,
+            style: ImageStyle {
+                scaling: ImageScaling::Fit,
+                ..ImageStyle::default()
+            },
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The unique ID of the image. As the image itself is only provided
+    /// whenever it changes, renderers can use the ID to look up the image they
+    /// cached. This is `None` if there is no image.
+    pub image_id: Option<usize>,
+    /// The image encoded as a Data URL. This value is only specified whenever
+    /// the image changes. If you explicitly want to query this value, remount
+    /// the component. The String itself may be empty. This indicates that
+    /// there is no image.
+    pub image_change: Option<String>,
+    /// The size of the component.
+    pub size: u32,
+    /// Describes how the image is drawn.
+    pub style: ImageStyle,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Image Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Image Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Image".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&mut self, _timer: &Timer) -> State {
+        let image = &self.settings.image;
+        State {
+            background: self.settings.background,
+            image_id: Some(image.id()).filter(|_| !image.is_empty()),
+            image_change: self
+                .image_id
+                .update_with(Some(&self.settings.image))
+                .map(str::to_owned),
+            size: self.settings.size,
+            style: self.settings.style,
+        }
+    }
+
+    /// Remounts the component as if it was freshly initialized. The image
+    /// shown by this component is only provided in the state objects whenever
+    /// the image changes or whenever the component's state is first queried.
+    /// Remounting returns the image again, whenever its state is queried the
+    /// next time.
+    pub fn remount(&mut self) {
+        self.image_id.reset();
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Image".into(), self.settings.image.clone().into()),
+            Field::new("Size".into(), u64::from(self.settings.size).into()),
+            Field::new(
+                "Opacity".into(),
+                f64::from(self.settings.style.opacity).into(),
+            ),
+            Field::new("Blur".into(), f64::from(self.settings.style.blur).into()),
+            Field::new("Scaling".into(), self.settings.style.scaling.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.image = value.into(),
+            2 => self.settings.size = value.into_uint().unwrap() as _,
+            3 => self.settings.style.opacity = value.into_float().unwrap() as _,
+            4 => self.settings.style.blur = value.into_float().unwrap() as _,
+            5 => self.settings.style.scaling = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
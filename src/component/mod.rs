@@ -4,13 +4,20 @@
 //! visualized by any kind of User Interface.
 
 pub mod blank_space;
+pub mod clock;
+pub mod counter;
 pub mod current_comparison;
 pub mod current_pace;
+pub mod custom;
 pub mod delta;
 pub mod detailed_timer;
 pub mod graph;
+pub mod image;
+pub mod notes;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod run_statistics;
+pub mod segment_history;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
@@ -19,14 +26,20 @@ pub mod timer;
 pub mod title;
 pub mod total_playtime;
 
+pub use self::image::Component as Image;
 pub use blank_space::Component as BlankSpace;
+pub use clock::Component as Clock;
+pub use counter::Component as Counter;
 pub use current_comparison::Component as CurrentComparison;
 pub use current_pace::Component as CurrentPace;
 pub use delta::Component as Delta;
 pub use detailed_timer::Component as DetailedTimer;
 pub use graph::Component as Graph;
+pub use notes::Component as Notes;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
+pub use run_statistics::Component as RunStatistics;
+pub use segment_history::Component as SegmentHistory;
 pub use separator::Component as Separator;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
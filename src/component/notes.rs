@@ -0,0 +1,178 @@
+//! Provides the Notes Component and relevant types for using it. The Notes
+//! Component is a component that shows the notes of the current segment or the
+//! segment that comes up next. This is useful for showing route notes while
+//! running.
+
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::Timer;
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// The Notes Component is a component that shows the notes of the current
+/// segment or the segment that comes up next. This is useful for showing route
+/// notes while running.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether the notes of the segment after the current segment
+    /// are shown, instead of the notes of the current segment.
+    pub show_next_segment: bool,
+    /// Specifies whether the name of the segment is shown above its notes.
+    pub show_segment_name: bool,
+    /// The amount of lines of notes that are visible. The notes are wrapped
+    /// onto multiple lines and cut off if they don't fit.
+    pub lines: u32,
+    /// The color of the segment's name. If `None` is specified, the color is
+    /// taken from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the notes. If `None` is specified, the color is taken from
+    /// the layout.
+    pub text_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            show_next_segment: false,
+            show_segment_name: true,
+            lines: 4,
+            label_color: None,
+            text_color: None,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the segment's name. If `None` is specified, the color is
+    /// taken from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the notes. If `None` is specified, the color is taken from
+    /// the layout.
+    pub text_color: Option<Color>,
+    /// The name of the segment the notes belong to. This is `None` if the
+    /// segment's name is not supposed to be shown.
+    pub segment_name: Option<String>,
+    /// The notes to show. They may contain line breaks and are supposed to be
+    /// wrapped onto multiple lines.
+    pub notes: String,
+    /// The amount of lines of notes that are visible.
+    pub lines: u32,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Notes Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Notes Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        if self.settings.show_next_segment {
+            "Notes (Next Segment)".into()
+        } else {
+            "Notes".into()
+        }
+    }
+
+    /// Calculates the component's state based on the timer provided. If no
+    /// attempt is in progress, the notes of the first segment are shown.
+    pub fn state(&self, timer: &Timer) -> State {
+        let index = timer.current_split_index().unwrap_or(0)
+            + if self.settings.show_next_segment {
+                1
+            } else {
+                0
+            };
+
+        let segment = timer.run().segments().get(index);
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            text_color: self.settings.text_color,
+            segment_name: segment
+                .filter(|_| self.settings.show_segment_name)
+                .map(|s| s.name().to_owned()),
+            notes: segment.map_or_else(String::new, |s| s.notes().to_owned()),
+            lines: self.settings.lines,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Show Next Segment".into(),
+                self.settings.show_next_segment.into(),
+            ),
+            Field::new(
+                "Show Segment Name".into(),
+                self.settings.show_segment_name.into(),
+            ),
+            Field::new("Lines".into(), u64::from(self.settings.lines).into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Text Color".into(), self.settings.text_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.show_next_segment = value.into(),
+            2 => self.settings.show_segment_name = value.into(),
+            3 => self.settings.lines = value.into_uint().unwrap() as _,
+            4 => self.settings.label_color = value.into(),
+            5 => self.settings.text_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -4,16 +4,19 @@
 //! Additionally, the potential time save for the previous segment can be
 //! displayed. This component switches to a `Live Segment` view that shows
 //! active time loss whenever the runner is losing time on the current segment.
+//! It can also show the deltas to multiple comparisons at once.
 
 use super::DEFAULT_INFO_TEXT_GRADIENT;
 use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
 use crate::timing::formatter::{Accuracy, Delta, PossibleTimeSave, TimeFormatter};
-use crate::{analysis, comparison, GeneralLayoutSettings, Timer, TimerPhase};
+use crate::{analysis, comparison, GeneralLayoutSettings, TimeSpan, Timer, TimerPhase};
 use serde_json::{to_writer, Result};
 use std::borrow::Cow;
 use std::fmt::Write as FmtWrite;
 use std::io::Write;
 
+const SETTINGS_BEFORE_COMPARISONS: usize = 8;
+
 /// The Previous Segment Component is a component that shows how much time was
 /// saved or lost during the previous segment based on the chosen comparison.
 /// Additionally, the potential time save for the previous segment can be
@@ -47,6 +50,10 @@ pub struct Settings {
     /// Determines if the time save that could've been saved is shown in
     /// addition to the previous segment.
     pub show_possible_time_save: bool,
+    /// The comparisons to show the deltas for, each in its own row. If this is
+    /// empty, only the delta to the comparison chosen by
+    /// `comparison_override` is shown.
+    pub comparisons: Vec<String>,
 }
 
 impl Default for Settings {
@@ -59,6 +66,7 @@ impl Default for Settings {
             drop_decimals: true,
             accuracy: Accuracy::Tenths,
             show_possible_time_save: false,
+            comparisons: Vec::new(),
         }
     }
 }
@@ -82,6 +90,26 @@ pub struct State {
     /// Specifies whether to display the name of the component and its value in
     /// two separate rows.
     pub display_two_rows: bool,
+    /// The deltas to each of the comparisons chosen in the settings that
+    /// exist. If this is not empty, these are shown in separate rows instead
+    /// of the single delta described by the other fields.
+    pub comparisons: Vec<ComparisonState>,
+}
+
+/// The state object describing the delta to a single comparison.
+#[derive(Serialize, Deserialize)]
+pub struct ComparisonState {
+    /// The label's text, including the shortened name of the comparison.
+    pub text: String,
+    /// The shortened name of the comparison. This can be shown instead of the
+    /// label's text if there is not enough space.
+    pub comparison: String,
+    /// The delta (and possibly the possible time save).
+    pub time: String,
+    /// The semantic coloring information the delta time carries.
+    pub semantic_color: SemanticColor,
+    /// The visual color of the delta time.
+    pub visual_color: Color,
 }
 
 impl State {
@@ -117,6 +145,15 @@ impl Component {
 
     /// Accesses the name of the component.
     pub fn name(&self) -> Cow<'_, str> {
+        if !self.settings.comparisons.is_empty() {
+            let comparisons = self
+                .settings
+                .comparisons
+                .iter()
+                .map(|c| comparison::shorten(c))
+                .collect::<Vec<_>>();
+            return format!("Previous Segment ({})", comparisons.join(", ")).into();
+        }
         self.text(
             false,
             self.settings
@@ -142,10 +179,44 @@ impl Component {
     /// Calculates the component's state based on the timer and the layout
     /// settings provided.
     pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
-        let mut time_change = None;
-        let mut previous_possible = None;
         let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(resolved_comparison, timer);
+        let (live, time, semantic_color) = self.calculate(timer, comparison);
+
+        let comparisons = self
+            .settings
+            .comparisons
+            .iter()
+            .filter_map(|c| timer.run().comparisons().find(|&rc| c == rc))
+            .map(|name| {
+                let (live, time, semantic_color) = self.calculate(timer, name);
+                ComparisonState {
+                    text: self.text(live, Some(name)).into_owned(),
+                    comparison: comparison::shorten(name).to_owned(),
+                    time,
+                    semantic_color,
+                    visual_color: semantic_color.visualize(layout_settings),
+                }
+            })
+            .collect();
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            text: self.text(live, resolved_comparison).into_owned(),
+            time,
+            semantic_color,
+            visual_color: semantic_color.visualize(layout_settings),
+            display_two_rows: self.settings.display_two_rows,
+            comparisons,
+        }
+    }
+
+    /// Calculates whether the live segment is shown, the formatted delta and
+    /// its coloring for the comparison provided.
+    fn calculate(&self, timer: &Timer, comparison: &str) -> (bool, String, SemanticColor) {
+        let mut time_change = None;
+        let mut previous_possible = None;
         let live_segment =
             analysis::check_live_delta(timer, false, comparison, timer.current_timing_method());
 
@@ -217,9 +288,12 @@ impl Component {
             SemanticColor::Default
         };
 
-        let visual_color = semantic_color.visualize(layout_settings);
+        let time = self.format(time_change, previous_possible);
+
+        (live_segment.is_some(), time, semantic_color)
+    }
 
-        let text = self.text(live_segment.is_some(), resolved_comparison);
+    fn format(&self, time_change: Option<TimeSpan>, previous_possible: Option<TimeSpan>) -> String {
         let mut time = Delta::custom(self.settings.drop_decimals, self.settings.accuracy)
             .format(time_change)
             .to_string();
@@ -233,21 +307,13 @@ impl Component {
             .unwrap();
         }
 
-        State {
-            background: self.settings.background,
-            label_color: self.settings.label_color,
-            text: text.into_owned(),
-            time,
-            semantic_color,
-            visual_color,
-            display_two_rows: self.settings.display_two_rows,
-        }
+        time
     }
 
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut settings = SettingsDescription::with_fields(vec![
             Field::new("Background".into(), self.settings.background.into()),
             Field::new(
                 "Comparison".into(),
@@ -264,7 +330,20 @@ impl Component {
                 "Show Possible Time Save".into(),
                 self.settings.show_possible_time_save.into(),
             ),
-        ])
+            Field::new(
+                "Comparisons".into(),
+                Value::UInt(self.settings.comparisons.len() as _),
+            ),
+        ]);
+
+        settings.fields.extend(
+            self.settings
+                .comparisons
+                .iter()
+                .map(|c| Field::new("Comparison".into(), c.clone().into())),
+        );
+
+        settings
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -283,7 +362,18 @@ impl Component {
             4 => self.settings.drop_decimals = value.into(),
             5 => self.settings.accuracy = value.into(),
             6 => self.settings.show_possible_time_save = value.into(),
-            _ => panic!("Unsupported Setting Index"),
+            7 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings.comparisons.resize(new_len, String::new());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_COMPARISONS;
+                if let Some(comparison) = self.settings.comparisons.get_mut(index) {
+                    *comparison = value.into();
+                } else {
+                    panic!("Unsupported Setting Index")
+                }
+            }
         }
     }
 }
@@ -0,0 +1,401 @@
+//! Provides the Run Statistics Component and relevant types for using it. The
+//! Run Statistics Component shows a configurable list of statistics that are
+//! calculated from the Attempt History and the Segment History of the run,
+//! such as the completion rate or the amount of attempts that were made today.
+
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+use crate::analysis::{attempts, consistency, pb_history, playtime_breakdown, state_helper};
+use crate::platform::local_utc_offset;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, Regular, TimeFormatter, DASH};
+use crate::{AtomicDateTime, TimeSpan, Timer, TimerPhase};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Run Statistics Component shows a configurable list of statistics that
+/// are calculated from the Attempt History and the Segment History of the run,
+/// such as the completion rate or the amount of attempts that were made today.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether the percentage of attempts that were finished is
+    /// shown.
+    pub show_completion_rate: bool,
+    /// Specifies whether the average final time of all the finished attempts
+    /// is shown.
+    pub show_average_finished_time: bool,
+    /// Specifies whether the amount of attempts that were started today is
+    /// shown. The days are based on the local time zone.
+    pub show_attempts_today: bool,
+    /// Specifies whether the amount of attempts of the current session is
+    /// shown.
+    pub show_attempts_this_session: bool,
+    /// Specifies whether the amount of best segments that were achieved in the
+    /// current session is shown.
+    pub show_golds_this_session: bool,
+    /// Specifies whether the amount of days since the Personal Best was
+    /// achieved is shown.
+    pub show_personal_best_age: bool,
+    /// The amount of minutes between two attempts after which the second
+    /// attempt is considered to be part of a new session.
+    pub session_idle_gap: u32,
+    /// The accuracy of the times shown.
+    pub accuracy: Accuracy,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            show_completion_rate: true,
+            show_average_finished_time: true,
+            show_attempts_today: true,
+            show_attempts_this_session: false,
+            show_golds_this_session: true,
+            show_personal_best_age: true,
+            session_idle_gap: 30,
+            accuracy: Accuracy::Seconds,
+            label_color: None,
+            value_color: None,
+        }
+    }
+}
+
+/// The state object that describes a single statistic to visualize.
+#[derive(Serialize, Deserialize)]
+pub struct StatisticState {
+    /// The name of the statistic.
+    pub name: String,
+    /// The value of the statistic.
+    pub value: String,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The statistics to visualize, in the order they are supposed to be shown
+    /// in.
+    pub statistics: Vec<StatisticState>,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Run Statistics Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Run Statistics Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Run Statistics".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        self.state_at(timer, AtomicDateTime::now())
+    }
+
+    fn state_at(&self, timer: &Timer, now: AtomicDateTime) -> State {
+        let settings = &self.settings;
+        let mut statistics = Vec::new();
+        let mut push = |name: &str, value: String| {
+            statistics.push(StatisticState {
+                name: name.to_owned(),
+                value,
+            })
+        };
+
+        if settings.show_completion_rate {
+            push("Completion Rate", completion_rate(timer));
+        }
+        if settings.show_average_finished_time {
+            let time = average_finished_time(timer);
+            push(
+                "Average Finished Time",
+                Regular::with_accuracy(settings.accuracy)
+                    .format(time)
+                    .to_string(),
+            );
+        }
+        if settings.show_attempts_today {
+            push("Attempts Today", attempts_today(timer, now).to_string());
+        }
+        if settings.show_attempts_this_session || settings.show_golds_this_session {
+            let session = current_session(timer, now, settings.session_idle_gap);
+            if settings.show_attempts_this_session {
+                push("Attempts This Session", session.len().to_string());
+            }
+            if settings.show_golds_this_session {
+                push(
+                    "Golds This Session",
+                    golds_this_session(timer, &session).to_string(),
+                );
+            }
+        }
+        if settings.show_personal_best_age {
+            push("Personal Best Age", personal_best_age(timer, now));
+        }
+
+        State {
+            background: settings.background,
+            label_color: settings.label_color,
+            value_color: settings.value_color,
+            statistics,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Show Completion Rate".into(),
+                self.settings.show_completion_rate.into(),
+            ),
+            Field::new(
+                "Show Average Finished Time".into(),
+                self.settings.show_average_finished_time.into(),
+            ),
+            Field::new(
+                "Show Attempts Today".into(),
+                self.settings.show_attempts_today.into(),
+            ),
+            Field::new(
+                "Show Attempts This Session".into(),
+                self.settings.show_attempts_this_session.into(),
+            ),
+            Field::new(
+                "Show Golds This Session".into(),
+                self.settings.show_golds_this_session.into(),
+            ),
+            Field::new(
+                "Show Personal Best Age".into(),
+                self.settings.show_personal_best_age.into(),
+            ),
+            Field::new(
+                "Session Idle Gap (Minutes)".into(),
+                u64::from(self.settings.session_idle_gap).into(),
+            ),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.show_completion_rate = value.into(),
+            2 => self.settings.show_average_finished_time = value.into(),
+            3 => self.settings.show_attempts_today = value.into(),
+            4 => self.settings.show_attempts_this_session = value.into(),
+            5 => self.settings.show_golds_this_session = value.into(),
+            6 => self.settings.show_personal_best_age = value.into(),
+            7 => self.settings.session_idle_gap = value.into_uint().unwrap() as _,
+            8 => self.settings.accuracy = value.into(),
+            9 => self.settings.label_color = value.into(),
+            10 => self.settings.value_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn is_attempt_in_progress(timer: &Timer) -> bool {
+    timer.current_phase() != TimerPhase::NotRunning
+}
+
+fn completion_rate(timer: &Timer) -> String {
+    let history = timer.run().attempt_history();
+    if history.is_empty() {
+        return DASH.to_owned();
+    }
+
+    let finished = history.iter().filter(|a| attempts::is_finished(a)).count();
+
+    format!("{:.1}%", 100.0 * finished as f64 / history.len() as f64)
+}
+
+fn average_finished_time(timer: &Timer) -> Option<TimeSpan> {
+    let method = timer.current_timing_method();
+    let (sum, count) = timer
+        .run()
+        .attempt_history()
+        .iter()
+        .filter_map(|a| a.time()[method])
+        .fold((0.0, 0), |(sum, count), time| {
+            (sum + time.total_seconds(), count + 1)
+        });
+
+    if count == 0 {
+        None
+    } else {
+        Some(TimeSpan::from_seconds(sum / f64::from(count)))
+    }
+}
+
+fn attempts_today(timer: &Timer, now: AtomicDateTime) -> usize {
+    let offset = local_utc_offset(now.time);
+    let today = now.time.with_timezone(&offset).naive_local().date();
+
+    let count = timer
+        .run()
+        .attempt_history()
+        .iter()
+        .filter_map(|a| a.started())
+        .filter(|started| started.time.with_timezone(&offset).naive_local().date() == today)
+        .count();
+
+    // The attempt in progress is not part of the history yet. It can only
+    // have started recently, so it's considered to be part of today.
+    count + is_attempt_in_progress(timer) as usize
+}
+
+/// Collects the indices of all the attempts of the current session. The
+/// attempt that is currently in progress is not part of the history yet and
+/// is therefore not included.
+fn session_attempts(timer: &Timer, now: AtomicDateTime, idle_gap: TimeSpan) -> HashSet<i32> {
+    let run = timer.run();
+    let session = match playtime_breakdown::by_session(run, idle_gap).pop() {
+        Some(session) => session,
+        None => return HashSet::new(),
+    };
+
+    // If there's an attempt in progress, the session continues if that
+    // attempt started within the idle gap.
+    let continued_at = if is_attempt_in_progress(timer) {
+        now.time - timer.current_attempt_duration().to_duration()
+    } else {
+        now.time
+    };
+
+    if TimeSpan::from(continued_at - session.ended) >= idle_gap {
+        return HashSet::new();
+    }
+
+    run.attempt_history()
+        .iter()
+        .filter(|a| {
+            a.started().map_or(false, |started| {
+                session.started <= started.time && started.time <= session.ended
+            })
+        })
+        .map(|a| a.index())
+        .collect()
+}
+
+/// The attempts of the current session, including the attempt that is
+/// currently in progress.
+struct Session {
+    attempts: HashSet<i32>,
+    includes_current_attempt: bool,
+}
+
+impl Session {
+    fn len(&self) -> usize {
+        self.attempts.len() + self.includes_current_attempt as usize
+    }
+}
+
+fn current_session(timer: &Timer, now: AtomicDateTime, idle_gap_minutes: u32) -> Session {
+    let idle_gap = TimeSpan::from_seconds(60.0 * f64::from(idle_gap_minutes));
+    Session {
+        attempts: session_attempts(timer, now, idle_gap),
+        includes_current_attempt: is_attempt_in_progress(timer),
+    }
+}
+
+fn golds_this_session(timer: &Timer, session: &Session) -> usize {
+    let method = timer.current_timing_method();
+
+    let previous_golds = consistency::golds(timer.run().segments(), method)
+        .into_iter()
+        .filter(|(_, index)| session.attempts.contains(index))
+        .count();
+
+    let current_golds = if session.includes_current_attempt {
+        let completed = timer
+            .current_split_index()
+            .unwrap_or(0)
+            .min(timer.run().len());
+        (0..completed)
+            .filter(|&index| state_helper::check_best_segment(timer, index, method))
+            .count()
+    } else {
+        0
+    };
+
+    previous_golds + current_golds
+}
+
+fn personal_best_age(timer: &Timer, now: AtomicDateTime) -> String {
+    let date = pb_history::calculate(timer.run(), timer.current_timing_method())
+        .pop()
+        .and_then(|pb| pb.date);
+
+    match date {
+        Some(date) => match (now.time - date.time).num_days() {
+            1 => String::from("1 day"),
+            days => format!("{} days", days),
+        },
+        None => DASH.to_owned(),
+    }
+}
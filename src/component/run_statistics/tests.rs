@@ -0,0 +1,158 @@
+use super::{Component, Settings};
+use crate::tests_helper::{
+    create_run, create_timer, run_with_splits, run_with_splits_opt, start_run,
+};
+use crate::{AtomicDateTime, Time, TimeSpan, Timer};
+use chrono::Duration;
+
+fn statistics(component: &Component, timer: &Timer, now: AtomicDateTime) -> Vec<(String, String)> {
+    component
+        .state_at(timer, now)
+        .statistics
+        .into_iter()
+        .map(|s| (s.name, s.value))
+        .collect()
+}
+
+fn value(component: &Component, timer: &Timer, now: AtomicDateTime, name: &str) -> String {
+    statistics(component, timer, now)
+        .into_iter()
+        .find(|(n, _)| n == name)
+        .map(|(_, v)| v)
+        .unwrap()
+}
+
+fn all_statistics() -> Component {
+    Component::with_settings(Settings {
+        show_attempts_this_session: true,
+        ..Default::default()
+    })
+}
+
+fn later(duration: Duration) -> AtomicDateTime {
+    let mut now = AtomicDateTime::now();
+    now.time += duration;
+    now
+}
+
+#[test]
+fn no_attempts() {
+    let timer = create_timer(&["A", "B"]);
+    let component = all_statistics();
+    let now = AtomicDateTime::now();
+
+    assert_eq!(
+        statistics(&component, &timer, now),
+        [
+            ("Completion Rate", "—"),
+            ("Average Finished Time", "—"),
+            ("Attempts Today", "0"),
+            ("Attempts This Session", "0"),
+            ("Golds This Session", "0"),
+            ("Personal Best Age", "—"),
+        ]
+        .iter()
+        .map(|&(n, v)| (n.to_owned(), v.to_owned()))
+        .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn finished_attempts() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits_opt(&mut timer, &[Some(10.0), None]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits_opt(&mut timer, &[Some(15.0)]);
+
+    let component = all_statistics();
+    let now = AtomicDateTime::now();
+
+    assert_eq!(value(&component, &timer, now, "Completion Rate"), "50.0%");
+    assert_eq!(
+        value(&component, &timer, now, "Average Finished Time"),
+        "0:25"
+    );
+    assert_eq!(value(&component, &timer, now, "Attempts Today"), "4");
+}
+
+#[test]
+fn game_time_only_attempts_are_finished() {
+    let mut run = create_run(&["A", "B"]);
+    let game_time = Time::new().with_game_time(Some(TimeSpan::from_seconds(20.0)));
+    run.add_attempt_with_index(game_time, 1, None, None, None);
+    run.add_attempt_with_index(Time::new(), 2, None, None, None);
+    let timer = Timer::new(run).unwrap();
+
+    let component = all_statistics();
+    let now = AtomicDateTime::now();
+
+    assert_eq!(value(&component, &timer, now, "Completion Rate"), "50.0%");
+}
+
+#[test]
+fn session() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 20.0]);
+
+    let component = all_statistics();
+    let now = AtomicDateTime::now();
+
+    assert_eq!(value(&component, &timer, now, "Attempts This Session"), "2");
+    assert_eq!(value(&component, &timer, now, "Golds This Session"), "3");
+
+    // The attempt in progress is part of the session, as is its new best
+    // segment.
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    timer.split();
+
+    assert_eq!(value(&component, &timer, now, "Attempts This Session"), "3");
+    assert_eq!(value(&component, &timer, now, "Golds This Session"), "4");
+
+    timer.reset(true);
+
+    let later = later(Duration::hours(1));
+    assert_eq!(
+        value(&component, &timer, later, "Attempts This Session"),
+        "0"
+    );
+    assert_eq!(value(&component, &timer, later, "Golds This Session"), "0");
+}
+
+#[test]
+fn personal_best_age() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let component = all_statistics();
+
+    assert_eq!(
+        value(
+            &component,
+            &timer,
+            AtomicDateTime::now(),
+            "Personal Best Age"
+        ),
+        "0 days"
+    );
+    assert_eq!(
+        value(
+            &component,
+            &timer,
+            later(Duration::days(1)),
+            "Personal Best Age"
+        ),
+        "1 day"
+    );
+    assert_eq!(
+        value(
+            &component,
+            &timer,
+            later(Duration::days(3)),
+            "Personal Best Age"
+        ),
+        "3 days"
+    );
+}
@@ -0,0 +1,298 @@
+//! Provides the Segment History Component and relevant types for using it. The
+//! Segment History Component lists the most recent segment times of the
+//! current segment, along with how much slower than the best segment they
+//! were. This is useful for judging whether a recent strategy change helped.
+
+use super::DEFAULT_INFO_TEXT_GRADIENT;
+use crate::analysis::{consistency, state_helper};
+use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, Delta, Regular, TimeFormatter};
+use crate::{comparison, GeneralLayoutSettings, TimeSpan, Timer};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Segment History Component lists the most recent segment times of the
+/// current segment, along with how much slower than the best segment they
+/// were. This is useful for judging whether a recent strategy change helped.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen for coloring the segment times. Uses the Timer's
+    /// current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// The amount of the most recent segment times to show.
+    pub times: u32,
+    /// Specifies whether the name of the current segment is shown above its
+    /// segment times.
+    pub show_segment_name: bool,
+    /// Specifies whether a sparkline visualizing the segment times is shown
+    /// below them.
+    pub show_sparkline: bool,
+    /// The accuracy of the segment times shown.
+    pub accuracy: Accuracy,
+    /// Specifies if the decimals should not be shown anymore when the delta to
+    /// the best segment is over a minute.
+    pub drop_decimals: bool,
+    /// The accuracy of the deltas to the best segment.
+    pub delta_accuracy: Accuracy,
+    /// The color of the segment's name and the segment times. If `None` is
+    /// specified, the color is taken from the layout.
+    pub label_color: Option<Color>,
+    /// The color of the sparkline. If `None` is specified, the color is taken
+    /// from the layout.
+    pub sparkline_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_INFO_TEXT_GRADIENT,
+            comparison_override: None,
+            times: 5,
+            show_segment_name: true,
+            show_sparkline: true,
+            accuracy: Accuracy::Hundredths,
+            drop_decimals: true,
+            delta_accuracy: Accuracy::Tenths,
+            label_color: None,
+            sparkline_color: None,
+        }
+    }
+}
+
+/// The state object that describes a single segment time to visualize.
+#[derive(Serialize, Deserialize)]
+pub struct TimeState {
+    /// The index of the attempt the segment time was achieved in.
+    pub attempt_index: i32,
+    /// The segment time.
+    pub time: String,
+    /// The delta of the segment time to the best segment time.
+    pub delta: String,
+    /// The semantic coloring information the segment time carries.
+    pub semantic_color: SemanticColor,
+    /// The visual color of the delta.
+    pub visual_color: Color,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the segment's name and the segment times. If `None` is
+    /// specified, the color is taken from the layout.
+    pub label_color: Option<Color>,
+    /// The name of the current segment. This is `None` if the segment's name
+    /// is not supposed to be shown.
+    pub segment_name: Option<String>,
+    /// The most recent segment times, starting with the most recent one.
+    pub times: Vec<TimeState>,
+    /// The points of the sparkline, starting with the oldest segment time.
+    /// Each point ranges from 0.0 for the fastest segment time to 1.0 for the
+    /// slowest segment time. This is `None` if the sparkline is not supposed
+    /// to be shown.
+    pub sparkline: Option<Vec<f32>>,
+    /// The color of the sparkline. If `None` is specified, the color is taken
+    /// from the layout.
+    pub sparkline_color: Option<Color>,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Segment History Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment History Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Segment History".into()
+    }
+
+    /// Calculates the component's state based on the timer and the layout
+    /// settings provided. If no attempt is in progress, the segment times of
+    /// the first segment are shown. If the attempt is finished, the segment
+    /// times of the last segment are shown.
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let run = timer.run();
+        let method = timer.current_timing_method();
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+
+        let segment_index = timer
+            .current_split_index()
+            .unwrap_or(0)
+            .min(run.len().saturating_sub(1));
+        let segment = run.segments().get(segment_index);
+
+        let history = segment.map_or_else(Vec::new, |_| {
+            let mut times = consistency::segment_times(run.segments(), segment_index, method)
+                .collect::<Vec<_>>();
+            let skip = times.len().saturating_sub(self.settings.times as usize);
+            times.drain(..skip);
+            times
+        });
+
+        let best_segment = segment.and_then(|s| s.best_segment_time()[method]);
+        let comparison_time = segment.and_then(|_| {
+            state_helper::comparison_segment_time(run, segment_index, comparison, method)
+        });
+
+        let time_formatter = Regular::with_accuracy(self.settings.accuracy);
+        let delta_formatter =
+            Delta::custom(self.settings.drop_decimals, self.settings.delta_accuracy);
+
+        let times = history
+            .iter()
+            .rev()
+            .map(|&(attempt_index, time)| {
+                let delta = best_segment.map(|best| time - best);
+                let semantic_color = color(time, delta, comparison_time);
+
+                TimeState {
+                    attempt_index,
+                    time: time_formatter.format(time).to_string(),
+                    delta: delta_formatter.format(delta).to_string(),
+                    semantic_color,
+                    visual_color: semantic_color.visualize(layout_settings),
+                }
+            })
+            .collect();
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            segment_name: segment
+                .filter(|_| self.settings.show_segment_name)
+                .map(|s| s.name().to_owned()),
+            times,
+            sparkline: if self.settings.show_sparkline {
+                Some(sparkline(&history))
+            } else {
+                None
+            },
+            sparkline_color: self.settings.sparkline_color,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new("Times".into(), u64::from(self.settings.times).into()),
+            Field::new(
+                "Show Segment Name".into(),
+                self.settings.show_segment_name.into(),
+            ),
+            Field::new("Show Sparkline".into(), self.settings.show_sparkline.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Drop Decimals".into(), self.settings.drop_decimals.into()),
+            Field::new("Delta Accuracy".into(), self.settings.delta_accuracy.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new(
+                "Sparkline Color".into(),
+                self.settings.sparkline_color.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.times = value.into_uint().unwrap() as _,
+            3 => self.settings.show_segment_name = value.into(),
+            4 => self.settings.show_sparkline = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            6 => self.settings.drop_decimals = value.into(),
+            7 => self.settings.delta_accuracy = value.into(),
+            8 => self.settings.label_color = value.into(),
+            9 => self.settings.sparkline_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// A segment time that matches the best segment time is colored as a best
+/// segment. All the other segment times are colored based on whether they are
+/// faster or slower than the comparison's segment time.
+fn color(
+    time: TimeSpan,
+    delta: Option<TimeSpan>,
+    comparison_time: Option<TimeSpan>,
+) -> SemanticColor {
+    if delta.map_or(false, |d| d <= TimeSpan::zero()) {
+        SemanticColor::BestSegment
+    } else {
+        match comparison_time {
+            Some(comparison_time) if time < comparison_time => SemanticColor::AheadGainingTime,
+            Some(_) => SemanticColor::BehindLosingTime,
+            None => SemanticColor::Default,
+        }
+    }
+}
+
+fn sparkline(history: &[(i32, TimeSpan)]) -> Vec<f32> {
+    let seconds = history.iter().map(|&(_, time)| time.total_seconds());
+    let min = seconds.clone().fold(f64::INFINITY, f64::min);
+    let max = seconds.clone().fold(f64::NEG_INFINITY, f64::max);
+
+    seconds
+        .map(|time| {
+            if max > min {
+                ((time - min) / (max - min)) as f32
+            } else {
+                0.5
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,111 @@
+use super::{Component, Settings, State};
+use crate::settings::SemanticColor;
+use crate::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, start_run};
+use crate::{GeneralLayoutSettings, TimeSpan, Timer};
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0]);
+    timer
+}
+
+fn state(component: &Component, timer: &Timer) -> State {
+    component.state(timer, &GeneralLayoutSettings::default())
+}
+
+fn times(state: &State) -> Vec<(i32, &str, &str)> {
+    state
+        .times
+        .iter()
+        .map(|t| (t.attempt_index, &*t.time, &*t.delta))
+        .collect()
+}
+
+#[test]
+fn shows_the_most_recent_times() {
+    let timer = timer();
+    let component = Component::with_settings(Settings {
+        times: 2,
+        ..Default::default()
+    });
+
+    let state = state(&component, &timer);
+    assert_eq!(state.segment_name.as_deref(), Some("A"));
+    assert_eq!(
+        times(&state),
+        [(3, "0:12.00", "+4.0"), (2, "0:08.00", "+0.0")]
+    );
+    assert_eq!(state.sparkline, Some(vec![0.0, 1.0]));
+}
+
+#[test]
+fn colors_the_times() {
+    let timer = timer();
+    let state = state(&Component::new(), &timer);
+
+    // The first attempt is the Personal Best, so its segment time is what
+    // the other segment times are compared against.
+    let colors = state
+        .times
+        .iter()
+        .map(|t| t.semantic_color)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        colors,
+        [
+            SemanticColor::BehindLosingTime,
+            SemanticColor::BestSegment,
+            SemanticColor::BehindLosingTime,
+        ]
+    );
+}
+
+#[test]
+fn follows_the_current_split() {
+    let mut timer = timer();
+    let component = Component::with_settings(Settings {
+        show_segment_name: false,
+        show_sparkline: false,
+        ..Default::default()
+    });
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(10.0));
+    timer.split();
+
+    let state = state(&component, &timer);
+    assert_eq!(state.segment_name, None);
+    assert_eq!(state.sparkline, None);
+    assert_eq!(
+        times(&state),
+        [
+            (3, "0:08.00", "+0.0"),
+            (2, "0:12.00", "+4.0"),
+            (1, "0:10.00", "+2.0"),
+        ]
+    );
+}
+
+#[test]
+fn ignores_combined_segment_times() {
+    let mut timer = timer();
+    run_with_splits_opt(&mut timer, &[None, Some(30.0)]);
+    let component = Component::new();
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(10.0));
+    timer.split();
+
+    let state = state(&component, &timer);
+    assert_eq!(
+        times(&state),
+        [
+            (3, "0:08.00", "+0.0"),
+            (2, "0:12.00", "+4.0"),
+            (1, "0:10.00", "+2.0"),
+        ]
+    );
+    assert_eq!(state.sparkline, Some(vec![0.5, 1.0, 0.0]));
+}
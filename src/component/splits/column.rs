@@ -1,8 +1,8 @@
 use crate::{
-    analysis::{self, possible_time_save, split_color},
-    comparison,
+    analysis::{self, consistency, possible_time_save, split_color, survival},
+    comparison::{self, best_split_times},
     settings::{Color, SemanticColor},
-    timing::formatter::{Delta, PossibleTimeSave, Regular, TimeFormatter},
+    timing::formatter::{Accuracy, Delta, PossibleTimeSave, Regular, TimeFormatter, DASH},
     GeneralLayoutSettings, Segment, TimeSpan, Timer, TimingMethod,
 };
 
@@ -46,6 +46,28 @@ pub enum ColumnStartWith {
     /// The column starts out with the time that can be saved on each individual
     /// segment stored in the comparison that is being compared against.
     PossibleTimeSave,
+    /// The column starts out with the amount of attempts that got reset on
+    /// each segment.
+    ResetCount,
+    /// The column starts out with the amount of times each segment was a new
+    /// best segment at the time it was achieved.
+    GoldCount,
+    /// The column starts out with the average of all the segment times of each
+    /// segment.
+    AverageSegmentTime,
+    /// The column starts out with the median of all the segment times of each
+    /// segment.
+    MedianSegmentTime,
+    /// The column starts out with the standard deviation of the segment times
+    /// of each segment. The lower it is, the more consistent the runner is on
+    /// the segment.
+    StandardDeviation,
+    /// The column starts out with the percentage of the attempts that made it
+    /// to each segment and didn't get reset on it.
+    SurvivalRate,
+    /// The column starts out with the best split time that was ever achieved
+    /// on each split. This is based on the Best Split Times comparison.
+    BestSplitTime,
 }
 
 /// Once a certain condition is met, which is usually being on the split or
@@ -119,17 +141,24 @@ pub struct ColumnState {
     pub visual_color: Color,
 }
 
-enum ColumnFormatter {
-    Time,
-    Delta,
-    PossibleTimeSave,
+enum ColumnValue {
+    Time(Option<TimeSpan>),
+    Delta(Option<TimeSpan>),
+    PossibleTimeSave(Option<TimeSpan>),
+    Deviation(Option<TimeSpan>),
+    Count(Option<usize>),
+    Percentage(Option<f64>),
 }
 
+/// Calculates the state of a column for the section of segments starting at
+/// the start index and ending with the segment provided. For individual
+/// segments, the start index is the index of the segment itself.
 pub fn state(
     column: &ColumnSettings,
     timer: &Timer,
     layout_settings: &GeneralLayoutSettings,
     segment: &Segment,
+    start_index: usize,
     segment_index: usize,
     current_split: Option<usize>,
     method: TimingMethod,
@@ -142,6 +171,7 @@ pub fn state(
         column,
         timer,
         segment,
+        start_index,
         segment_index,
         current_split,
         method,
@@ -150,38 +180,36 @@ pub fn state(
 
     let updated = update_value.is_some();
 
-    let (column_value, semantic_color, formatter) =
-        update_value.unwrap_or_else(|| match column.start_with {
-            ColumnStartWith::Empty => (None, SemanticColor::Default, ColumnFormatter::Time),
-            ColumnStartWith::ComparisonTime => (
-                segment.comparison(comparison)[method],
-                SemanticColor::Default,
-                ColumnFormatter::Time,
-            ),
-            ColumnStartWith::ComparisonSegmentTime => (
-                analysis::comparison_segment_time(timer.run(), segment_index, comparison, method),
-                SemanticColor::Default,
-                ColumnFormatter::Time,
-            ),
-            ColumnStartWith::PossibleTimeSave => (
-                possible_time_save::calculate(timer, segment_index, comparison, false),
-                SemanticColor::Default,
-                ColumnFormatter::PossibleTimeSave,
+    let (column_value, semantic_color) = update_value.unwrap_or_else(|| {
+        (
+            column_start_value(
+                column,
+                timer,
+                segment,
+                start_index,
+                segment_index,
+                method,
+                comparison,
             ),
-        });
+            SemanticColor::Default,
+        )
+    });
 
     let is_empty = column.start_with == ColumnStartWith::Empty && !updated;
 
     let value = if is_empty {
         String::new()
     } else {
-        match formatter {
-            ColumnFormatter::Time => Regular::new().format(column_value).to_string(),
-            ColumnFormatter::Delta => Delta::with_decimal_dropping()
-                .format(column_value)
+        match column_value {
+            ColumnValue::Time(time) => Regular::new().format(time).to_string(),
+            ColumnValue::Delta(delta) => Delta::with_decimal_dropping().format(delta).to_string(),
+            ColumnValue::PossibleTimeSave(time) => PossibleTimeSave::new().format(time).to_string(),
+            ColumnValue::Deviation(deviation) => Regular::with_accuracy(Accuracy::Tenths)
+                .format(deviation)
                 .to_string(),
-            ColumnFormatter::PossibleTimeSave => {
-                PossibleTimeSave::new().format(column_value).to_string()
+            ColumnValue::Count(count) => count.map_or_else(|| DASH.to_owned(), |c| c.to_string()),
+            ColumnValue::Percentage(percentage) => {
+                percentage.map_or_else(|| DASH.to_owned(), |p| format!("{:.1}%", p))
             }
         }
     };
@@ -193,23 +221,88 @@ pub fn state(
     }
 }
 
+fn column_start_value(
+    column: &ColumnSettings,
+    timer: &Timer,
+    segment: &Segment,
+    start_index: usize,
+    segment_index: usize,
+    method: TimingMethod,
+    comparison: &str,
+) -> ColumnValue {
+    let run = timer.run();
+
+    // The segment time statistics are only meaningful for individual segments,
+    // not for whole sections.
+    let consistency = || {
+        if start_index == segment_index {
+            consistency::calculate(run.segments(), segment_index, method, false)
+        } else {
+            None
+        }
+    };
+
+    match column.start_with {
+        ColumnStartWith::Empty => ColumnValue::Time(None),
+        ColumnStartWith::ComparisonTime => {
+            ColumnValue::Time(segment.comparison(comparison)[method])
+        }
+        ColumnStartWith::ComparisonSegmentTime => ColumnValue::Time(
+            analysis::comparison_section_time(run, start_index, segment_index, comparison, method),
+        ),
+        ColumnStartWith::PossibleTimeSave => ColumnValue::PossibleTimeSave(
+            (start_index..=segment_index).try_fold(TimeSpan::zero(), |sum, index| {
+                Some(sum + possible_time_save::calculate(timer, index, comparison, false)?)
+            }),
+        ),
+        ColumnStartWith::ResetCount => ColumnValue::Count(Some(
+            survival::calculate(run, start_index, segment_index).resets,
+        )),
+        ColumnStartWith::GoldCount => ColumnValue::Count(if start_index == segment_index {
+            Some(consistency::gold_count(
+                run.segments(),
+                segment_index,
+                method,
+            ))
+        } else {
+            None
+        }),
+        ColumnStartWith::AverageSegmentTime => ColumnValue::Time(consistency().map(|c| c.mean)),
+        ColumnStartWith::MedianSegmentTime => ColumnValue::Time(consistency().map(|c| c.median)),
+        ColumnStartWith::StandardDeviation => {
+            ColumnValue::Deviation(consistency().map(|c| c.standard_deviation))
+        }
+        ColumnStartWith::SurvivalRate => {
+            ColumnValue::Percentage(survival::calculate(run, start_index, segment_index).rate())
+        }
+        ColumnStartWith::BestSplitTime => {
+            ColumnValue::Time(segment.comparison(best_split_times::NAME)[method])
+        }
+    }
+}
+
 fn column_update_value(
     column: &ColumnSettings,
     timer: &Timer,
     segment: &Segment,
+    start_index: usize,
     segment_index: usize,
     current_split: Option<usize>,
     method: TimingMethod,
     comparison: &str,
-) -> Option<(Option<TimeSpan>, SemanticColor, ColumnFormatter)> {
+) -> Option<(ColumnValue, SemanticColor)> {
     use self::{ColumnUpdateTrigger::*, ColumnUpdateWith::*};
 
-    if current_split < Some(segment_index) {
+    if current_split < Some(start_index) {
         // Didn't reach the segment yet.
         return None;
     }
 
-    let is_current_split = current_split == Some(segment_index);
+    let is_current_split = current_split <= Some(segment_index);
+
+    // Best segments are only meaningful for individual segments, not for whole
+    // sections.
+    let show_best_segments = start_index == segment_index;
 
     if is_current_split {
         if column.update_trigger == OnEndingSegment {
@@ -237,14 +330,12 @@ fn column_update_value(
         (DontUpdate, _) => None,
 
         (SplitTime, false) => Some((
-            segment.split_time()[method],
+            ColumnValue::Time(segment.split_time()[method]),
             SemanticColor::Default,
-            ColumnFormatter::Time,
         )),
         (SplitTime, true) => Some((
-            timer.current_time()[method],
+            ColumnValue::Time(timer.current_time()[method]),
             SemanticColor::Default,
-            ColumnFormatter::Time,
         )),
 
         (Delta, false) | (DeltaWithFallback, false) => {
@@ -253,57 +344,86 @@ fn column_update_value(
                 split_time? -
                 segment.comparison(comparison)[method]?
             };
-            let (value, formatter) = if delta.is_none() && column.update_with.has_fallback() {
-                (split_time, ColumnFormatter::Time)
+            let value = if delta.is_none() && column.update_with.has_fallback() {
+                ColumnValue::Time(split_time)
             } else {
-                (delta, ColumnFormatter::Delta)
+                ColumnValue::Delta(delta)
             };
             Some((
                 value,
-                split_color(timer, delta, segment_index, true, true, comparison, method),
-                formatter,
+                split_color(
+                    timer,
+                    delta,
+                    segment_index,
+                    true,
+                    show_best_segments,
+                    comparison,
+                    method,
+                ),
             ))
         }
         (Delta, true) | (DeltaWithFallback, true) => Some((
-            catch! {
+            ColumnValue::Delta(catch! {
                 timer.current_time()[method]? -
                 segment.comparison(comparison)[method]?
-            },
+            }),
             SemanticColor::Default,
-            ColumnFormatter::Delta,
         )),
 
         (SegmentTime, false) => Some((
-            analysis::previous_segment_time(timer, segment_index, method),
+            ColumnValue::Time(analysis::previous_section_time(
+                timer,
+                start_index,
+                segment_index,
+                method,
+            )),
             SemanticColor::Default,
-            ColumnFormatter::Time,
         )),
         (SegmentTime, true) => Some((
-            analysis::live_segment_time(timer, segment_index, method),
+            ColumnValue::Time(analysis::live_section_time(timer, start_index, method)),
             SemanticColor::Default,
-            ColumnFormatter::Time,
         )),
 
         (SegmentDelta, false) | (SegmentDeltaWithFallback, false) => {
-            let delta = analysis::previous_segment_delta(timer, segment_index, comparison, method);
-            let (value, formatter) = if delta.is_none() && column.update_with.has_fallback() {
-                (
-                    analysis::previous_segment_time(timer, segment_index, method),
-                    ColumnFormatter::Time,
-                )
+            let delta = analysis::previous_section_delta(
+                timer,
+                start_index,
+                segment_index,
+                comparison,
+                method,
+            );
+            let value = if delta.is_none() && column.update_with.has_fallback() {
+                ColumnValue::Time(analysis::previous_section_time(
+                    timer,
+                    start_index,
+                    segment_index,
+                    method,
+                ))
             } else {
-                (delta, ColumnFormatter::Delta)
+                ColumnValue::Delta(delta)
             };
             Some((
                 value,
-                split_color(timer, delta, segment_index, false, true, comparison, method),
-                formatter,
+                split_color(
+                    timer,
+                    delta,
+                    segment_index,
+                    false,
+                    show_best_segments,
+                    comparison,
+                    method,
+                ),
             ))
         }
         (SegmentDelta, true) | (SegmentDeltaWithFallback, true) => Some((
-            analysis::live_segment_delta(timer, segment_index, comparison, method),
+            ColumnValue::Delta(analysis::live_section_delta(
+                timer,
+                start_index,
+                segment_index,
+                comparison,
+                method,
+            )),
             SemanticColor::Default,
-            ColumnFormatter::Delta,
         )),
     }
 }
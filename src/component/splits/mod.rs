@@ -3,7 +3,8 @@
 //! segment is shown in a tabular fashion showing the segment icon, segment
 //! name, the delta compared to the chosen comparison, and the split time. The
 //! list provides scrolling functionality, so not every segment needs to be
-//! shown all the time.
+//! shown all the time. Segments can also be grouped into sections of
+//! subsplits, which can be collapsed into a single row.
 
 use crate::{
     settings::{Color, Field, Gradient, ListGradient, SettingsDescription, Value},
@@ -18,12 +19,14 @@ use std::io::Write;
 mod tests;
 
 mod column;
+mod section;
 
 pub use column::{
     ColumnSettings, ColumnStartWith, ColumnState, ColumnUpdateTrigger, ColumnUpdateWith,
 };
+pub use section::SubsplitVisibility;
 
-const SETTINGS_BEFORE_COLUMNS: usize = 11;
+const SETTINGS_BEFORE_COLUMNS: usize = 20;
 const SETTINGS_PER_COLUMN: usize = 6;
 
 /// The Splits Component is the main component for visualizing all the split
@@ -82,27 +85,75 @@ pub struct Settings {
     pub current_split_gradient: Gradient,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// Specifies whether the segments are grouped into sections. Segments whose
+    /// names start with `-` are subsplits of the section that is ended by the
+    /// next segment whose name doesn't start with `-`. If the name of that
+    /// segment starts with a name in curly braces, like `{Section} Segment`,
+    /// that name is used as the name of the section.
+    pub group_subsplits: bool,
+    /// Specifies when the subsplits of a section are shown. Sections whose
+    /// subsplits are not shown are collapsed into a single row that shows the
+    /// times of the whole section.
+    pub subsplit_visibility: SubsplitVisibility,
+    /// Specifies whether only the section the current segment belongs to is
+    /// shown, if it has any subsplits.
+    pub current_section_only: bool,
+    /// Specifies whether subsplits are indented.
+    pub indent_subsplits: bool,
+    /// Specifies whether a header row with the name of the section is shown
+    /// above the subsplits of a section.
+    pub show_section_headers: bool,
+    /// Specifies whether the header rows of sections show the times of the
+    /// whole section.
+    pub show_section_header_times: bool,
+    /// The gradient to show behind subsplits.
+    pub subsplit_gradient: Gradient,
+    /// The gradient to show behind the header rows of sections.
+    pub section_header_gradient: Gradient,
+    /// The color of the names of the sections in the header rows. If `None`
+    /// is specified, the color is taken from the layout.
+    pub section_header_text_color: Option<Color>,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
     pub columns: Vec<ColumnSettings>,
 }
 
+/// Describes what a single row of the Splits Component represents.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum SplitKind {
+    /// An individual segment that is not part of a section with subsplits.
+    Segment,
+    /// A segment that is part of a section that is shown with all of its
+    /// subsplits.
+    Subsplit,
+    /// The header row of a section that is shown with all of its subsplits.
+    SectionHeader,
+    /// A whole section that is collapsed into a single row. Its columns show
+    /// the times of the whole section.
+    Section,
+}
+
 /// The state object that describes a single segment's information to visualize.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SplitState {
-    /// The name of the segment.
+    /// The name of the segment. For sections and section headers this is the
+    /// name of the section.
     pub name: String,
+    /// Describes what this row represents.
+    pub kind: SplitKind,
     /// The state of each column from right to left. The amount of columns is
     /// not guaranteed to be the same across different splits.
     pub columns: Vec<ColumnState>,
     /// Describes if this segment is the segment the active attempt is currently
-    /// on.
+    /// on. For collapsed sections this describes if the active attempt is
+    /// currently on any of its segments.
     pub is_current_split: bool,
     /// The index of the segment based on all the segments of the run. This may
     /// differ from the index of this `SplitState` in the `State` object, as
-    /// there can be a scrolling window, showing only a subset of segments. Each
-    /// index is guaranteed to be unique.
+    /// there can be a scrolling window, showing only a subset of segments. For
+    /// collapsed sections this is the index of the last segment of the
+    /// section. Each index is guaranteed to be unique.
     pub index: usize,
 }
 
@@ -155,6 +206,15 @@ pub struct State {
     /// The gradient to show behind the current segment as an indicator of it
     /// being the current segment.
     pub current_split_gradient: Gradient,
+    /// Specifies whether subsplits are supposed to be indented.
+    pub indent_subsplits: bool,
+    /// The gradient to show behind subsplits.
+    pub subsplit_gradient: Gradient,
+    /// The gradient to show behind the header rows of sections.
+    pub section_header_gradient: Gradient,
+    /// The color of the names of the sections in the header rows. If `None`
+    /// is specified, the color is taken from the layout.
+    pub section_header_text_color: Option<Color>,
 }
 
 impl Default for Settings {
@@ -176,6 +236,15 @@ impl Default for Settings {
                 Color::from((21.0 / 255.0, 53.0 / 255.0, 116.0 / 255.0, 1.0)),
             ),
             show_column_labels: false,
+            group_subsplits: false,
+            subsplit_visibility: SubsplitVisibility::CurrentSection,
+            current_section_only: false,
+            indent_subsplits: true,
+            show_section_headers: true,
+            show_section_header_times: true,
+            subsplit_gradient: Gradient::Transparent,
+            section_header_gradient: Gradient::Plain(Color::from((1.0, 1.0, 1.0, 0.08))),
+            section_header_text_color: None,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -198,6 +267,23 @@ impl Default for Settings {
     }
 }
 
+/// A single row that can be shown by the component. It covers all the segments
+/// from the start index to the end index, both inclusive.
+struct Row<'a> {
+    kind: SplitKind,
+    name: &'a str,
+    start: usize,
+    end: usize,
+}
+
+impl Row<'_> {
+    fn contains(&self, segment_index: usize) -> bool {
+        self.kind != SplitKind::SectionHeader
+            && self.start <= segment_index
+            && segment_index <= self.end
+    }
+}
+
 impl State {
     /// Encodes the state object's information as JSON.
     pub fn write_json<W>(&self, writer: W) -> Result<()>
@@ -270,33 +356,40 @@ impl Component {
         let run = timer.run();
         self.icon_ids.resize(run.len(), CachedImageId::default());
 
+        let current_split = timer.current_split_index();
+        let method = timer.current_timing_method();
+
+        let rows = self.rows(timer);
+        let current_row = current_split.map(|c_s| {
+            rows.iter()
+                .position(|row| row.contains(c_s))
+                .unwrap_or_else(|| rows.len())
+        });
+
         let mut visual_split_count = self.settings.visual_split_count;
         if visual_split_count == 0 {
-            visual_split_count = run.len();
+            visual_split_count = rows.len();
         }
 
-        let current_split = timer.current_split_index();
-        let method = timer.current_timing_method();
-
         let always_show_last_split = if self.settings.always_show_last_split {
             0
         } else {
             1
         };
         let skip_count = min(
-            current_split.map_or(0, |c_s| {
-                c_s.saturating_sub(
+            current_row.map_or(0, |c_r| {
+                c_r.saturating_sub(
                     visual_split_count
                         .saturating_sub(2)
                         .saturating_sub(self.settings.split_preview_count)
                         .saturating_add(always_show_last_split),
                 ) as isize
             }),
-            run.len() as isize - visual_split_count as isize,
+            rows.len() as isize - visual_split_count as isize,
         );
         self.scroll_offset = min(
             max(self.scroll_offset, -skip_count),
-            run.len() as isize - skip_count - visual_split_count as isize,
+            rows.len() as isize - skip_count - visual_split_count as isize,
         );
         let skip_count = max(0, skip_count + self.scroll_offset) as usize;
         let take_count = visual_split_count + always_show_last_split as usize - 1;
@@ -304,29 +397,29 @@ impl Component {
 
         let show_final_separator = self.settings.separator_last_split
             && always_show_last_split
-            && skip_count + take_count + 1 < run.len();
+            && skip_count + take_count + 1 < rows.len();
 
         let Settings {
             show_thin_separators,
             fill_with_blank_space,
             display_two_rows,
+            show_section_header_times,
             ref columns,
             ..
         } = self.settings;
 
         let mut icon_changes = Vec::new();
+        let mut splits = Vec::with_capacity(visual_split_count);
+
+        for (i, row) in rows.iter().enumerate().skip(skip_count) {
+            if i - skip_count >= take_count && !(always_show_last_split && i + 1 == rows.len()) {
+                continue;
+            }
+
+            let segment = run.segment(row.end);
 
-        let mut splits: Vec<_> = run
-            .segments()
-            .iter()
-            .enumerate()
-            .zip(self.icon_ids.iter_mut())
-            .skip(skip_count)
-            .filter(|&((i, _), _)| {
-                i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
-            })
-            .map(|((i, segment), icon_id)| {
-                let columns = columns
+            let columns = if row.kind != SplitKind::SectionHeader || show_section_header_times {
+                columns
                     .iter()
                     .map(|column| {
                         column::state(
@@ -334,34 +427,47 @@ impl Component {
                             timer,
                             layout_settings,
                             segment,
-                            i,
+                            row.start,
+                            row.end,
                             current_split,
                             method,
                         )
                     })
-                    .collect();
-
-                if let Some(icon_change) = icon_id.update_with(Some(segment.icon())) {
+                    .collect()
+            } else {
+                Vec::new()
+            };
+
+            let index = if row.kind == SplitKind::SectionHeader {
+                // Section headers share their segments with the rows below
+                // them, so they need indices that can't collide with these.
+                usize::max_value() - 2 * row.start
+            } else {
+                if let Some(icon_change) = self.icon_ids[row.end].update_with(Some(segment.icon()))
+                {
                     icon_changes.push(IconChange {
-                        segment_index: i,
+                        segment_index: row.end,
                         icon: icon_change.to_owned(),
                     });
                 }
-
-                SplitState {
-                    name: segment.name().to_string(),
-                    columns,
-                    is_current_split: Some(i) == current_split,
-                    index: i,
-                }
-            })
-            .collect();
+                row.end
+            };
+
+            splits.push(SplitState {
+                name: row.name.to_string(),
+                kind: row.kind,
+                columns,
+                is_current_split: current_split.map_or(false, |c_s| row.contains(c_s)),
+                index,
+            });
+        }
 
         if fill_with_blank_space && splits.len() < visual_split_count {
             let blank_split_count = visual_split_count - splits.len();
             for i in 0..blank_split_count {
                 splits.push(SplitState {
                     name: String::new(),
+                    kind: SplitKind::Segment,
                     columns: Vec::new(),
                     is_current_split: false,
                     index: (usize::max_value() ^ 1) - 2 * i,
@@ -391,7 +497,92 @@ impl Component {
             show_final_separator,
             display_two_rows,
             current_split_gradient: self.settings.current_split_gradient,
+            indent_subsplits: self.settings.indent_subsplits,
+            subsplit_gradient: self.settings.subsplit_gradient,
+            section_header_gradient: self.settings.section_header_gradient,
+            section_header_text_color: self.settings.section_header_text_color,
+        }
+    }
+
+    /// Determines the rows that can be shown by the component. If the segments
+    /// are not grouped into sections, there is exactly one row per segment.
+    fn rows<'a>(&self, timer: &'a Timer) -> Vec<Row<'a>> {
+        let segments = timer.run().segments();
+
+        if !self.settings.group_subsplits {
+            return segments
+                .iter()
+                .enumerate()
+                .map(|(i, segment)| Row {
+                    kind: SplitKind::Segment,
+                    name: segment.name(),
+                    start: i,
+                    end: i,
+                })
+                .collect();
+        }
+
+        let sections = section::sections(segments);
+        let current_section = timer
+            .current_split_index()
+            .and_then(|c_s| sections.iter().position(|s| s.contains(c_s)));
+        let only_section = current_section
+            .filter(|&c_s| self.settings.current_section_only && sections[c_s].has_subsplits());
+
+        let mut rows = Vec::with_capacity(segments.len());
+
+        for (index, section) in sections.iter().enumerate() {
+            if only_section.map_or(false, |o_s| o_s != index) {
+                continue;
+            }
+
+            if !section.has_subsplits() {
+                rows.push(Row {
+                    kind: SplitKind::Segment,
+                    name: section::display_name(segments[section.end].name()),
+                    start: section.start,
+                    end: section.end,
+                });
+                continue;
+            }
+
+            let expanded = match self.settings.subsplit_visibility {
+                SubsplitVisibility::CurrentSection => current_section == Some(index),
+                SubsplitVisibility::Always => true,
+                SubsplitVisibility::Never => false,
+            };
+
+            if !expanded {
+                rows.push(Row {
+                    kind: SplitKind::Section,
+                    name: section.name,
+                    start: section.start,
+                    end: section.end,
+                });
+                continue;
+            }
+
+            if self.settings.show_section_headers {
+                rows.push(Row {
+                    kind: SplitKind::SectionHeader,
+                    name: section.name,
+                    start: section.start,
+                    end: section.end,
+                });
+            }
+
+            let subsplits = &segments[section.start..=section.end];
+            for (i, segment) in (section.start..).zip(subsplits) {
+                rows.push(Row {
+                    kind: SplitKind::Subsplit,
+                    name: section::display_name(segment.name()),
+                    start: i,
+                    end: i,
+                });
+            }
         }
+
+        rows
     }
 
     /// Accesses a generic description of the settings available for this
@@ -435,6 +626,42 @@ impl Component {
                 "Show Column Labels".into(),
                 self.settings.show_column_labels.into(),
             ),
+            Field::new(
+                "Group Subsplits into Sections".into(),
+                self.settings.group_subsplits.into(),
+            ),
+            Field::new(
+                "Show Subsplits".into(),
+                self.settings.subsplit_visibility.into(),
+            ),
+            Field::new(
+                "Only Show Current Section".into(),
+                self.settings.current_section_only.into(),
+            ),
+            Field::new(
+                "Indent Subsplits".into(),
+                self.settings.indent_subsplits.into(),
+            ),
+            Field::new(
+                "Show Section Headers".into(),
+                self.settings.show_section_headers.into(),
+            ),
+            Field::new(
+                "Show Times in Section Headers".into(),
+                self.settings.show_section_header_times.into(),
+            ),
+            Field::new(
+                "Subsplit Background".into(),
+                self.settings.subsplit_gradient.into(),
+            ),
+            Field::new(
+                "Section Header Background".into(),
+                self.settings.section_header_gradient.into(),
+            ),
+            Field::new(
+                "Section Header Text Color".into(),
+                self.settings.section_header_text_color.into(),
+            ),
             Field::new(
                 "Columns".into(),
                 Value::UInt(self.settings.columns.len() as _),
@@ -491,7 +718,16 @@ impl Component {
             7 => self.settings.display_two_rows = value.into(),
             8 => self.settings.current_split_gradient = value.into(),
             9 => self.settings.show_column_labels = value.into(),
-            10 => {
+            10 => self.settings.group_subsplits = value.into(),
+            11 => self.settings.subsplit_visibility = value.into(),
+            12 => self.settings.current_section_only = value.into(),
+            13 => self.settings.indent_subsplits = value.into(),
+            14 => self.settings.show_section_headers = value.into(),
+            15 => self.settings.show_section_header_times = value.into(),
+            16 => self.settings.subsplit_gradient = value.into(),
+            17 => self.settings.section_header_gradient = value.into(),
+            18 => self.settings.section_header_text_color = value.into(),
+            19 => {
                 let new_len = value.into_uint().unwrap() as usize;
                 self.settings.columns.resize(new_len, Default::default());
             }
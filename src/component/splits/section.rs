@@ -0,0 +1,83 @@
+use crate::Segment;
+
+/// Specifies when the subsplits of a section are shown.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum SubsplitVisibility {
+    /// Only the subsplits of the section the current segment belongs to are
+    /// shown. All the other sections are collapsed into a single row.
+    CurrentSection,
+    /// The subsplits of all the sections are always shown.
+    Always,
+    /// The subsplits are never shown. All the sections are always collapsed
+    /// into a single row.
+    Never,
+}
+
+/// A group of consecutive segments. A section consists of any amount of
+/// subsplits, which are segments whose names start with `-`, followed by the
+/// segment that ends the section. If the name of that segment starts with a
+/// name in curly braces, like `{Section} Segment`, that name is the name of
+/// the section.
+pub struct Section<'a> {
+    pub name: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Section<'_> {
+    pub fn has_subsplits(&self) -> bool {
+        self.start != self.end
+    }
+
+    pub fn contains(&self, segment_index: usize) -> bool {
+        self.start <= segment_index && segment_index <= self.end
+    }
+}
+
+/// Groups the segments into sections based on their names.
+pub fn sections(segments: &[Segment]) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+
+    for (index, segment) in segments.iter().enumerate() {
+        if !is_subsplit(segment.name()) || index + 1 == segments.len() {
+            sections.push(Section {
+                name: section_name(segment.name()),
+                start,
+                end: index,
+            });
+            start = index + 1;
+        }
+    }
+
+    sections
+}
+
+/// Strips the prefixes that are used for grouping the segments from the
+/// segment's name.
+pub fn display_name(name: &str) -> &str {
+    if is_subsplit(name) {
+        return &name[1..];
+    }
+    match section_header(name) {
+        Some((section, "")) => section,
+        Some((_, rest)) => rest,
+        None => name,
+    }
+}
+
+fn section_name(name: &str) -> &str {
+    section_header(name).map_or_else(|| display_name(name), |(section, _)| section)
+}
+
+fn is_subsplit(name: &str) -> bool {
+    name.starts_with('-')
+}
+
+fn section_header(name: &str) -> Option<(&str, &str)> {
+    if !name.starts_with('{') {
+        return None;
+    }
+    let end = name.find('}')?;
+    Some((&name[1..end], name[end + 1..].trim()))
+}
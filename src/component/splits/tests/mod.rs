@@ -5,6 +5,8 @@ use super::{
 use crate::{Run, Segment, TimeSpan, Timer, TimingMethod};
 
 pub mod column;
+pub mod section;
+pub mod statistics;
 
 #[test]
 fn zero_visual_split_count_always_shows_all_splits() {
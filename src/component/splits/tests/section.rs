@@ -0,0 +1,199 @@
+use super::{
+    ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, Component, Settings,
+    State,
+};
+use crate::component::splits::{SplitKind, SubsplitVisibility};
+use crate::tests_helper::{
+    create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+};
+use crate::Timer;
+
+const NAMES: &[&str] = &["-A1", "-A2", "{A} A3", "B", "-C1", "{C} C2"];
+
+fn grouped(settings: Settings) -> Component {
+    Component::with_settings(Settings {
+        group_subsplits: true,
+        fill_with_blank_space: false,
+        ..settings
+    })
+}
+
+fn rows(state: &State) -> Vec<(SplitKind, &str)> {
+    state
+        .splits
+        .iter()
+        .map(|s| (s.kind, s.name.as_str()))
+        .collect()
+}
+
+fn calculate(component: &mut Component, timer: &Timer) -> State {
+    component.state(timer, &Default::default())
+}
+
+#[test]
+fn segments_are_not_grouped_by_default() {
+    let timer = create_timer(NAMES);
+    let mut component = Component::with_settings(Settings {
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let state = calculate(&mut component, &timer);
+    assert_eq!(state.splits.len(), NAMES.len());
+    assert!(state.splits.iter().all(|s| s.kind == SplitKind::Segment));
+    assert_eq!(state.splits[0].name, "-A1");
+}
+
+#[test]
+fn sections_are_collapsed_without_an_attempt() {
+    let timer = create_timer(NAMES);
+    let mut component = grouped(Default::default());
+
+    let state = calculate(&mut component, &timer);
+    assert_eq!(
+        rows(&state),
+        [
+            (SplitKind::Section, "A"),
+            (SplitKind::Segment, "B"),
+            (SplitKind::Section, "C"),
+        ]
+    );
+    assert_eq!(
+        state.splits.iter().map(|s| s.index).collect::<Vec<_>>(),
+        [2, 3, 5]
+    );
+}
+
+#[test]
+fn current_section_is_expanded() {
+    let mut timer = create_timer(NAMES);
+    let mut component = grouped(Default::default());
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0)]);
+
+    let state = calculate(&mut component, &timer);
+    assert_eq!(
+        rows(&state),
+        [
+            (SplitKind::SectionHeader, "A"),
+            (SplitKind::Subsplit, "A1"),
+            (SplitKind::Subsplit, "A2"),
+            (SplitKind::Subsplit, "A3"),
+            (SplitKind::Segment, "B"),
+            (SplitKind::Section, "C"),
+        ]
+    );
+    assert_eq!(
+        state
+            .splits
+            .iter()
+            .map(|s| s.is_current_split)
+            .collect::<Vec<_>>(),
+        [false, false, true, false, false, false]
+    );
+
+    let mut indices = state.splits.iter().map(|s| s.index).collect::<Vec<_>>();
+    indices.sort_unstable();
+    assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn subsplit_visibility() {
+    let mut timer = create_timer(NAMES);
+    start_run(&mut timer);
+
+    let mut component = grouped(Settings {
+        subsplit_visibility: SubsplitVisibility::Never,
+        ..Default::default()
+    });
+    let state = calculate(&mut component, &timer);
+    assert_eq!(state.splits[0].kind, SplitKind::Section);
+    assert!(state.splits[0].is_current_split);
+
+    let mut component = grouped(Settings {
+        subsplit_visibility: SubsplitVisibility::Always,
+        show_section_headers: false,
+        ..Default::default()
+    });
+    let state = calculate(&mut component, &timer);
+    assert_eq!(
+        rows(&state),
+        [
+            (SplitKind::Subsplit, "A1"),
+            (SplitKind::Subsplit, "A2"),
+            (SplitKind::Subsplit, "A3"),
+            (SplitKind::Segment, "B"),
+            (SplitKind::Subsplit, "C1"),
+            (SplitKind::Subsplit, "C2"),
+        ]
+    );
+}
+
+#[test]
+fn current_section_only() {
+    let mut timer = create_timer(NAMES);
+    let mut component = grouped(Settings {
+        current_section_only: true,
+        ..Default::default()
+    });
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), Some(2.0), Some(3.0)]);
+
+    // The current segment is not part of a section with subsplits, so
+    // everything is shown.
+    assert_eq!(calculate(&mut component, &timer).splits.len(), 3);
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0)]);
+
+    let state = calculate(&mut component, &timer);
+    assert_eq!(
+        rows(&state),
+        [
+            (SplitKind::SectionHeader, "C"),
+            (SplitKind::Subsplit, "C1"),
+            (SplitKind::Subsplit, "C2"),
+        ]
+    );
+}
+
+#[test]
+fn collapsed_sections_show_section_deltas() {
+    let mut timer = create_timer(NAMES);
+    run_with_splits(&mut timer, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let mut component = grouped(Settings {
+        columns: vec![
+            ColumnSettings {
+                start_with: ColumnStartWith::ComparisonSegmentTime,
+                update_with: ColumnUpdateWith::SegmentDelta,
+                update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+                ..Default::default()
+            },
+            ColumnSettings {
+                start_with: ColumnStartWith::ComparisonTime,
+                update_with: ColumnUpdateWith::Delta,
+                update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    });
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.5), Some(3.0), Some(5.0)]);
+
+    let state = calculate(&mut component, &timer);
+    assert_eq!(state.splits[0].kind, SplitKind::Section);
+
+    // The whole section took 2 seconds longer than in the Personal Best.
+    assert_eq!(state.splits[0].columns[0].value, "+2.0");
+    assert_eq!(state.splits[0].columns[1].value, "+2.0");
+
+    // Sections that haven't been reached yet show the comparison's time for
+    // the whole section.
+    assert_eq!(state.splits[2].kind, SplitKind::Section);
+    assert_eq!(state.splits[2].columns[0].value, "0:02");
+    assert_eq!(state.splits[2].columns[1].value, "0:06");
+}
@@ -0,0 +1,83 @@
+use super::{ColumnSettings, ColumnStartWith, Component, Settings};
+use crate::tests_helper::{create_timer, run_with_splits_opt};
+use crate::Timer;
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(1.0), Some(3.0), Some(6.0)]);
+    // Reset on the second segment.
+    run_with_splits_opt(&mut timer, &[Some(2.0)]);
+    // Reset on the first segment.
+    run_with_splits_opt(&mut timer, &[]);
+    run_with_splits_opt(&mut timer, &[Some(1.5), Some(4.5), Some(6.5)]);
+
+    timer
+}
+
+fn values(timer: &Timer, start_with: ColumnStartWith) -> Vec<String> {
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            start_with,
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    component
+        .state(timer, &Default::default())
+        .splits
+        .into_iter()
+        .map(|split| split.columns[0].value.clone())
+        .collect()
+}
+
+#[test]
+fn reset_count() {
+    assert_eq!(
+        values(&timer(), ColumnStartWith::ResetCount),
+        ["1", "1", "0"]
+    );
+}
+
+#[test]
+fn survival_rate() {
+    assert_eq!(
+        values(&timer(), ColumnStartWith::SurvivalRate),
+        ["75.0%", "66.7%", "100.0%"]
+    );
+}
+
+#[test]
+fn gold_count() {
+    assert_eq!(
+        values(&timer(), ColumnStartWith::GoldCount),
+        ["1", "1", "2"]
+    );
+}
+
+#[test]
+fn segment_time_statistics() {
+    let timer = timer();
+    assert_eq!(
+        values(&timer, ColumnStartWith::AverageSegmentTime),
+        ["0:01", "0:02", "0:02"]
+    );
+    assert_eq!(
+        values(&timer, ColumnStartWith::MedianSegmentTime),
+        ["0:01", "0:02", "0:02"]
+    );
+    assert_eq!(
+        values(&timer, ColumnStartWith::StandardDeviation),
+        ["0:00.5", "0:00.7", "0:00.7"]
+    );
+}
+
+#[test]
+fn best_split_time() {
+    assert_eq!(
+        values(&timer(), ColumnStartWith::BestSplitTime),
+        ["0:01", "0:03", "0:06"]
+    );
+}
@@ -2,14 +2,43 @@
 //! Component simply visualizes any given text. This can either be a single
 //! centered text, or split up into a left and right text, which is suitable for
 //! a situation where you have a label and a value.
+//!
+//! The text may contain variables that are resolved every time the state is
+//! calculated. They are written as `{name}` or `{name:argument}`. The following
+//! variables are supported:
+//!
+//! - `{game}`: The name of the game.
+//! - `{category}`: The name of the category.
+//! - `{platform}`: The name of the platform the game is played on.
+//! - `{region}`: The name of the region of the game.
+//! - `{attempts}`: The amount of attempts.
+//! - `{finished}`: The amount of finished attempts.
+//! - `{current_split}`: The name of the segment the attempt is currently on.
+//! - `{delta}`: The delta of the current attempt to the current comparison.
+//! - `{sob}`: The Sum of Best Segments.
+//! - `{pb}`: The final time of the Personal Best.
+//! - `{comparison}`: The name of the current comparison.
+//! - `{comparison:Name}`: The final time of the comparison with the given name.
+//! - `{variable:Name}`: The value of the speedrun.com variable with the given
+//!   name.
+//!
+//! Variables that are not known are shown as they are. Literal braces can be
+//! written as `{{` and `}}`.
 
 use super::DEFAULT_INFO_TEXT_GRADIENT;
 use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::timing::formatter::Accuracy;
+use crate::Timer;
 use serde_json::{to_writer, Result};
 use std::borrow::Cow;
 use std::io::Write;
 use std::mem::replace;
 
+#[cfg(test)]
+mod tests;
+
+mod template;
+
 /// The Text Component simply visualizes any given text. This can either be a
 /// single centered text, or split up into a left and right text, which is
 /// suitable for a situation where you have a label and a value.
@@ -35,8 +64,16 @@ pub struct Settings {
     /// the text is not split up. If `None` is specified, the color is taken
     /// from the layout.
     pub right_color: Option<Color>,
-    /// The text to be shown.
+    /// The text to be shown. It may contain variables that get resolved when
+    /// the state is calculated.
     pub text: Text,
+    /// The accuracy of the times the variables resolve to.
+    pub accuracy: Accuracy,
+    /// Specifies if the decimals of deltas should not be shown anymore when
+    /// the deltas are over a minute.
+    pub drop_decimals: bool,
+    /// The accuracy of the deltas the variables resolve to.
+    pub delta_accuracy: Accuracy,
 }
 
 /// The text that is supposed to be shown.
@@ -108,7 +145,8 @@ pub struct State {
     /// the text is not split up. If `None` is specified, the color is taken
     /// from the layout.
     pub right_color: Option<Color>,
-    /// The text to show for the component.
+    /// The text to show for the component. All of its variables are already
+    /// resolved.
     pub text: Text,
 }
 
@@ -120,6 +158,9 @@ impl Default for Settings {
             left_center_color: None,
             right_color: None,
             text: Text::Center(String::from("")),
+            accuracy: Accuracy::Seconds,
+            drop_decimals: true,
+            delta_accuracy: Accuracy::Tenths,
         }
     }
 }
@@ -177,14 +218,22 @@ impl Component {
         }
     }
 
-    /// Calculates the component's state.
-    pub fn state(&self) -> State {
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        let text = match &self.settings.text {
+            Text::Center(text) => Text::Center(template::resolve(text, timer, &self.settings)),
+            Text::Split(left, right) => Text::Split(
+                template::resolve(left, timer, &self.settings),
+                template::resolve(right, timer, &self.settings),
+            ),
+        };
+
         State {
             background: self.settings.background,
             display_two_rows: self.settings.text.is_split() && self.settings.display_two_rows,
             left_center_color: self.settings.left_center_color,
             right_color: self.settings.right_color,
-            text: self.settings.text.clone(),
+            text,
         }
     }
 
@@ -223,6 +272,16 @@ impl Component {
             ));
         }
 
+        fields.push(Field::new("Accuracy".into(), self.settings.accuracy.into()));
+        fields.push(Field::new(
+            "Drop Decimals".into(),
+            self.settings.drop_decimals.into(),
+        ));
+        fields.push(Field::new(
+            "Delta Accuracy".into(),
+            self.settings.delta_accuracy.into(),
+        ));
+
         SettingsDescription::with_fields(fields)
     }
 
@@ -234,6 +293,14 @@ impl Component {
     /// the type of the setting's value. A panic can also occur if the index of
     /// the setting provided is out of bounds.
     pub fn set_value(&mut self, index: usize, value: Value) {
+        // The settings of the right text are only available if the text is
+        // split up, so the settings after them are shifted.
+        let index = if !self.settings.text.is_split() && index >= 4 {
+            index + 3
+        } else {
+            index
+        };
+
         match index {
             0 => self.settings.background = value.into(),
             1 => {
@@ -268,6 +335,9 @@ impl Component {
             },
             5 => self.settings.right_color = value.into(),
             6 => self.settings.display_two_rows = value.into(),
+            7 => self.settings.accuracy = value.into(),
+            8 => self.settings.drop_decimals = value.into(),
+            9 => self.settings.delta_accuracy = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
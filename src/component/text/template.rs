@@ -0,0 +1,103 @@
+use super::Settings;
+use crate::analysis::{attempts, delta, sum_of_segments::calculate_best};
+use crate::timing::formatter::{Delta, Regular, TimeFormatter};
+use crate::{Timer, TimerPhase};
+
+/// Resolves all the variables in the template based on the timer provided.
+/// Variables are written as `{name}` or `{name:argument}`. Variables that are
+/// not known are kept as they are. Literal braces can be written as `{{` and
+/// `}}`.
+pub fn resolve(template: &str, timer: &Timer, settings: &Settings) -> String {
+    let mut resolved = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(index) = rest.find(&['{', '}'][..]) {
+        resolved.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            resolved.push_str(&rest[..1]);
+            rest = &rest[2..];
+        } else if rest.starts_with('}') {
+            resolved.push('}');
+            rest = &rest[1..];
+        } else if let Some(end) = rest.find('}') {
+            match variable(&rest[1..end], timer, settings) {
+                Some(value) => resolved.push_str(&value),
+                None => resolved.push_str(&rest[..=end]),
+            }
+            rest = &rest[end + 1..];
+        } else {
+            break;
+        }
+    }
+
+    resolved.push_str(rest);
+    resolved
+}
+
+fn variable(variable: &str, timer: &Timer, settings: &Settings) -> Option<String> {
+    let (name, argument) = match variable.find(':') {
+        Some(index) => (&variable[..index], Some(&variable[index + 1..])),
+        None => (variable, None),
+    };
+
+    let run = timer.run();
+    let method = timer.current_timing_method();
+    let time = Regular::with_accuracy(settings.accuracy);
+
+    Some(match (name, argument) {
+        ("game", None) => run.game_name().to_owned(),
+        ("category", None) => run.category_name().to_owned(),
+        ("platform", None) => run.metadata().platform_name().to_owned(),
+        ("region", None) => run.metadata().region_name().to_owned(),
+        ("attempts", None) => run.attempt_count().to_string(),
+        ("finished", None) => {
+            let mut count = run
+                .attempt_history()
+                .iter()
+                .filter(|a| attempts::is_finished(a))
+                .count();
+
+            if timer.current_phase() == TimerPhase::Ended {
+                count += 1;
+            }
+
+            count.to_string()
+        }
+        ("current_split", None) => timer
+            .current_split()
+            .map_or_else(String::new, |s| s.name().to_owned()),
+        ("delta", None) => {
+            let (delta, _) = delta::calculate(timer, timer.current_comparison());
+            Delta::custom(settings.drop_decimals, settings.delta_accuracy)
+                .format(delta)
+                .to_string()
+        }
+        ("sob", None) => time
+            .format(calculate_best(run.segments(), false, true, method))
+            .to_string(),
+        ("pb", None) => time
+            .format(
+                run.segments()
+                    .last()
+                    .and_then(|s| s.personal_best_split_time()[method]),
+            )
+            .to_string(),
+        ("comparison", None) => timer.current_comparison().to_owned(),
+        ("comparison", Some(comparison)) => time
+            .format(
+                run.segments()
+                    .last()
+                    .and_then(|s| s.comparison(comparison)[method]),
+            )
+            .to_string(),
+        ("variable", Some(name)) => run
+            .metadata()
+            .variables
+            .get(name)
+            .cloned()
+            .unwrap_or_default(),
+        _ => return None,
+    })
+}
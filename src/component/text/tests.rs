@@ -0,0 +1,102 @@
+use super::{Component, Text};
+use crate::tests_helper::{create_run, run_with_splits, start_run};
+use crate::{Time, TimeSpan, Timer, TimingMethod};
+
+fn center(component: &Component, timer: &Timer) -> String {
+    match component.state(timer).text {
+        Text::Center(text) => text,
+        Text::Split(_, _) => panic!("Expected a centered text"),
+    }
+}
+
+fn timer() -> Timer {
+    let mut run = create_run(&["A", "B"]);
+    run.set_game_name("Super Mario Odyssey");
+    run.set_category_name("Any%");
+    run.metadata_mut().set_variable("Platform", "Switch");
+    let mut timer = Timer::new(run).unwrap();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    timer
+}
+
+#[test]
+fn resolves_run_information() {
+    let timer = timer();
+    let mut component = Component::new();
+    component
+        .settings_mut()
+        .text
+        .set_center("{game} - {category} ({variable:Platform})");
+
+    assert_eq!(
+        center(&component, &timer),
+        "Super Mario Odyssey - Any% (Switch)"
+    );
+}
+
+#[test]
+fn resolves_times() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[15.0, 25.0]);
+
+    let mut component = Component::new();
+    component
+        .settings_mut()
+        .text
+        .set_center("{attempts} {finished} {pb} {sob}");
+    assert_eq!(center(&component, &timer), "2 2 0:25 0:20");
+
+    component
+        .settings_mut()
+        .text
+        .set_center("{comparison:Best Segments}");
+    assert_eq!(center(&component, &timer), "0:20");
+}
+
+#[test]
+fn counts_attempts_finished_in_game_time_only() {
+    let mut run = create_run(&["A", "B"]);
+    let game_time = Time::new().with_game_time(Some(TimeSpan::from_seconds(20.0)));
+    run.add_attempt_with_index(game_time, 1, None, None, None);
+    run.add_attempt_with_index(Time::new(), 2, None, None, None);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    component.settings_mut().text.set_center("{finished}");
+    assert_eq!(center(&component, &timer), "1");
+}
+
+#[test]
+fn resolves_the_current_attempt() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    start_run(&mut timer);
+
+    let mut component = Component::new();
+    component.settings_mut().text.set_left("{current_split}");
+    component.settings_mut().text.set_right("{delta}");
+
+    timer.set_game_time(TimeSpan::from_seconds(12.0));
+    timer.split();
+
+    match component.state(&timer).text {
+        Text::Split(left, right) => {
+            assert_eq!(left, "B");
+            assert_eq!(right, "+2.0");
+        }
+        Text::Center(_) => panic!("Expected a split text"),
+    }
+}
+
+#[test]
+fn keeps_unknown_variables_and_escaped_braces() {
+    let timer = timer();
+    let mut component = Component::new();
+    component
+        .settings_mut()
+        .text
+        .set_center("{unknown} {{game}} {game");
+
+    assert_eq!(center(&component, &timer), "{unknown} {game} {game");
+}
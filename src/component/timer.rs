@@ -1,11 +1,13 @@
 //! Provides the Timer Component and relevant types for using it. The Timer
 //! Component is a component that shows the total time of the current attempt as
 //! a digital clock. The color of the time shown is based on a how well the
-//! current attempt is doing compared to the chosen comparison.
+//! current attempt is doing compared to the chosen comparison. Instead of the
+//! time of the current attempt, the Timer Component can also count down to a
+//! goal or show the time left to beat the current comparison.
 
 use crate::analysis::split_color;
 use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
-use crate::timing::formatter::{timer as formatter, Accuracy, DigitsFormat, TimeFormatter};
+use crate::timing::formatter::{timer as formatter, Accuracy, DigitsFormat, TimeFormatter, DASH};
 use crate::{GeneralLayoutSettings, TimeSpan, Timer, TimerPhase, TimingMethod};
 use palette::rgb::LinSrgb;
 use palette::Hsv;
@@ -21,6 +23,25 @@ pub struct Component {
     settings: Settings,
 }
 
+/// Describes which time the Timer Component shows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimerMode {
+    /// The time of the current attempt is shown.
+    CountUp,
+    /// The time left until the countdown goal is reached is shown. Once the
+    /// time runs out, the time shown becomes negative.
+    Countdown,
+    /// The time left until the final time of the current comparison is reached
+    /// is shown. This is how much time is left to beat the current comparison.
+    RemainingToBeatComparison,
+}
+
+impl Default for TimerMode {
+    fn default() -> Self {
+        TimerMode::CountUp
+    }
+}
+
 /// The Settings for this component.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -46,6 +67,15 @@ pub struct Settings {
     pub digits_format: DigitsFormat,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies which time is shown.
+    pub mode: TimerMode,
+    /// The time the countdown counts down from. Only used in the Countdown
+    /// mode.
+    pub countdown_goal: TimeSpan,
+    /// The color of the time shown once the time of a countdown ran out. If
+    /// `None` is specified, the layout's color for losing time while being
+    /// behind is used.
+    pub time_up_color: Option<Color>,
 }
 
 impl Default for Settings {
@@ -58,6 +88,9 @@ impl Default for Settings {
             show_gradient: true,
             digits_format: DigitsFormat::SingleDigitSeconds,
             accuracy: Accuracy::Hundredths,
+            mode: TimerMode::CountUp,
+            countdown_goal: TimeSpan::zero(),
+            time_up_color: None,
         }
     }
 }
@@ -79,6 +112,9 @@ pub struct State {
     pub bottom_color: Color,
     /// The height of the timer.
     pub height: u32,
+    /// Specifies whether the time of a countdown ran out. This is never the
+    /// case when counting up.
+    pub is_time_up: bool,
 }
 
 impl State {
@@ -114,7 +150,11 @@ impl Component {
 
     /// Accesses the name of the component.
     pub fn name(&self) -> Cow<'_, str> {
-        "Timer".into()
+        match self.settings.mode {
+            TimerMode::CountUp => "Timer".into(),
+            TimerMode::Countdown => "Timer (Countdown)".into(),
+            TimerMode::RemainingToBeatComparison => "Timer (Remaining)".into(),
+        }
     }
 
     /// Calculates the component's state based on the timer and the layout
@@ -168,10 +208,30 @@ impl Component {
             _ => SemanticColor::NotRunning,
         };
 
-        let visual_color = if let Some(color) = self.settings.color_override {
-            color
+        let shown_time = match self.settings.mode {
+            TimerMode::CountUp => Some(time),
+            TimerMode::Countdown => Some(self.settings.countdown_goal - time),
+            TimerMode::RemainingToBeatComparison => timer
+                .run()
+                .segments()
+                .last()
+                .and_then(|s| s.comparison(current_comparison)[method])
+                .map(|final_time| final_time - time),
+        };
+        let is_time_up = self.settings.mode != TimerMode::CountUp
+            && shown_time.map_or(false, |t| t <= TimeSpan::zero());
+
+        let (semantic_color, visual_color) = if is_time_up {
+            let semantic_color = SemanticColor::BehindLosingTime;
+            let visual_color = self
+                .settings
+                .time_up_color
+                .unwrap_or_else(|| semantic_color.visualize(layout_settings));
+            (semantic_color, visual_color)
+        } else if let Some(color) = self.settings.color_override {
+            (semantic_color, color)
         } else {
-            semantic_color.visualize(layout_settings)
+            (semantic_color, semantic_color.visualize(layout_settings))
         };
 
         let (top_color, bottom_color) = if self.settings.show_gradient {
@@ -180,18 +240,35 @@ impl Component {
             (visual_color, visual_color)
         };
 
+        let (time, fraction) = if let Some(time) = shown_time {
+            // Countdowns round up, so that they only show zero once the time
+            // actually ran out.
+            let time = if self.settings.mode == TimerMode::CountUp {
+                time
+            } else {
+                formatter::round_up(time, self.settings.accuracy)
+            };
+            (
+                formatter::Time::with_digits_format(self.settings.digits_format)
+                    .format(time)
+                    .to_string(),
+                formatter::Fraction::with_accuracy(self.settings.accuracy)
+                    .format(time)
+                    .to_string(),
+            )
+        } else {
+            (DASH.into(), String::new())
+        };
+
         State {
             background: self.settings.background,
-            time: formatter::Time::with_digits_format(self.settings.digits_format)
-                .format(time)
-                .to_string(),
-            fraction: formatter::Fraction::with_accuracy(self.settings.accuracy)
-                .format(time)
-                .to_string(),
+            time,
+            fraction,
             semantic_color,
             top_color,
             bottom_color,
             height: self.settings.height,
+            is_time_up,
         }
     }
 
@@ -206,6 +283,12 @@ impl Component {
             Field::new("Show Gradient".into(), self.settings.show_gradient.into()),
             Field::new("Digits Format".into(), self.settings.digits_format.into()),
             Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Mode".into(), self.settings.mode.into()),
+            Field::new(
+                "Countdown Goal".into(),
+                self.settings.countdown_goal.total_seconds().into(),
+            ),
+            Field::new("Time Up Color".into(), self.settings.time_up_color.into()),
         ])
     }
 
@@ -225,6 +308,9 @@ impl Component {
             4 => self.settings.show_gradient = value.into(),
             5 => self.settings.digits_format = value.into(),
             6 => self.settings.accuracy = value.into(),
+            7 => self.settings.mode = value.into(),
+            8 => self.settings.countdown_goal = TimeSpan::from_seconds(value.into_float().unwrap()),
+            9 => self.settings.time_up_color = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
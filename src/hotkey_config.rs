@@ -30,6 +30,12 @@ pub struct HotkeyConfig {
     /// The key to use for toggling between the `Real Time` and `Game Time`
     /// timing methods.
     pub toggle_timing_method: Option<KeyCode>,
+    /// The key to use for incrementing all the counters of the timer.
+    pub increment_counter: Option<KeyCode>,
+    /// The key to use for decrementing all the counters of the timer.
+    pub decrement_counter: Option<KeyCode>,
+    /// The key to use for resetting all the counters of the timer to 0.
+    pub reset_counter: Option<KeyCode>,
 }
 
 #[cfg(any(windows, target_os = "linux"))]
@@ -46,6 +52,9 @@ impl Default for HotkeyConfig {
             previous_comparison: Some(NumPad4),
             next_comparison: Some(NumPad6),
             toggle_timing_method: None,
+            increment_counter: None,
+            decrement_counter: None,
+            reset_counter: None,
         }
     }
 }
@@ -67,6 +76,9 @@ impl Default for HotkeyConfig {
             previous_comparison: Some(Numpad4),
             next_comparison: Some(Numpad6),
             toggle_timing_method: None,
+            increment_counter: None,
+            decrement_counter: None,
+            reset_counter: None,
         }
     }
 }
@@ -89,6 +101,9 @@ impl Default for HotkeyConfig {
             previous_comparison: Some(KeyCode),
             next_comparison: Some(KeyCode),
             toggle_timing_method: None,
+            increment_counter: None,
+            decrement_counter: None,
+            reset_counter: None,
         }
     }
 }
@@ -113,6 +128,9 @@ impl HotkeyConfig {
                 "Toggle Timing Method".into(),
                 self.toggle_timing_method.into(),
             ),
+            Field::new("Increment Counter".into(), self.increment_counter.into()),
+            Field::new("Decrement Counter".into(), self.decrement_counter.into()),
+            Field::new("Reset Counter".into(), self.reset_counter.into()),
         ])
     }
 
@@ -138,9 +156,13 @@ impl HotkeyConfig {
                 self.undo,
                 self.skip,
                 self.pause,
+                self.undo_all_pauses,
                 self.previous_comparison,
                 self.next_comparison,
-                self.undo_all_pauses,
+                self.toggle_timing_method,
+                self.increment_counter,
+                self.decrement_counter,
+                self.reset_counter,
             ]
             .iter()
             .enumerate()
@@ -162,6 +184,9 @@ impl HotkeyConfig {
             6 => self.previous_comparison = value,
             7 => self.next_comparison = value,
             8 => self.toggle_timing_method = value,
+            9 => self.increment_counter = value,
+            10 => self.decrement_counter = value,
+            11 => self.reset_counter = value,
             _ => panic!("Unsupported Setting Index"),
         }
 
@@ -1,10 +1,12 @@
 use crate::hotkey::{Hook, KeyCode};
-use crate::{HotkeyConfig, SharedTimer};
+use crate::{HotkeyConfig, SharedTimer, Timer};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
 pub use crate::hotkey::{Error, Result};
 
+type CounterAction = fn(&mut Timer, &str);
+
 /// With a Hotkey System the runner can use hotkeys on their keyboard to control
 /// the Timer. The hotkeys are global, so the application doesn't need to be in
 /// focus. The behavior of the hotkeys depends on the platform and is stubbed
@@ -15,6 +17,7 @@ pub struct HotkeySystem {
     hook: Hook,
     timer: SharedTimer,
     is_active: Arc<AtomicBool>,
+    counter_hotkeys: Vec<KeyCode>,
 }
 
 impl HotkeySystem {
@@ -120,12 +123,46 @@ impl HotkeySystem {
             })?;
         }
 
-        Ok(Self {
+        if let Some(increment_counter) = config.increment_counter {
+            let inner = timer.clone();
+            let active = is_active.clone();
+            hook.register(increment_counter, move || {
+                if active.load(Ordering::Acquire) {
+                    inner.write().increment_counters();
+                }
+            })?;
+        }
+
+        if let Some(decrement_counter) = config.decrement_counter {
+            let inner = timer.clone();
+            let active = is_active.clone();
+            hook.register(decrement_counter, move || {
+                if active.load(Ordering::Acquire) {
+                    inner.write().decrement_counters();
+                }
+            })?;
+        }
+
+        if let Some(reset_counter) = config.reset_counter {
+            let inner = timer.clone();
+            let active = is_active.clone();
+            hook.register(reset_counter, move || {
+                if active.load(Ordering::Acquire) {
+                    inner.write().reset_counters();
+                }
+            })?;
+        }
+
+        let mut system = Self {
             config,
             hook,
             timer,
             is_active,
-        })
+            counter_hotkeys: Vec::new(),
+        };
+        system.update_counter_hotkeys()?;
+
+        Ok(system)
     }
 
     /// Sets the key to use for splitting and starting a new attempt.
@@ -320,6 +357,103 @@ impl HotkeySystem {
         Ok(())
     }
 
+    /// Sets the key to use for incrementing all the counters of the timer.
+    pub fn set_increment_counter(&mut self, hotkey: Option<KeyCode>) -> Result<()> {
+        if self.config.increment_counter == hotkey {
+            return Ok(());
+        }
+        let inner = self.timer.clone();
+        let active = self.is_active.clone();
+        if let Some(hotkey) = hotkey {
+            self.hook.register(hotkey, move || {
+                if active.load(Ordering::Acquire) {
+                    inner.write().increment_counters();
+                }
+            })?;
+        }
+        if let Some(increment_counter) = self.config.increment_counter {
+            self.hook.unregister(increment_counter)?;
+        }
+        self.config.increment_counter = hotkey;
+        Ok(())
+    }
+
+    /// Sets the key to use for decrementing all the counters of the timer.
+    pub fn set_decrement_counter(&mut self, hotkey: Option<KeyCode>) -> Result<()> {
+        if self.config.decrement_counter == hotkey {
+            return Ok(());
+        }
+        let inner = self.timer.clone();
+        let active = self.is_active.clone();
+        if let Some(hotkey) = hotkey {
+            self.hook.register(hotkey, move || {
+                if active.load(Ordering::Acquire) {
+                    inner.write().decrement_counters();
+                }
+            })?;
+        }
+        if let Some(decrement_counter) = self.config.decrement_counter {
+            self.hook.unregister(decrement_counter)?;
+        }
+        self.config.decrement_counter = hotkey;
+        Ok(())
+    }
+
+    /// Sets the key to use for resetting all the counters of the timer to 0.
+    pub fn set_reset_counter(&mut self, hotkey: Option<KeyCode>) -> Result<()> {
+        if self.config.reset_counter == hotkey {
+            return Ok(());
+        }
+        let inner = self.timer.clone();
+        let active = self.is_active.clone();
+        if let Some(hotkey) = hotkey {
+            self.hook.register(hotkey, move || {
+                if active.load(Ordering::Acquire) {
+                    inner.write().reset_counters();
+                }
+            })?;
+        }
+        if let Some(reset_counter) = self.config.reset_counter {
+            self.hook.unregister(reset_counter)?;
+        }
+        self.config.reset_counter = hotkey;
+        Ok(())
+    }
+
+    /// Registers the hotkeys of the individual counters of the timer, replacing
+    /// the ones that were registered before. This needs to be called again
+    /// whenever the counters of the timer change. This operation may fail if a
+    /// hotkey is used for multiple operations.
+    pub fn update_counter_hotkeys(&mut self) -> Result<()> {
+        while let Some(hotkey) = self.counter_hotkeys.pop() {
+            self.hook.unregister(hotkey)?;
+        }
+
+        let counters = self.timer.read().counters().to_vec();
+        for counter in counters {
+            let actions: [(_, CounterAction); 3] = [
+                (counter.increment_hotkey, Timer::increment_counter),
+                (counter.decrement_hotkey, Timer::decrement_counter),
+                (counter.reset_hotkey, Timer::reset_counter),
+            ];
+            for &(hotkey, action) in &actions {
+                if let Some(hotkey) = hotkey {
+                    let inner = self.timer.clone();
+                    let active = self.is_active.clone();
+                    let name = counter.name.clone();
+                    self.hook.register(hotkey, move || {
+                        if active.load(Ordering::Acquire) {
+                            action(&mut inner.write(), &name);
+                        }
+                    })?;
+                    self.counter_hotkeys.push(hotkey);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Deactivates the Hotkey System. No hotkeys will go through until it gets
     /// activated again. If it's already deactivated, nothing happens.
     pub fn deactivate(&self) {
@@ -351,6 +485,9 @@ impl HotkeySystem {
         self.set_next_comparison(config.next_comparison)?;
         self.set_undo_all_pauses(config.undo_all_pauses)?;
         self.set_toggle_timing_method(config.toggle_timing_method)?;
+        self.set_increment_counter(config.increment_counter)?;
+        self.set_decrement_counter(config.decrement_counter)?;
+        self.set_reset_counter(config.reset_counter)?;
 
         Ok(())
     }
@@ -1,4 +1,5 @@
 use base64::{self, STANDARD};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
@@ -6,6 +7,10 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 static LAST_IMAGE_ID: AtomicUsize = AtomicUsize::new(0);
 
+/// The maximum width and height of icons. Larger icons get shrunk down to this
+/// size if image shrinking is enabled.
+const MAX_ICON_SIZE: u32 = 128;
+
 /// Images can be used to store segment and game icons. Each image object comes
 /// with an ID that changes whenever the image is modified. IDs are unique
 /// across different images. You can query the image's data as a Data URL.
@@ -34,14 +39,44 @@ impl<D: AsRef<[u8]>> From<D> for Image {
     }
 }
 
+impl Serialize for Image {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.url)
+    }
+}
+
+impl<'de> Deserialize<'de> for Image {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // The image data is stored as a Data URL already, so there's no need to
+        // decode it and shrink it again.
+        let url = String::deserialize(deserializer)?;
+        Ok(Image {
+            url,
+            id: LAST_IMAGE_ID.fetch_add(1, Ordering::SeqCst),
+        })
+    }
+}
+
 impl Image {
+    /// The maximum width and height to use for background images. These are
+    /// shown at a much larger size than icons, so they are allowed to be
+    /// larger.
+    pub const MAX_BACKGROUND_SIZE: u32 = 1024;
+
     /// Creates a new image with a unique ID with the image data provided.
     pub fn new(data: &[u8]) -> Self {
+        Image::with_max_size(data, MAX_ICON_SIZE)
+    }
+
+    /// Creates a new image with a unique ID with the image data provided. If
+    /// image shrinking is enabled, the image is shrunk down to the maximum
+    /// width and height provided, instead of the size used for icons.
+    pub fn with_max_size(data: &[u8], max_size: u32) -> Self {
         let mut image = Image {
             url: String::new(),
             id: 0,
         };
-        image.modify(data);
+        image.modify_with_max_size(data, max_size);
         image
     }
 
@@ -88,13 +123,15 @@ impl Image {
     /// Modifies an image by replacing its image data with the new image data
     /// provided. The image's ID changes to a new unique ID.
     pub fn modify(&mut self, data: &[u8]) {
+        self.modify_with_max_size(data, MAX_ICON_SIZE);
+    }
+
+    fn modify_with_max_size(&mut self, data: &[u8], max_size: u32) {
         #[cfg(feature = "image-shrinking")]
-        let data = {
-            use crate::image_shrinking::shrink;
-            const MAX_IMAGE_SIZE: u32 = 128;
+        let data = crate::image_shrinking::shrink(data, max_size);
+        #[cfg(not(feature = "image-shrinking"))]
+        let _ = max_size;
 
-            shrink(data, MAX_IMAGE_SIZE)
-        };
         self.id = LAST_IMAGE_ID.fetch_add(1, Ordering::SeqCst);
         self.url.clear();
 
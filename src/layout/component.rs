@@ -1,8 +1,8 @@
 use super::{ComponentSettings, ComponentState, GeneralSettings};
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
-    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
-    total_playtime,
+    blank_space, clock, counter, current_comparison, current_pace, custom, delta, detailed_timer,
+    graph, image, notes, possible_time_save, previous_segment, run_statistics, segment_history,
+    separator, splits, sum_of_best, text, timer, title, total_playtime,
 };
 use crate::settings::{SettingsDescription, Value};
 use crate::Timer;
@@ -14,20 +14,34 @@ use std::borrow::Cow;
 pub enum Component {
     /// The Blank Space Component.
     BlankSpace(blank_space::Component),
+    /// The Clock Component.
+    Clock(clock::Component),
+    /// The Counter Component.
+    Counter(counter::Component),
     /// The Current Comparison Component.
     CurrentComparison(current_comparison::Component),
     /// The Current Pace Component.
     CurrentPace(current_pace::Component),
+    /// A Custom Component that is implemented outside of this crate.
+    Custom(Box<dyn custom::CustomComponent>),
     /// The Delta Component.
     Delta(delta::Component),
     /// The Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::Component>),
     /// The Graph Component.
     Graph(graph::Component),
+    /// The Image Component.
+    Image(image::Component),
+    /// The Notes Component.
+    Notes(notes::Component),
     /// The Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Component),
     /// The Previous Segment Component.
     PreviousSegment(previous_segment::Component),
+    /// The Run Statistics Component.
+    RunStatistics(run_statistics::Component),
+    /// The Segment History Component.
+    SegmentHistory(segment_history::Component),
     /// The Separator Component.
     Separator(separator::Component),
     /// The Splits Component.
@@ -52,12 +66,17 @@ impl Component {
     pub fn state(&mut self, timer: &Timer, layout_settings: &GeneralSettings) -> ComponentState {
         match self {
             Component::BlankSpace(component) => ComponentState::BlankSpace(component.state(timer)),
+            Component::Clock(component) => ComponentState::Clock(component.state(timer)),
+            Component::Counter(component) => ComponentState::Counter(component.state(timer)),
             Component::CurrentComparison(component) => {
                 ComponentState::CurrentComparison(component.state(timer))
             }
             Component::CurrentPace(component) => {
                 ComponentState::CurrentPace(component.state(timer))
             }
+            Component::Custom(component) => {
+                ComponentState::Custom(component.state(timer, layout_settings))
+            }
             Component::Delta(component) => {
                 ComponentState::Delta(component.state(timer, layout_settings))
             }
@@ -67,18 +86,26 @@ impl Component {
             Component::Graph(component) => {
                 ComponentState::Graph(component.state(timer, layout_settings))
             }
+            Component::Image(component) => ComponentState::Image(component.state(timer)),
+            Component::Notes(component) => ComponentState::Notes(component.state(timer)),
             Component::PossibleTimeSave(component) => {
                 ComponentState::PossibleTimeSave(component.state(timer))
             }
             Component::PreviousSegment(component) => {
                 ComponentState::PreviousSegment(component.state(timer, layout_settings))
             }
+            Component::RunStatistics(component) => {
+                ComponentState::RunStatistics(component.state(timer))
+            }
+            Component::SegmentHistory(component) => {
+                ComponentState::SegmentHistory(component.state(timer, layout_settings))
+            }
             Component::Separator(component) => ComponentState::Separator(component.state(timer)),
             Component::Splits(component) => {
                 ComponentState::Splits(component.state(timer, layout_settings))
             }
             Component::SumOfBest(component) => ComponentState::SumOfBest(component.state(timer)),
-            Component::Text(component) => ComponentState::Text(component.state()),
+            Component::Text(component) => ComponentState::Text(component.state(timer)),
             Component::Timer(component) => {
                 ComponentState::Timer(component.state(timer, layout_settings))
             }
@@ -98,23 +125,39 @@ impl Component {
             Component::BlankSpace(component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::Clock(component) => ComponentSettings::Clock(component.settings().clone()),
+            Component::Counter(component) => {
+                ComponentSettings::Counter(component.settings().clone())
+            }
             Component::CurrentComparison(component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
             Component::CurrentPace(component) => {
                 ComponentSettings::CurrentPace(component.settings().clone())
             }
+            Component::Custom(component) => ComponentSettings::Custom(custom::Settings {
+                id: component.id().to_owned(),
+                settings: component.settings(),
+            }),
             Component::Delta(component) => ComponentSettings::Delta(component.settings().clone()),
             Component::DetailedTimer(component) => {
                 ComponentSettings::DetailedTimer(Box::new(component.settings().clone()))
             }
             Component::Graph(component) => ComponentSettings::Graph(component.settings().clone()),
+            Component::Image(component) => ComponentSettings::Image(component.settings().clone()),
+            Component::Notes(component) => ComponentSettings::Notes(component.settings().clone()),
             Component::PossibleTimeSave(component) => {
                 ComponentSettings::PossibleTimeSave(component.settings().clone())
             }
             Component::PreviousSegment(component) => {
                 ComponentSettings::PreviousSegment(component.settings().clone())
             }
+            Component::RunStatistics(component) => {
+                ComponentSettings::RunStatistics(component.settings().clone())
+            }
+            Component::SegmentHistory(component) => {
+                ComponentSettings::SegmentHistory(component.settings().clone())
+            }
             Component::Separator(_) => ComponentSettings::Separator,
             Component::Splits(component) => ComponentSettings::Splits(component.settings().clone()),
             Component::SumOfBest(component) => {
@@ -133,13 +176,20 @@ impl Component {
     pub fn name(&self) -> Cow<'_, str> {
         match self {
             Component::BlankSpace(component) => component.name(),
+            Component::Clock(component) => component.name(),
+            Component::Counter(component) => component.name(),
             Component::CurrentComparison(component) => component.name(),
             Component::CurrentPace(component) => component.name(),
+            Component::Custom(component) => component.name(),
             Component::Delta(component) => component.name(),
             Component::DetailedTimer(component) => component.name(),
             Component::Graph(component) => component.name(),
+            Component::Image(component) => component.name(),
+            Component::Notes(component) => component.name(),
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
+            Component::RunStatistics(component) => component.name(),
+            Component::SegmentHistory(component) => component.name(),
             Component::Separator(component) => component.name(),
             Component::Splits(component) => component.name(),
             Component::SumOfBest(component) => component.name(),
@@ -172,7 +222,9 @@ impl Component {
     /// time.
     pub fn remount(&mut self) {
         match self {
+            Component::Custom(component) => component.remount(),
             Component::DetailedTimer(component) => component.remount(),
+            Component::Image(component) => component.remount(),
             Component::Splits(component) => component.remount(),
             Component::Title(component) => component.remount(),
             _ => {}
@@ -186,13 +238,20 @@ impl Component {
     pub fn settings_description(&self) -> SettingsDescription {
         match self {
             Component::BlankSpace(component) => component.settings_description(),
+            Component::Clock(component) => component.settings_description(),
+            Component::Counter(component) => component.settings_description(),
             Component::CurrentComparison(component) => component.settings_description(),
             Component::CurrentPace(component) => component.settings_description(),
+            Component::Custom(component) => component.settings_description(),
             Component::Delta(component) => component.settings_description(),
             Component::DetailedTimer(component) => component.settings_description(),
             Component::Graph(component) => component.settings_description(),
+            Component::Image(component) => component.settings_description(),
+            Component::Notes(component) => component.settings_description(),
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
+            Component::RunStatistics(component) => component.settings_description(),
+            Component::SegmentHistory(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
@@ -214,13 +273,20 @@ impl Component {
     pub fn set_value(&mut self, index: usize, value: Value) {
         match self {
             Component::BlankSpace(component) => component.set_value(index, value),
+            Component::Clock(component) => component.set_value(index, value),
+            Component::Counter(component) => component.set_value(index, value),
             Component::CurrentComparison(component) => component.set_value(index, value),
             Component::CurrentPace(component) => component.set_value(index, value),
+            Component::Custom(component) => component.set_value(index, value),
             Component::Delta(component) => component.set_value(index, value),
             Component::DetailedTimer(component) => component.set_value(index, value),
             Component::Graph(component) => component.set_value(index, value),
+            Component::Image(component) => component.set_value(index, value),
+            Component::Notes(component) => component.set_value(index, value),
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
+            Component::RunStatistics(component) => component.set_value(index, value),
+            Component::SegmentHistory(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
@@ -1,8 +1,8 @@
 use super::Component;
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
-    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
-    total_playtime,
+    blank_space, clock, counter, current_comparison, current_pace, custom, delta, detailed_timer,
+    graph, image, notes, possible_time_save, previous_segment, run_statistics, segment_history,
+    separator, splits, sum_of_best, text, timer, title, total_playtime,
 };
 
 /// The settings for one of the components available.
@@ -10,20 +10,34 @@ use crate::component::{
 pub enum ComponentSettings {
     /// The Settings for the Blank Space Component.
     BlankSpace(blank_space::Settings),
+    /// The Settings for the Clock Component.
+    Clock(clock::Settings),
+    /// The Settings for the Counter Component.
+    Counter(counter::Settings),
     /// The Settings for the Current Comparison Component.
     CurrentComparison(current_comparison::Settings),
     /// The Settings for the Current Pace Component.
     CurrentPace(current_pace::Settings),
+    /// The Settings for a Custom Component.
+    Custom(custom::Settings),
     /// The Settings for the Delta Component.
     Delta(delta::Settings),
     /// The Settings for the Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::Settings>),
     /// The Settings for the Graph Component.
     Graph(graph::Settings),
+    /// The Settings for the Image Component.
+    Image(image::Settings),
+    /// The Settings for the Notes Component.
+    Notes(notes::Settings),
     /// The Settings for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Settings),
     /// The Settings for the Previous Segment Component.
     PreviousSegment(previous_segment::Settings),
+    /// The Settings for the Run Statistics Component.
+    RunStatistics(run_statistics::Settings),
+    /// The Settings for the Segment History Component.
+    SegmentHistory(segment_history::Settings),
     /// The Settings for the Separator Component.
     Separator,
     /// The Settings for the Splits Component.
@@ -46,12 +60,21 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::Clock(settings) => {
+                Component::Clock(clock::Component::with_settings(settings))
+            }
+            ComponentSettings::Counter(settings) => {
+                Component::Counter(counter::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
             ComponentSettings::CurrentPace(settings) => {
                 Component::CurrentPace(current_pace::Component::with_settings(settings))
             }
+            ComponentSettings::Custom(settings) => {
+                Component::Custom(custom::from_settings(settings))
+            }
             ComponentSettings::Delta(settings) => {
                 Component::Delta(delta::Component::with_settings(settings))
             }
@@ -61,12 +84,24 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::Graph(settings) => {
                 Component::Graph(graph::Component::with_settings(settings))
             }
+            ComponentSettings::Image(settings) => {
+                Component::Image(image::Component::with_settings(settings))
+            }
+            ComponentSettings::Notes(settings) => {
+                Component::Notes(notes::Component::with_settings(settings))
+            }
             ComponentSettings::PossibleTimeSave(settings) => {
                 Component::PossibleTimeSave(possible_time_save::Component::with_settings(settings))
             }
             ComponentSettings::PreviousSegment(settings) => {
                 Component::PreviousSegment(previous_segment::Component::with_settings(settings))
             }
+            ComponentSettings::RunStatistics(settings) => {
+                Component::RunStatistics(run_statistics::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentHistory(settings) => {
+                Component::SegmentHistory(segment_history::Component::with_settings(settings))
+            }
             ComponentSettings::Separator => Component::Separator(separator::Component::new()),
             ComponentSettings::Splits(settings) => {
                 Component::Splits(splits::Component::with_settings(settings))
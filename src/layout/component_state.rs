@@ -1,7 +1,7 @@
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
-    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
-    total_playtime,
+    blank_space, clock, counter, current_comparison, current_pace, custom, delta, detailed_timer,
+    graph, image, notes, possible_time_save, previous_segment, run_statistics, segment_history,
+    separator, splits, sum_of_best, text, timer, title, total_playtime,
 };
 
 /// The state object for one of the components available.
@@ -9,20 +9,34 @@ use crate::component::{
 pub enum ComponentState {
     /// The state object for the Blank Space Component.
     BlankSpace(blank_space::State),
+    /// The state object for the Clock Component.
+    Clock(clock::State),
+    /// The state object for the Counter Component.
+    Counter(counter::State),
     /// The state object for the Current Comparison Component.
     CurrentComparison(current_comparison::State),
     /// The state object for the Current Pace Component.
     CurrentPace(current_pace::State),
+    /// The state object for a Custom Component.
+    Custom(custom::State),
     /// The state object for the Delta Component.
     Delta(delta::State),
     /// The state object for the Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::State>),
     /// The state object for the Graph Component.
     Graph(graph::State),
+    /// The state object for the Image Component.
+    Image(image::State),
+    /// The state object for the Notes Component.
+    Notes(notes::State),
     /// The state object for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::State),
     /// The state object for the Previous Segment Component.
     PreviousSegment(previous_segment::State),
+    /// The state object for the Run Statistics Component.
+    RunStatistics(run_statistics::State),
+    /// The state object for the Segment History Component.
+    SegmentHistory(segment_history::State),
     /// The state object for the Separator Component.
     Separator(separator::State),
     /// The state object for the Splits Component.
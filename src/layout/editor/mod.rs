@@ -172,7 +172,35 @@ impl Editor {
     /// the type of the setting's value. A panic can also occur if the index of
     /// the setting provided is out of bounds.
     pub fn set_component_settings_value(&mut self, index: usize, value: Value) {
-        self.layout.components[self.selected_component].set_value(index, value);
+        self.layout.components[self.selected_component]
+            .component
+            .set_value(index, value);
+    }
+
+    /// Sets a setting's value of the visibility conditions of the selected
+    /// component by its setting index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_visibility_settings_value(&mut self, index: usize, value: Value) {
+        self.layout.components[self.selected_component]
+            .visibility
+            .set_value(index, value);
+    }
+
+    /// Sets a setting's value of the background image of the selected component
+    /// by its setting index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_background_image_settings_value(&mut self, index: usize, value: Value) {
+        self.layout.components[self.selected_component].set_background_image_value(index, value);
     }
 
     /// Sets a setting's value of the general settings by its setting index to
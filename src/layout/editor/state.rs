@@ -16,6 +16,12 @@ pub struct State {
     /// A generic description of the settings available for the selected
     /// component and their current values.
     pub component_settings: SettingsDescription,
+    /// A generic description of the visibility conditions of the selected
+    /// component and their current values.
+    pub visibility_settings: SettingsDescription,
+    /// A generic description of the settings of the background image of the
+    /// selected component and their current values.
+    pub background_image_settings: SettingsDescription,
     /// A generic description of the general settings available for the layout
     /// and their current values.
     pub general_settings: SettingsDescription,
@@ -54,7 +60,7 @@ impl Editor {
             .layout
             .components
             .iter()
-            .map(|c| c.name().into_owned())
+            .map(|c| c.component.name().into_owned())
             .collect();
 
         let buttons = Buttons {
@@ -63,12 +69,15 @@ impl Editor {
             can_move_down: self.can_move_component_down(),
         };
 
+        let selected = &self.layout.components[self.selected_component];
+
         State {
             components,
             buttons,
             selected_component: self.selected_component as u32,
-            component_settings: self.layout.components[self.selected_component]
-                .settings_description(),
+            component_settings: selected.component.settings_description(),
+            visibility_settings: selected.visibility.settings_description(),
+            background_image_settings: selected.background_image_settings_description(),
             general_settings: self.layout.general_settings().settings_description(),
         }
     }
@@ -1,5 +1,6 @@
 use super::LayoutDirection;
-use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::settings::{Color, Field, Gradient, ImageStyle, SettingsDescription, Value};
+use crate::Image;
 
 /// The general settings of the layout that apply to all components.
 #[derive(Clone, Serialize, Deserialize)]
@@ -9,6 +10,11 @@ pub struct GeneralSettings {
     pub direction: LayoutDirection,
     /// The background to show behind the layout.
     pub background: Gradient,
+    /// The image to show on top of the background of the layout. If the image
+    /// is empty, no background image is shown.
+    pub background_image: Image,
+    /// Describes how the background image is drawn.
+    pub background_image_style: ImageStyle,
     /// The color to use for when the runner achieved a best segment.
     pub best_segment_color: Color,
     /// The color to use for when the runner is ahead of the comparison and is
@@ -42,6 +48,8 @@ impl Default for GeneralSettings {
         Self {
             direction: LayoutDirection::Vertical,
             background: Gradient::Plain(Color::hsla(0.0, 0.0, 0.06, 1.0)),
+            background_image: Image::default(),
+            background_image_style: ImageStyle::default(),
             best_segment_color: Color::hsla(50.0, 1.0, 0.5, 1.0),
             ahead_gaining_time_color: Color::hsla(136.0, 1.0, 0.4, 1.0),
             ahead_losing_time_color: Color::hsla(136.0, 0.55, 0.6, 1.0),
@@ -64,6 +72,22 @@ impl GeneralSettings {
         SettingsDescription::with_fields(vec![
             Field::new("Layout Direction".into(), self.direction.into()),
             Field::new("Background".into(), self.background.into()),
+            Field::new(
+                "Background Image".into(),
+                self.background_image.clone().into(),
+            ),
+            Field::new(
+                "Background Image Opacity".into(),
+                f64::from(self.background_image_style.opacity).into(),
+            ),
+            Field::new(
+                "Background Image Blur".into(),
+                f64::from(self.background_image_style.blur).into(),
+            ),
+            Field::new(
+                "Background Image Scaling".into(),
+                self.background_image_style.scaling.into(),
+            ),
             Field::new("Best Segment".into(), self.best_segment_color.into()),
             Field::new(
                 "Ahead (Gaining Time)".into(),
@@ -101,17 +125,21 @@ impl GeneralSettings {
         match index {
             0 => self.direction = value.into(),
             1 => self.background = value.into(),
-            2 => self.best_segment_color = value.into(),
-            3 => self.ahead_gaining_time_color = value.into(),
-            4 => self.ahead_losing_time_color = value.into(),
-            5 => self.behind_gaining_time_color = value.into(),
-            6 => self.behind_losing_time_color = value.into(),
-            7 => self.not_running_color = value.into(),
-            8 => self.personal_best_color = value.into(),
-            9 => self.paused_color = value.into(),
-            10 => self.thin_separators_color = value.into(),
-            11 => self.separators_color = value.into(),
-            12 => self.text_color = value.into(),
+            2 => self.background_image = value.into(),
+            3 => self.background_image_style.opacity = value.into_float().unwrap() as _,
+            4 => self.background_image_style.blur = value.into_float().unwrap() as _,
+            5 => self.background_image_style.scaling = value.into(),
+            6 => self.best_segment_color = value.into(),
+            7 => self.ahead_gaining_time_color = value.into(),
+            8 => self.ahead_losing_time_color = value.into(),
+            9 => self.behind_gaining_time_color = value.into(),
+            10 => self.behind_losing_time_color = value.into(),
+            11 => self.not_running_color = value.into(),
+            12 => self.personal_best_color = value.into(),
+            13 => self.paused_color = value.into(),
+            14 => self.thin_separators_color = value.into(),
+            15 => self.separators_color = value.into(),
+            16 => self.text_color = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -0,0 +1,143 @@
+use super::{
+    Component, GeneralSettings, LayoutComponentSettings, LayoutComponentState, Visibility,
+};
+use crate::settings::{Field, ImageStyle, SettingsDescription, Value};
+use crate::{CachedImageId, Image, Timer};
+
+/// A component within a layout along with the conditions under which the
+/// layout shows it and the background image the layout shows behind it.
+#[derive(Clone)]
+pub struct LayoutComponent {
+    /// The component itself.
+    pub component: Component,
+    /// The conditions under which the component is shown.
+    pub visibility: Visibility,
+    /// The image to show behind the component. If the image is empty, no
+    /// background image is shown.
+    pub background_image: Image,
+    /// Describes how the background image is drawn.
+    pub background_image_style: ImageStyle,
+    background_image_id: CachedImageId,
+    visible: bool,
+}
+
+impl LayoutComponent {
+    /// Creates a new layout component out of the component provided. It is
+    /// always shown and has no background image.
+    pub fn new<C: Into<Component>>(component: C) -> Self {
+        Self {
+            component: component.into(),
+            visibility: Visibility::default(),
+            background_image: Image::default(),
+            background_image_style: ImageStyle::default(),
+            background_image_id: CachedImageId::default(),
+            visible: false,
+        }
+    }
+
+    /// Accesses the settings of the component along with its visibility
+    /// conditions and its background image.
+    pub fn settings(&self) -> LayoutComponentSettings {
+        LayoutComponentSettings {
+            settings: self.component.settings(),
+            visibility: self.visibility.clone(),
+            background_image: self.background_image.clone(),
+            background_image_style: self.background_image_style,
+        }
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided. If the component's visibility conditions aren't met, `None`
+    /// is returned.
+    pub fn state(
+        &mut self,
+        timer: &Timer,
+        layout_settings: &GeneralSettings,
+    ) -> Option<LayoutComponentState> {
+        let state = if self.visibility.is_met(timer) {
+            // Renderers forget about the information that components only
+            // provide whenever it changes, such as images, once the components
+            // disappear. So the components get remounted to provide this
+            // information again when they reappear.
+            if !self.visible {
+                self.remount();
+            }
+            Some(self.component.state(timer, layout_settings))
+                .filter(|state| self.visibility.allows(state))
+        } else {
+            None
+        };
+        self.visible = state.is_some();
+
+        let image = &self.background_image;
+        Some(LayoutComponentState {
+            state: state?,
+            background_image_id: Some(image.id()).filter(|_| !image.is_empty()),
+            background_image_change: self
+                .background_image_id
+                .update_with(Some(image))
+                .map(str::to_owned),
+            background_image_style: self.background_image_style,
+        })
+    }
+
+    /// Remounts the component as if it was freshly initialized. Its background
+    /// image is provided again as well, whenever its state is queried the next
+    /// time.
+    pub fn remount(&mut self) {
+        self.component.remount();
+        self.background_image_id.reset();
+    }
+
+    /// Accesses a generic description of the settings of the background image
+    /// and their current values.
+    pub fn background_image_settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Background Image".into(),
+                self.background_image.clone().into(),
+            ),
+            Field::new(
+                "Background Image Opacity".into(),
+                f64::from(self.background_image_style.opacity).into(),
+            ),
+            Field::new(
+                "Background Image Blur".into(),
+                f64::from(self.background_image_style.blur).into(),
+            ),
+            Field::new(
+                "Background Image Scaling".into(),
+                self.background_image_style.scaling.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value of the background image by its index to the given
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_background_image_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.background_image = value.into(),
+            1 => self.background_image_style.opacity = value.into_float().unwrap() as _,
+            2 => self.background_image_style.blur = value.into_float().unwrap() as _,
+            3 => self.background_image_style.scaling = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+impl From<LayoutComponentSettings> for LayoutComponent {
+    fn from(settings: LayoutComponentSettings) -> Self {
+        Self {
+            visibility: settings.visibility,
+            background_image: settings.background_image,
+            background_image_style: settings.background_image_style,
+            ..Self::new(settings.settings)
+        }
+    }
+}
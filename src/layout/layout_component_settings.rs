@@ -0,0 +1,70 @@
+use super::{ComponentSettings, Visibility};
+use crate::settings::ImageStyle;
+use crate::Image;
+use serde::{Deserialize, Deserializer};
+
+/// Describes a component within a layout by its settings along with the
+/// conditions under which the layout shows it and the background image the
+/// layout shows behind it.
+#[derive(Clone, Serialize)]
+pub struct LayoutComponentSettings {
+    /// The settings of the component.
+    #[serde(flatten)]
+    pub settings: ComponentSettings,
+    /// The conditions under which the component is shown.
+    pub visibility: Visibility,
+    /// The image to show behind the component. If the image is empty, no
+    /// background image is shown.
+    pub background_image: Image,
+    /// Describes how the background image is drawn.
+    pub background_image_style: ImageStyle,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Repr {
+    WithLayoutSettings {
+        #[serde(flatten)]
+        settings: ComponentSettings,
+        #[serde(default)]
+        visibility: Visibility,
+        #[serde(default)]
+        background_image: Image,
+        #[serde(default)]
+        background_image_style: ImageStyle,
+    },
+    // Layouts that were saved before the components had any settings specific
+    // to the layout store the component settings on their own. Components
+    // without any settings, like the Separator, are stored as a bare string.
+    Bare(ComponentSettings),
+}
+
+impl<'de> Deserialize<'de> for LayoutComponentSettings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::WithLayoutSettings {
+                settings,
+                visibility,
+                background_image,
+                background_image_style,
+            } => LayoutComponentSettings {
+                settings,
+                visibility,
+                background_image,
+                background_image_style,
+            },
+            Repr::Bare(settings) => settings.into(),
+        })
+    }
+}
+
+impl From<ComponentSettings> for LayoutComponentSettings {
+    fn from(settings: ComponentSettings) -> Self {
+        Self {
+            settings,
+            visibility: Visibility::default(),
+            background_image: Image::default(),
+            background_image_style: ImageStyle::default(),
+        }
+    }
+}
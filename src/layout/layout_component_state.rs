@@ -0,0 +1,23 @@
+use super::ComponentState;
+use crate::settings::ImageStyle;
+
+/// The state object of a component within a layout along with the background
+/// image the layout shows behind it.
+#[derive(Serialize, Deserialize)]
+pub struct LayoutComponentState {
+    /// The state object of the component.
+    #[serde(flatten)]
+    pub state: ComponentState,
+    /// The unique ID of the background image. As the background image itself
+    /// is only provided whenever it changes, renderers can use the ID to look
+    /// up the image they cached. This is `None` if there is no background
+    /// image.
+    pub background_image_id: Option<usize>,
+    /// The background image to show behind the component. This value is only
+    /// specified whenever the background image changes. If you explicitly want
+    /// to query this value, remount the layout. The image is provided as a Data
+    /// URL. An empty string means that the background image was removed.
+    pub background_image_change: Option<String>,
+    /// Describes how the background image is drawn.
+    pub background_image_style: ImageStyle,
+}
@@ -1,4 +1,4 @@
-use super::{ComponentSettings, GeneralSettings};
+use super::{GeneralSettings, LayoutComponentSettings};
 use serde_json::{from_reader, to_writer, Result};
 use std::io::{Read, Write};
 
@@ -6,8 +6,9 @@ use std::io::{Read, Write};
 /// serialized and deserialized.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct LayoutSettings {
-    /// The settings for all the components.
-    pub components: Vec<ComponentSettings>,
+    /// The settings for all the components along with the conditions under
+    /// which they are shown.
+    pub components: Vec<LayoutComponentSettings>,
     /// The general settings of the layout that apply to all components.
     pub general: GeneralSettings,
 }
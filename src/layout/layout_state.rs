@@ -1,17 +1,26 @@
-use super::{ComponentState, LayoutDirection};
-use crate::settings::{Color, Gradient};
+use super::{LayoutComponentState, LayoutDirection};
+use crate::settings::{Color, Gradient, ImageStyle};
 use serde_json::{to_writer, Result};
 use std::io::Write;
 
 /// The state object describes the information to visualize for the layout.
 #[derive(Serialize, Deserialize)]
 pub struct LayoutState {
-    /// The state objects for all of the components in the layout.
-    pub components: Vec<ComponentState>,
+    /// The state objects for all of the components in the layout that are
+    /// shown, along with their background images.
+    pub components: Vec<LayoutComponentState>,
     /// The direction which the components are laid out in.
     pub direction: LayoutDirection,
     /// The background to show behind the layout.
     pub background: Gradient,
+    /// The background image to show on top of the background of the layout.
+    /// This value is only specified whenever the background image changes. If
+    /// you explicitly want to query this value, remount the layout. The image
+    /// is provided as a Data URL. An empty string means that the background
+    /// image was removed.
+    pub background_image_change: Option<String>,
+    /// Describes how the background image is drawn.
+    pub background_image_style: ImageStyle,
     /// The color of thin separators.
     pub thin_separators_color: Color,
     /// The color of normal separators.
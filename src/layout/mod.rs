@@ -7,30 +7,45 @@ mod component_settings;
 mod component_state;
 pub mod editor;
 mod general_settings;
+mod layout_component;
+mod layout_component_settings;
+mod layout_component_state;
 mod layout_direction;
 mod layout_settings;
 mod layout_state;
 pub mod parser;
+pub mod saver;
+mod visibility;
+
+#[cfg(test)]
+mod tests;
 
 pub use self::component::Component;
 pub use self::component_settings::ComponentSettings;
 pub use self::component_state::ComponentState;
 pub use self::editor::Editor;
 pub use self::general_settings::GeneralSettings;
+pub use self::layout_component::LayoutComponent;
+pub use self::layout_component_settings::LayoutComponentSettings;
+pub use self::layout_component_state::LayoutComponentState;
 pub use self::layout_direction::LayoutDirection;
 pub use self::layout_settings::LayoutSettings;
 pub use self::layout_state::LayoutState;
+pub use self::visibility::{PaceCondition, RunningCondition, Visibility};
 
 use crate::component::{previous_segment, splits, timer, title};
 use crate::timing::Timer;
+use crate::CachedImageId;
 
 /// A Layout allows you to combine multiple components together to visualize a
 /// variety of information the runner is interested in.
 #[derive(Clone, Default)]
 pub struct Layout {
-    /// All of the layout's components.
-    pub components: Vec<Component>,
+    /// All of the layout's components along with the conditions under which
+    /// they are shown and their background images.
+    pub components: Vec<LayoutComponent>,
     settings: GeneralSettings,
+    background_image_id: CachedImageId,
 }
 
 impl Layout {
@@ -46,12 +61,13 @@ impl Layout {
     pub fn default_layout() -> Self {
         Self {
             components: vec![
-                title::Component::new().into(),
-                splits::Component::new().into(),
-                timer::Component::new().into(),
-                previous_segment::Component::new().into(),
+                LayoutComponent::new(title::Component::new()),
+                LayoutComponent::new(splits::Component::new()),
+                LayoutComponent::new(timer::Component::new()),
+                LayoutComponent::new(previous_segment::Component::new()),
             ],
             settings: GeneralSettings::default(),
+            background_image_id: CachedImageId::default(),
         }
     }
 
@@ -64,6 +80,7 @@ impl Layout {
                 .map(Into::into)
                 .collect(),
             settings: layout_settings.general,
+            background_image_id: CachedImageId::default(),
         }
     }
 
@@ -79,22 +96,31 @@ impl Layout {
         &mut self.settings
     }
 
-    /// Adds a new component to the end of the layout.
+    /// Adds a new component to the end of the layout. It is always shown.
     pub fn push<C: Into<Component>>(&mut self, component: C) {
-        self.components.push(component.into());
+        self.components.push(LayoutComponent::new(component));
     }
 
     /// Calculates the layout's state based on the timer provided. You can use
-    /// this to visualize all of the components of a layout.
+    /// this to visualize all of the components of a layout. Only the
+    /// components whose visibility conditions are met are part of the state.
     pub fn state(&mut self, timer: &Timer) -> LayoutState {
         let settings = &self.settings;
+
+        let components = self
+            .components
+            .iter_mut()
+            .filter_map(|c| c.state(timer, settings))
+            .collect();
+
         LayoutState {
-            components: self
-                .components
-                .iter_mut()
-                .map(|c| c.state(timer, settings))
-                .collect(),
+            components,
             background: settings.background,
+            background_image_change: self
+                .background_image_id
+                .update_with(Some(&settings.background_image))
+                .map(str::to_owned),
+            background_image_style: settings.background_image_style,
             thin_separators_color: settings.thin_separators_color,
             separators_color: settings.separators_color,
             text_color: settings.text_color,
@@ -105,22 +131,26 @@ impl Layout {
     /// Accesses the settings of the layout.
     pub fn settings(&self) -> LayoutSettings {
         LayoutSettings {
-            components: self.components.iter().map(Component::settings).collect(),
+            components: self
+                .components
+                .iter()
+                .map(LayoutComponent::settings)
+                .collect(),
             general: self.settings.clone(),
         }
     }
 
     /// Scrolls up all the components in the layout that can be scrolled up.
     pub fn scroll_up(&mut self) {
-        for component in &mut self.components {
-            component.scroll_up();
+        for entry in &mut self.components {
+            entry.component.scroll_up();
         }
     }
 
     /// Scrolls down all the components in the layout that can be scrolled down.
     pub fn scroll_down(&mut self) {
-        for component in &mut self.components {
-            component.scroll_down();
+        for entry in &mut self.components {
+            entry.component.scroll_down();
         }
     }
 
@@ -129,8 +159,23 @@ impl Layout {
     /// their state is first queried. Remounting returns this information again,
     /// whenever the layout's state is queried the next time.
     pub fn remount(&mut self) {
+        self.background_image_id.reset();
         for component in &mut self.components {
             component.remount();
         }
     }
+
+    /// Adds the counters of all the Counter Components in the layout to the
+    /// timer provided, so that the Hotkey System can modify them. This needs to
+    /// be called again whenever the layout or the settings of its Counter
+    /// Components change. Afterwards the Hotkey System needs to update the
+    /// hotkeys of the individual counters with
+    /// [`update_counter_hotkeys`](crate::HotkeySystem::update_counter_hotkeys).
+    pub fn register_counters(&self, timer: &mut Timer) {
+        for entry in &self.components {
+            if let Component::Counter(component) = &entry.component {
+                timer.add_counter(component.counter());
+            }
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! Provides the parser for layout files of the original LiveSplit.
 
-use super::{Component, Layout, LayoutDirection};
+use super::{Component, Layout, LayoutComponent, LayoutComponentSettings, LayoutDirection};
 use crate::{
     component::separator,
     settings::{Alignment, Color, Gradient, ListGradient},
@@ -9,9 +9,10 @@ use crate::{
         TimingMethod,
     },
     xml_util::{
-        end_tag, parse_base, parse_children, text, text_as_escaped_bytes_err, text_err,
-        text_parsed, Error as XmlError, Tag,
+        end_tag, image, parse_base, parse_children, reencode_children, text,
+        text_as_escaped_bytes_err, text_err, text_parsed, Error as XmlError, Tag,
     },
+    Image,
 };
 use quick_xml::Reader;
 use std::io::BufRead;
@@ -51,6 +52,10 @@ quick_error! {
         Int(err: std::num::ParseIntError) {
             from()
         }
+        /// Failed to parse a floating point number.
+        Float(err: std::num::ParseFloatError) {
+            from()
+        }
         /// Failed to parse a boolean.
         Bool {}
         /// Failed to parse the layout direction.
@@ -67,6 +72,11 @@ quick_error! {
         Alignment {}
         /// Failed to parse a column type.
         ColumnType {}
+        /// Failed to parse the settings of a component that the original
+        /// LiveSplit doesn't provide.
+        Json(err: serde_json::Error) {
+            from()
+        }
         /// Parsed an empty layout, which is considered an invalid layout.
         Empty {}
     }
@@ -75,6 +85,17 @@ quick_error! {
 /// The Result type for parsing layout files of the original LiveSplit.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The path of components that the original LiveSplit doesn't provide. Their
+/// settings are stored as JSON, so that they survive saving and loading the
+/// layout with livesplit-core.
+pub(super) const EMBEDDED_COMPONENT_PATH: &str = "livesplit-core";
+
+/// The element within the settings of the components that the original
+/// LiveSplit provides, which stores all of the settings of the component as
+/// JSON. This includes the settings that the original LiveSplit doesn't know
+/// about, which it ignores.
+pub(super) const LAYOUT_COMPONENT_SETTINGS_TAG: &[u8] = b"LiveSplitCoreSettings";
+
 enum GradientKind {
     Transparent,
     Plain,
@@ -216,32 +237,36 @@ where
     F: FnOnce(Color),
 {
     text_err(reader, buf, |text| {
-        let n = u32::from_str_radix(&text, 16)?;
-        let b = (n & 0xFF) as u8;
-        let g = ((n >> 8) & 0xFF) as u8;
-        let r = ((n >> 16) & 0xFF) as u8;
-        let a = ((n >> 24) & 0xFF) as u8;
-        let mut color = Color::from([r, g, b, a]);
-        let (r, g, b, a) = color.rgba.into_components();
-
-        // Adjust alpha based on the lightness of the color. The formula is
-        // based on two sRGB curves measured for white on top of a black
-        // background and for black on top of a white background. We interpolate
-        // between the two curves based on the lightness of the color. The
-        // problem is that we only have the foreground color, so based on the
-        // actual background color, this may be wrong. Therefore this is only a
-        // heuristic. We often have white on dark grey, instead of white on
-        // black. Because of that, we use 1.75 as the exponent denominator for
-        // the white on black case instead of the usual 2.2 for sRGB.
-        let lightness = (r + g + b) / 3.0;
-        color.rgba.alpha =
-            (1.0 - lightness) * (1.0 - (1.0 - a).powf(1.0 / 2.2)) + lightness * a.powf(1.0 / 1.75);
-
-        f(color);
+        f(decode_color(u32::from_str_radix(&text, 16)?));
         Ok(())
     })
 }
 
+/// Decodes a color encoded as ARGB, the way the original LiveSplit stores them.
+pub(super) fn decode_color(n: u32) -> Color {
+    let b = (n & 0xFF) as u8;
+    let g = ((n >> 8) & 0xFF) as u8;
+    let r = ((n >> 16) & 0xFF) as u8;
+    let a = ((n >> 24) & 0xFF) as u8;
+    let mut color = Color::from([r, g, b, a]);
+    let (r, g, b, a) = color.rgba.into_components();
+
+    // Adjust alpha based on the lightness of the color. The formula is
+    // based on two sRGB curves measured for white on top of a black
+    // background and for black on top of a white background. We interpolate
+    // between the two curves based on the lightness of the color. The
+    // problem is that we only have the foreground color, so based on the
+    // actual background color, this may be wrong. Therefore this is only a
+    // heuristic. We often have white on dark grey, instead of white on
+    // black. Because of that, we use 1.75 as the exponent denominator for
+    // the white on black case instead of the usual 2.2 for sRGB.
+    let lightness = (r + g + b) / 3.0;
+    color.rgba.alpha =
+        (1.0 - lightness) * (1.0 - (1.0 - a).powf(1.0 / 2.2)) + lightness * a.powf(1.0 / 1.75);
+
+    color
+}
+
 fn parse_bool<R, F>(reader: &mut Reader<R>, buf: &mut Vec<u8>, f: F) -> Result<()>
 where
     R: BufRead,
@@ -300,6 +325,7 @@ where
             b"Tenths" => Accuracy::Tenths,
             b"Seconds" => Accuracy::Seconds,
             b"Hundredths" => Accuracy::Hundredths,
+            b"Milliseconds" => Accuracy::Milliseconds,
             _ => return Err(Error::Accuracy),
         });
         Ok(())
@@ -321,6 +347,7 @@ where
             _ => return Err(Error::DigitsFormat),
         };
         let accuracy = match splits.next().unwrap_or(b"") {
+            b"234" => Accuracy::Milliseconds,
             b"23" => Accuracy::Hundredths,
             b"2" => Accuracy::Tenths,
             b"" => Accuracy::Seconds,
@@ -334,14 +361,20 @@ where
 fn component<R, F>(reader: &mut Reader<R>, buf: &mut Vec<u8>, f: F) -> Result<()>
 where
     R: BufRead,
-    F: FnOnce(Component),
+    F: FnOnce(LayoutComponent),
 {
     let mut component = None;
+    let mut layout_component_settings = None;
+    let mut is_embedded = false;
 
     parse_children(reader, buf, |reader, tag| {
         if tag.name() == b"Path" {
             text_err(reader, tag.into_buf(), |text| {
                 component = Some(match &*text {
+                    EMBEDDED_COMPONENT_PATH => {
+                        is_embedded = true;
+                        return Ok(());
+                    }
                     "LiveSplit.BlankSpace.dll" => blank_space::Component::new().into(),
                     "LiveSplit.CurrentComparison.dll" => {
                         current_comparison::Component::new().into()
@@ -355,8 +388,11 @@ where
                     "LiveSplit.PossibleTimeSave.dll" => possible_time_save::Component::new().into(),
                     "LiveSplit.PreviousSegment.dll" => previous_segment::Component::new().into(),
                     "" => separator::Component::new().into(),
-                    "LiveSplit.Splits.dll" | "LiveSplit.Subsplits.dll" => {
-                        splits::Component::new().into()
+                    "LiveSplit.Splits.dll" => splits::Component::new().into(),
+                    "LiveSplit.Subsplits.dll" => {
+                        let mut component = splits::Component::new();
+                        component.settings_mut().group_subsplits = true;
+                        component.into()
                     }
                     "LiveSplit.SumOfBest.dll" => sum_of_best::Component::new().into(),
                     "LiveSplit.Text.dll" => text::Component::new().into(),
@@ -367,37 +403,21 @@ where
                 });
                 Ok(())
             })
+        } else if tag.name() == b"Settings" && is_embedded {
+            text_err(reader, tag.into_buf(), |text| {
+                layout_component_settings = Some(serde_json::from_str(&text)?);
+                Ok(())
+            })
         } else if tag.name() == b"Settings" {
             // Assumption: Settings always has to come after the Path.
             // Otherwise we need to cache the settings and load them later.
             if let Some(component) = &mut component {
-                match component {
-                    Component::BlankSpace(c) => blank_space::settings(reader, tag.into_buf(), c),
-                    Component::CurrentComparison(c) => {
-                        current_comparison::settings(reader, tag.into_buf(), c)
-                    }
-                    Component::CurrentPace(c) => current_pace::settings(reader, tag.into_buf(), c),
-                    Component::Delta(c) => delta::settings(reader, tag.into_buf(), c),
-                    Component::DetailedTimer(c) => {
-                        detailed_timer::settings(reader, tag.into_buf(), c)
-                    }
-                    Component::Graph(c) => graph::settings(reader, tag.into_buf(), c),
-                    Component::PossibleTimeSave(c) => {
-                        possible_time_save::settings(reader, tag.into_buf(), c)
-                    }
-                    Component::PreviousSegment(c) => {
-                        previous_segment::settings(reader, tag.into_buf(), c)
-                    }
-                    Component::Separator(_) => end_tag(reader, tag.into_buf()),
-                    Component::Splits(c) => splits::settings(reader, tag.into_buf(), c),
-                    Component::SumOfBest(c) => sum_of_best::settings(reader, tag.into_buf(), c),
-                    Component::Text(c) => text::settings(reader, tag.into_buf(), c),
-                    Component::Timer(c) => timer::settings(reader, tag.into_buf(), c),
-                    Component::Title(c) => title::settings(reader, tag.into_buf(), c),
-                    Component::TotalPlaytime(c) => {
-                        total_playtime::settings(reader, tag.into_buf(), c)
-                    }
-                }
+                settings(
+                    reader,
+                    tag.into_buf(),
+                    component,
+                    &mut layout_component_settings,
+                )
             } else {
                 end_tag(reader, tag.into_buf())
             }
@@ -406,13 +426,87 @@ where
         }
     })?;
 
-    if let Some(component) = component {
-        f(component);
+    if let Some(settings) = layout_component_settings {
+        f(settings.into());
+    } else if let Some(component) = component {
+        f(LayoutComponent::new(component));
     }
 
     Ok(())
 }
 
+fn settings<R: BufRead>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    component: &mut Component,
+    layout_component_settings: &mut Option<LayoutComponentSettings>,
+) -> Result<()> {
+    // The parsers of the individual components skip the element that stores
+    // all of the settings as JSON, so the settings are reencoded in order to
+    // look for that element first.
+    let mut xml = b"<Settings>".to_vec();
+    reencode_children(reader, buf, &mut xml)?;
+    xml.extend_from_slice(b"</Settings>");
+
+    let settings_reader = || {
+        let mut reader = Reader::from_reader(&xml[..]);
+        reader.expand_empty_elements(true);
+        reader.trim_text(true);
+        reader
+    };
+
+    parse_base(&mut settings_reader(), buf, b"Settings", |reader, tag| {
+        parse_children::<_, _, Error>(reader, tag.into_buf(), |reader, tag| {
+            if tag.name() == LAYOUT_COMPONENT_SETTINGS_TAG {
+                text_err(reader, tag.into_buf(), |text| {
+                    *layout_component_settings = Some(serde_json::from_str(&text)?);
+                    Ok(())
+                })
+            } else {
+                end_tag(reader, tag.into_buf())
+            }
+        })
+    })?;
+
+    if layout_component_settings.is_some() {
+        return Ok(());
+    }
+
+    parse_base(
+        &mut settings_reader(),
+        buf,
+        b"Settings",
+        |reader, tag| match component {
+            Component::BlankSpace(c) => blank_space::settings(reader, tag.into_buf(), c),
+            Component::Clock(_) => end_tag(reader, tag.into_buf()),
+            Component::Counter(_) => end_tag(reader, tag.into_buf()),
+            Component::CurrentComparison(c) => {
+                current_comparison::settings(reader, tag.into_buf(), c)
+            }
+            Component::CurrentPace(c) => current_pace::settings(reader, tag.into_buf(), c),
+            Component::Custom(_) => end_tag(reader, tag.into_buf()),
+            Component::Delta(c) => delta::settings(reader, tag.into_buf(), c),
+            Component::DetailedTimer(c) => detailed_timer::settings(reader, tag.into_buf(), c),
+            Component::Graph(c) => graph::settings(reader, tag.into_buf(), c),
+            Component::Image(_) => end_tag(reader, tag.into_buf()),
+            Component::Notes(_) => end_tag(reader, tag.into_buf()),
+            Component::PossibleTimeSave(c) => {
+                possible_time_save::settings(reader, tag.into_buf(), c)
+            }
+            Component::PreviousSegment(c) => previous_segment::settings(reader, tag.into_buf(), c),
+            Component::RunStatistics(_) => end_tag(reader, tag.into_buf()),
+            Component::SegmentHistory(_) => end_tag(reader, tag.into_buf()),
+            Component::Separator(_) => end_tag(reader, tag.into_buf()),
+            Component::Splits(c) => splits::settings(reader, tag.into_buf(), c),
+            Component::SumOfBest(c) => sum_of_best::settings(reader, tag.into_buf(), c),
+            Component::Text(c) => text::settings(reader, tag.into_buf(), c),
+            Component::Timer(c) => timer::settings(reader, tag.into_buf(), c),
+            Component::Title(c) => title::settings(reader, tag.into_buf(), c),
+            Component::TotalPlaytime(c) => total_playtime::settings(reader, tag.into_buf(), c),
+        },
+    )
+}
+
 fn parse_general_settings<R: BufRead>(
     layout: &mut Layout,
     reader: &mut Reader<R>,
@@ -420,6 +514,8 @@ fn parse_general_settings<R: BufRead>(
 ) -> Result<()> {
     let settings = layout.general_settings_mut();
     let mut background_builder = GradientBuilder::new();
+    let mut background_image = None;
+    let mut is_image_background = false;
 
     parse_children(reader, buf, |reader, tag| {
         if tag.name() == b"TextColor" {
@@ -474,6 +570,19 @@ fn parse_general_settings<R: BufRead>(
             color(reader, tag.into_buf(), |color| {
                 settings.paused_color = color;
             })
+        } else if tag.name() == b"BackgroundImage" {
+            let mut image_buf = Vec::new();
+            image(reader, tag.into_buf(), &mut image_buf, |data| {
+                background_image = Some(Image::with_max_size(data, Image::MAX_BACKGROUND_SIZE));
+            })
+        } else if tag.name() == b"ImageOpacity" {
+            text_parsed(reader, tag.into_buf(), |opacity| {
+                settings.background_image_style.opacity = opacity;
+            })
+        } else if tag.name() == b"ImageBlur" {
+            text_parsed(reader, tag.into_buf(), |blur| {
+                settings.background_image_style.blur = blur;
+            })
         } else if tag.name() == b"BackgroundType" {
             text_err(reader, tag.into_buf(), |text| {
                 background_builder.kind = match &*text {
@@ -481,6 +590,9 @@ fn parse_general_settings<R: BufRead>(
                     "VerticalGradient" => GradientKind::Vertical,
                     "HorizontalGradient" => GradientKind::Horizontal,
                     "Image" => {
+                        // The image is drawn on top of the background, so
+                        // black shows through wherever it is transparent.
+                        is_image_background = true;
                         background_builder.first = Color::black();
                         background_builder.second = Color::black();
                         GradientKind::Plain
@@ -495,6 +607,11 @@ fn parse_general_settings<R: BufRead>(
     })?;
 
     settings.background = background_builder.build();
+    if is_image_background {
+        if let Some(background_image) = background_image {
+            settings.background_image = background_image;
+        }
+    }
 
     Ok(())
 }
@@ -527,7 +644,7 @@ pub fn parse<R: BufRead>(source: R) -> Result<Layout> {
             } else if tag.name() == b"Components" {
                 parse_children(reader, tag.into_buf(), |reader, tag| {
                     component(reader, tag.into_buf(), |c| {
-                        layout.push(c);
+                        layout.components.push(c);
                     })
                 })
             } else {
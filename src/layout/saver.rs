@@ -0,0 +1,928 @@
+//! Provides the saver for saving layouts as layout files of the original
+//! LiveSplit (*.lsl).
+//!
+//! The original LiveSplit only knows about some of the settings of
+//! livesplit-core's components, so all of the settings of each component,
+//! including its visibility conditions and its background image, are
+//! additionally stored as JSON in an element of the component's settings that
+//! the original LiveSplit ignores. Components that the original LiveSplit
+//! doesn't provide at all are stored with their settings encoded as JSON only.
+//! The layout parser restores all of these settings when loading the layout
+//! again. If the layout gets saved by the original LiveSplit, only the
+//! settings that it knows about are kept.
+//!
+//! # Examples
+//!
+//! Using the saver to save a layout as a layout file of the original LiveSplit.
+//!
+//! ```no_run
+//! use livesplit_core::layout::{saver, Layout};
+//! use std::fs::File;
+//! use std::io::BufWriter;
+//!
+//! // Create a layout that we can save.
+//! let layout = Layout::default_layout();
+//!
+//! // Create the layout file.
+//! let file = File::create("path/to/layout_file.lsl");
+//! let writer = BufWriter::new(file.expect("Failed creating the file"));
+//!
+//! // Save the layout as a layout file of the original LiveSplit.
+//! saver::save_layout(&layout, writer).expect("Couldn't save the layout file");
+//! ```
+
+use super::parser::{decode_color, EMBEDDED_COMPONENT_PATH, LAYOUT_COMPONENT_SETTINGS_TAG};
+use super::{
+    ComponentSettings, GeneralSettings, Layout, LayoutComponentSettings, LayoutDirection,
+    LayoutSettings,
+};
+use crate::component::{
+    blank_space, current_comparison, current_pace, delta, detailed_timer, graph,
+    possible_time_save, previous_segment, splits, sum_of_best, text, timer, title, total_playtime,
+};
+use crate::settings::{Alignment, Color, Gradient, ListGradient};
+use crate::timing::formatter::{Accuracy, DigitsFormat};
+use crate::timing::TimingMethod;
+use crate::xml_writer::{bool, image, new_tag, scoped_iter, text, write_display, write_end};
+use crate::xml_writer::{write_start, LSS_IMAGE_HEADER};
+use ordered_float::OrderedFloat;
+use quick_xml::events::{BytesDecl, BytesStart, Event};
+use quick_xml::{Error as XmlError, Result as XmlResult, Writer};
+use std::borrow::Cow;
+use std::io::Write;
+use std::result::Result as StdResult;
+
+quick_error! {
+    #[derive(Debug)]
+    /// The Error type for layouts that couldn't be saved as layout files of the
+    /// original LiveSplit.
+    pub enum Error {
+        /// Failed writing as XML.
+        Xml(err: XmlError) {
+            from()
+        }
+        /// Failed encoding the settings of a component as JSON.
+        Json(err: serde_json::Error) {
+            from()
+        }
+    }
+}
+
+/// The Result type for saving layout files of the original LiveSplit.
+pub type Result<T> = StdResult<T, Error>;
+
+type GradientTags = [&'static [u8]; 3];
+
+const BACKGROUND: GradientTags = [
+    b"BackgroundColor",
+    b"BackgroundColor2",
+    b"BackgroundGradient",
+];
+
+fn encode_color(color: Color) -> u32 {
+    let (r, g, b, a) = color.rgba.into_components();
+    let byte = |v: f32| u32::from((v.clamp(0.0, 1.0) * 255.0).round() as u8);
+    let rgb = byte(r) << 16 | byte(g) << 8 | byte(b);
+
+    // The parser adjusts the alpha based on the lightness of the color, so we
+    // choose the alpha that decodes to the closest alpha.
+    let alpha = (0..=255)
+        .min_by_key(|&alpha| OrderedFloat((decode_color(alpha << 24 | rgb).rgba.alpha - a).abs()))
+        .unwrap();
+
+    alpha << 24 | rgb
+}
+
+fn color<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    color: Color,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    write_display(
+        writer,
+        new_tag(tag),
+        format_args!("{:08X}", encode_color(color)),
+        buf,
+    )
+}
+
+fn optional_color<W: Write>(
+    writer: &mut Writer<W>,
+    [color_tag, override_tag]: [&[u8]; 2],
+    optional: Option<Color>,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    color(
+        writer,
+        color_tag,
+        optional.unwrap_or_else(Color::white),
+        buf,
+    )?;
+    flag(writer, override_tag, optional.is_some())
+}
+
+fn flag<W: Write>(writer: &mut Writer<W>, tag: &[u8], value: bool) -> XmlResult<()> {
+    text(writer, new_tag(tag), bool(value))
+}
+
+fn number<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    value: impl std::fmt::Display,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    write_display(writer, new_tag(tag), value, buf)
+}
+
+fn gradient<W: Write>(
+    writer: &mut Writer<W>,
+    [color1, color2, kind_tag]: GradientTags,
+    gradient: Gradient,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    let (kind, first, second): (&[u8], _, _) = match gradient {
+        Gradient::Transparent => (b"Plain", Color::transparent(), Color::transparent()),
+        Gradient::Plain(plain) => (b"Plain", plain, plain),
+        Gradient::Vertical(top, bottom) => (b"Vertical", top, bottom),
+        Gradient::Horizontal(left, right) => (b"Horizontal", left, right),
+    };
+    color(writer, color1, first, buf)?;
+    color(writer, color2, second, buf)?;
+    text(writer, new_tag(kind_tag), kind)
+}
+
+fn list_gradient<W: Write>(
+    writer: &mut Writer<W>,
+    tags: GradientTags,
+    list_gradient: ListGradient,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    match list_gradient {
+        ListGradient::Same(same) => gradient(writer, tags, same, buf),
+        ListGradient::Alternating(even, odd) => {
+            color(writer, tags[0], even, buf)?;
+            color(writer, tags[1], odd, buf)?;
+            text(writer, new_tag(tags[2]), b"Alternating")
+        }
+    }
+}
+
+fn comparison_override<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    comparison: &Option<String>,
+) -> XmlResult<()> {
+    let comparison = comparison
+        .as_ref()
+        .map_or("Current Comparison", String::as_str);
+    text(writer, new_tag(tag), comparison)
+}
+
+fn timing_method_override<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    timing_method: Option<TimingMethod>,
+) -> XmlResult<()> {
+    let timing_method = match timing_method {
+        None => "Current Timing Method",
+        Some(TimingMethod::RealTime) => "Real Time",
+        Some(TimingMethod::GameTime) => "Game Time",
+    };
+    text(writer, new_tag(tag), timing_method)
+}
+
+fn accuracy<W: Write>(writer: &mut Writer<W>, tag: &[u8], accuracy: Accuracy) -> XmlResult<()> {
+    let accuracy = match accuracy {
+        Accuracy::Seconds => "Seconds",
+        Accuracy::Tenths => "Tenths",
+        Accuracy::Hundredths => "Hundredths",
+        Accuracy::Milliseconds => "Milliseconds",
+    };
+    text(writer, new_tag(tag), accuracy)
+}
+
+fn timer_format<W: Write>(
+    writer: &mut Writer<W>,
+    tag: &[u8],
+    digits_format: DigitsFormat,
+    accuracy: Accuracy,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    // The original LiveSplit doesn't know about all of the digits formats, so
+    // we choose the closest one it knows about.
+    let digits_format = match digits_format {
+        DigitsFormat::SingleDigitSeconds | DigitsFormat::DoubleDigitSeconds => "1",
+        DigitsFormat::SingleDigitMinutes | DigitsFormat::DoubleDigitMinutes => "00:01",
+        DigitsFormat::SingleDigitHours => "0:00:01",
+        DigitsFormat::DoubleDigitHours => "00:00:01",
+    };
+    let accuracy = match accuracy {
+        Accuracy::Seconds => "",
+        Accuracy::Tenths => ".2",
+        Accuracy::Hundredths => ".23",
+        Accuracy::Milliseconds => ".234",
+    };
+    write_display(
+        writer,
+        new_tag(tag),
+        format_args!("{}{}", digits_format, accuracy),
+        buf,
+    )
+}
+
+fn version<W: Write>(writer: &mut Writer<W>, version: &str) -> XmlResult<()> {
+    text(writer, new_tag(b"Version"), version)
+}
+
+fn blank_space<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &blank_space::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.7")?;
+    number(writer, b"SpaceHeight", settings.size, buf)?;
+    gradient(writer, BACKGROUND, settings.background, buf)
+}
+
+fn current_comparison<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &current_comparison::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.4")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimeColor", b"OverrideTimeColor"],
+        settings.value_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)
+}
+
+fn current_pace<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &current_pace::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.4")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimeColor", b"OverrideTimeColor"],
+        settings.value_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    comparison_override(writer, b"Comparison", &settings.comparison_override)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    accuracy(writer, b"Accuracy", settings.accuracy)
+}
+
+fn delta<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &delta::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.4")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    comparison_override(writer, b"Comparison", &settings.comparison_override)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    accuracy(writer, b"Accuracy", settings.accuracy)?;
+    flag(writer, b"DropDecimals", settings.drop_decimals)
+}
+
+fn detailed_timer<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &detailed_timer::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    let total_height = settings.timer.height + settings.segment_timer.height;
+    // The segment timer's height is stored as a percentage of the total
+    // height. The parser rounds it down, so we look for the percentage that
+    // results in the same height again.
+    let ratio = (0..=100u32)
+        .find(|&ratio| {
+            (total_height as f32 * (ratio as f32 / 100.0)) as u32 == settings.segment_timer.height
+        })
+        .unwrap_or_else(|| {
+            (100.0 * settings.segment_timer.height as f32 / total_height.max(1) as f32).round()
+                as u32
+        });
+
+    version(writer, "1.5")?;
+    number(writer, b"Height", total_height, buf)?;
+    number(writer, b"SegmentTimerSizeRatio", ratio, buf)?;
+    flag(writer, b"TimerShowGradient", settings.timer.show_gradient)?;
+    flag(
+        writer,
+        b"OverrideTimerColors",
+        settings.timer.color_override.is_some(),
+    )?;
+    flag(
+        writer,
+        b"SegmentTimerShowGradient",
+        settings.segment_timer.show_gradient,
+    )?;
+    timer_format(
+        writer,
+        b"TimerFormat",
+        settings.timer.digits_format,
+        settings.timer.accuracy,
+        buf,
+    )?;
+    timer_format(
+        writer,
+        b"SegmentTimerFormat",
+        settings.segment_timer.digits_format,
+        settings.segment_timer.accuracy,
+        buf,
+    )?;
+    color(
+        writer,
+        b"TimerColor",
+        settings.timer.color_override.unwrap_or_else(Color::white),
+        buf,
+    )?;
+    flag(writer, b"DisplayIcon", settings.display_icon)?;
+    flag(writer, b"ShowSplitName", settings.show_segment_name)?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    comparison_override(writer, b"Comparison", &settings.comparison1)?;
+    comparison_override(writer, b"Comparison2", &settings.comparison2)?;
+    flag(writer, b"HideComparison", settings.hide_second_comparison)?;
+    timing_method_override(writer, b"TimingMethod", settings.timer.timing_method)
+}
+
+fn graph<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &graph::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.5")?;
+    number(writer, b"Height", settings.height, buf)?;
+    color(
+        writer,
+        b"BehindGraphColor",
+        settings.behind_background_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"AheadGraphColor",
+        settings.ahead_background_color,
+        buf,
+    )?;
+    color(writer, b"GridlinesColor", settings.grid_lines_color, buf)?;
+    color(
+        writer,
+        b"PartialFillColorAhead",
+        settings.partial_fill_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"CompleteFillColorAhead",
+        settings.complete_fill_color,
+        buf,
+    )?;
+    color(writer, b"GraphColor", settings.graph_lines_color, buf)?;
+    flag(writer, b"LiveGraph", settings.live_graph)?;
+    flag(writer, b"FlipGraph", settings.flip_graph)?;
+    comparison_override(writer, b"Comparison", &settings.comparison_override)?;
+    flag(writer, b"ShowBestSegments", settings.show_best_segments)
+}
+
+fn possible_time_save<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &possible_time_save::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.5")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimeColor", b"OverrideTimeColor"],
+        settings.value_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    comparison_override(writer, b"Comparison", &settings.comparison_override)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    accuracy(writer, b"Accuracy", settings.accuracy)?;
+    flag(writer, b"TotalTimeSave", settings.total_possible_time_save)
+}
+
+fn previous_segment<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &previous_segment::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.6")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    accuracy(writer, b"DeltaAccuracy", settings.accuracy)?;
+    flag(writer, b"DropDecimals", settings.drop_decimals)?;
+    comparison_override(writer, b"Comparison", &settings.comparison_override)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    flag(
+        writer,
+        b"ShowPossibleTimeSave",
+        settings.show_possible_time_save,
+    )
+}
+
+fn column_type(column: &splits::ColumnSettings) -> &'static str {
+    use self::splits::{ColumnStartWith, ColumnUpdateWith};
+
+    // The original LiveSplit only knows about a few combinations of how a
+    // column starts out and gets updated, so we choose the closest one.
+    match column.update_with {
+        ColumnUpdateWith::Delta => "Delta",
+        ColumnUpdateWith::SplitTime => "SplitTime",
+        ColumnUpdateWith::DeltaWithFallback => "DeltaorSplitTime",
+        ColumnUpdateWith::SegmentDelta => "SegmentDelta",
+        ColumnUpdateWith::SegmentTime => "SegmentTime",
+        ColumnUpdateWith::SegmentDeltaWithFallback => "SegmentDeltaorSegmentTime",
+        ColumnUpdateWith::DontUpdate => match column.start_with {
+            ColumnStartWith::ComparisonSegmentTime => "SegmentTime",
+            _ => "SplitTime",
+        },
+    }
+}
+
+fn splits<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &splits::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    use self::splits::SubsplitVisibility;
+
+    version(writer, "1.6")?;
+    gradient(
+        writer,
+        [
+            b"CurrentSplitTopColor",
+            b"CurrentSplitBottomColor",
+            b"CurrentSplitGradient",
+        ],
+        settings.current_split_gradient,
+        buf,
+    )?;
+    number(
+        writer,
+        b"VisualSplitCount",
+        settings.visual_split_count,
+        buf,
+    )?;
+    number(
+        writer,
+        b"SplitPreviewCount",
+        settings.split_preview_count,
+        buf,
+    )?;
+    flag(writer, b"ShowThinSeparators", settings.show_thin_separators)?;
+    flag(
+        writer,
+        b"AlwaysShowLastSplit",
+        settings.always_show_last_split,
+    )?;
+    flag(writer, b"ShowBlankSplits", settings.fill_with_blank_space)?;
+    list_gradient(writer, BACKGROUND, settings.background, buf)?;
+    flag(writer, b"SeparatorLastSplit", settings.separator_last_split)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    flag(writer, b"ShowColumnLabels", settings.show_column_labels)?;
+
+    // The columns are stored from right to left.
+    scoped_iter(
+        writer,
+        new_tag(b"Columns"),
+        settings.columns.iter().rev(),
+        |writer, column| {
+            write_start(writer, new_tag(b"Settings"))?;
+            version(writer, "1.5")?;
+            text(writer, new_tag(b"Name"), &column.name)?;
+            text(writer, new_tag(b"Type"), column_type(column))?;
+            comparison_override(writer, b"Comparison", &column.comparison_override)?;
+            timing_method_override(writer, b"TimingMethod", column.timing_method)?;
+            write_end(writer, b"Settings")
+        },
+    )?;
+
+    flag(writer, b"IndentSubsplits", settings.indent_subsplits)?;
+    flag(
+        writer,
+        b"HideSubsplits",
+        settings.subsplit_visibility == SubsplitVisibility::Never,
+    )?;
+    flag(
+        writer,
+        b"ShowSubsplits",
+        settings.subsplit_visibility == SubsplitVisibility::Always,
+    )?;
+    flag(writer, b"CurrentSectionOnly", settings.current_section_only)?;
+    flag(
+        writer,
+        b"OverrideSubsplitColor",
+        !matches!(settings.subsplit_gradient, Gradient::Transparent),
+    )?;
+    gradient(
+        writer,
+        [
+            b"SubsplitTopColor",
+            b"SubsplitBottomColor",
+            b"SubsplitGradient",
+        ],
+        settings.subsplit_gradient,
+        buf,
+    )?;
+    flag(writer, b"ShowHeader", settings.show_section_headers)?;
+    flag(writer, b"HeaderTimes", settings.show_section_header_times)?;
+    optional_color(
+        writer,
+        [b"HeaderTextColor", b"OverrideHeaderColor"],
+        settings.section_header_text_color,
+        buf,
+    )?;
+    gradient(
+        writer,
+        [b"HeaderTopColor", b"HeaderBottomColor", b"HeaderGradient"],
+        settings.section_header_gradient,
+        buf,
+    )
+}
+
+fn sum_of_best<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &sum_of_best::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.4")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimeColor", b"OverrideTimeColor"],
+        settings.value_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    accuracy(writer, b"Accuracy", settings.accuracy)
+}
+
+fn text_component<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &text::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    let (left_center, right) = match &settings.text {
+        text::Text::Center(center) => (center.as_str(), ""),
+        text::Text::Split(left, right) => (left.as_str(), right.as_str()),
+    };
+
+    version(writer, "1.4")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.left_center_color,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimeColor", b"OverrideTimeColor"],
+        settings.right_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    text(writer, new_tag(b"Text1"), left_center)?;
+    text(writer, new_tag(b"Text2"), right)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)
+}
+
+fn timer<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &timer::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.5")?;
+    number(writer, b"TimerHeight", settings.height, buf)?;
+    timer_format(
+        writer,
+        b"TimerFormat",
+        settings.digits_format,
+        settings.accuracy,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimerColor", b"OverrideSplitColors"],
+        settings.color_override,
+        buf,
+    )?;
+    flag(writer, b"ShowGradient", settings.show_gradient)?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    timing_method_override(writer, b"TimingMethod", settings.timing_method)
+}
+
+fn title<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &title::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    let alignment = match settings.text_alignment {
+        Alignment::Auto => "0",
+        Alignment::Left => "1",
+        Alignment::Center => "2",
+    };
+
+    version(writer, "1.7.3")?;
+    flag(writer, b"ShowGameName", settings.show_game_name)?;
+    flag(writer, b"ShowCategoryName", settings.show_category_name)?;
+    flag(
+        writer,
+        b"ShowFinishedRunsCount",
+        settings.show_finished_runs_count,
+    )?;
+    flag(writer, b"ShowAttemptCount", settings.show_attempt_count)?;
+    text(writer, new_tag(b"TextAlignment"), alignment)?;
+    flag(writer, b"SingleLine", settings.display_as_single_line)?;
+    optional_color(
+        writer,
+        [b"TitleColor", b"OverrideTitleColor"],
+        settings.text_color,
+        buf,
+    )?;
+    flag(writer, b"DisplayGameIcon", settings.display_game_icon)?;
+    flag(writer, b"ShowRegion", settings.show_region)?;
+    flag(writer, b"ShowPlatform", settings.show_platform)?;
+    flag(writer, b"ShowVariables", settings.show_variables)?;
+    gradient(writer, BACKGROUND, settings.background, buf)
+}
+
+fn total_playtime<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &total_playtime::Settings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    version(writer, "1.6")?;
+    optional_color(
+        writer,
+        [b"TextColor", b"OverrideTextColor"],
+        settings.label_color,
+        buf,
+    )?;
+    optional_color(
+        writer,
+        [b"TimeColor", b"OverrideTimeColor"],
+        settings.value_color,
+        buf,
+    )?;
+    gradient(writer, BACKGROUND, settings.background, buf)?;
+    flag(writer, b"Display2Rows", settings.display_two_rows)?;
+    flag(writer, b"ShowTotalHours", !settings.show_days)
+}
+
+fn component<W: Write>(
+    writer: &mut Writer<W>,
+    layout_component: &LayoutComponentSettings,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    let component = &layout_component.settings;
+    let path = match component {
+        ComponentSettings::BlankSpace(_) => "LiveSplit.BlankSpace.dll",
+        ComponentSettings::CurrentComparison(_) => "LiveSplit.CurrentComparison.dll",
+        ComponentSettings::CurrentPace(_) => "LiveSplit.RunPrediction.dll",
+        ComponentSettings::Delta(_) => "LiveSplit.Delta.dll",
+        ComponentSettings::DetailedTimer(_) => "LiveSplit.DetailedTimer.dll",
+        ComponentSettings::Graph(_) => "LiveSplit.Graph.dll",
+        ComponentSettings::PossibleTimeSave(_) => "LiveSplit.PossibleTimeSave.dll",
+        ComponentSettings::PreviousSegment(_) => "LiveSplit.PreviousSegment.dll",
+        ComponentSettings::Separator => "",
+        ComponentSettings::Splits(settings) => {
+            if settings.group_subsplits {
+                "LiveSplit.Subsplits.dll"
+            } else {
+                "LiveSplit.Splits.dll"
+            }
+        }
+        ComponentSettings::SumOfBest(_) => "LiveSplit.SumOfBest.dll",
+        ComponentSettings::Text(_) => "LiveSplit.Text.dll",
+        ComponentSettings::Timer(_) => "LiveSplit.Timer.dll",
+        ComponentSettings::Title(_) => "LiveSplit.Title.dll",
+        ComponentSettings::TotalPlaytime(_) => "LiveSplit.TotalPlaytime.dll",
+        ComponentSettings::Clock(_)
+        | ComponentSettings::Counter(_)
+        | ComponentSettings::Custom(_)
+        | ComponentSettings::Image(_)
+        | ComponentSettings::Notes(_)
+        | ComponentSettings::RunStatistics(_)
+        | ComponentSettings::SegmentHistory(_) => EMBEDDED_COMPONENT_PATH,
+    };
+
+    write_start(writer, new_tag(b"Component"))?;
+    text(writer, new_tag(b"Path"), path)?;
+
+    if path == EMBEDDED_COMPONENT_PATH {
+        text(
+            writer,
+            new_tag(b"Settings"),
+            serde_json::to_string(layout_component)?,
+        )?;
+        write_end(writer, b"Component")?;
+        return Ok(());
+    }
+
+    write_start(writer, new_tag(b"Settings"))?;
+    match component {
+        ComponentSettings::BlankSpace(settings) => blank_space(writer, settings, buf)?,
+        ComponentSettings::CurrentComparison(settings) => {
+            current_comparison(writer, settings, buf)?
+        }
+        ComponentSettings::CurrentPace(settings) => current_pace(writer, settings, buf)?,
+        ComponentSettings::Delta(settings) => delta(writer, settings, buf)?,
+        ComponentSettings::DetailedTimer(settings) => detailed_timer(writer, settings, buf)?,
+        ComponentSettings::Graph(settings) => graph(writer, settings, buf)?,
+        ComponentSettings::PossibleTimeSave(settings) => possible_time_save(writer, settings, buf)?,
+        ComponentSettings::PreviousSegment(settings) => previous_segment(writer, settings, buf)?,
+        ComponentSettings::Splits(settings) => splits(writer, settings, buf)?,
+        ComponentSettings::SumOfBest(settings) => sum_of_best(writer, settings, buf)?,
+        ComponentSettings::Text(settings) => text_component(writer, settings, buf)?,
+        ComponentSettings::Timer(settings) => timer(writer, settings, buf)?,
+        ComponentSettings::Title(settings) => title(writer, settings, buf)?,
+        ComponentSettings::TotalPlaytime(settings) => total_playtime(writer, settings, buf)?,
+        ComponentSettings::Separator => {}
+        _ => unreachable!(),
+    }
+    text(
+        writer,
+        new_tag(LAYOUT_COMPONENT_SETTINGS_TAG),
+        serde_json::to_string(layout_component)?,
+    )?;
+    write_end(writer, b"Settings")?;
+    write_end(writer, b"Component")?;
+
+    Ok(())
+}
+
+fn general_settings<W: Write>(
+    writer: &mut Writer<W>,
+    settings: &GeneralSettings,
+    buf: &mut Vec<u8>,
+) -> XmlResult<()> {
+    let has_image = !settings.background_image.is_empty();
+    let (kind, first, second) = match settings.background {
+        _ if has_image => ("Image", Color::black(), Color::black()),
+        Gradient::Transparent => ("SolidColor", Color::transparent(), Color::transparent()),
+        Gradient::Plain(plain) => ("SolidColor", plain, plain),
+        Gradient::Vertical(top, bottom) => ("VerticalGradient", top, bottom),
+        Gradient::Horizontal(left, right) => ("HorizontalGradient", left, right),
+    };
+
+    write_start(writer, new_tag(b"Settings"))?;
+    color(writer, b"TextColor", settings.text_color, buf)?;
+    color(writer, b"BackgroundColor", first, buf)?;
+    color(writer, b"BackgroundColor2", second, buf)?;
+    color(
+        writer,
+        b"ThinSeparatorsColor",
+        settings.thin_separators_color,
+        buf,
+    )?;
+    color(writer, b"SeparatorsColor", settings.separators_color, buf)?;
+    color(
+        writer,
+        b"PersonalBestColor",
+        settings.personal_best_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"AheadGainingTimeColor",
+        settings.ahead_gaining_time_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"AheadLosingTimeColor",
+        settings.ahead_losing_time_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"BehindGainingTimeColor",
+        settings.behind_gaining_time_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"BehindLosingTimeColor",
+        settings.behind_losing_time_color,
+        buf,
+    )?;
+    color(
+        writer,
+        b"BestSegmentColor",
+        settings.best_segment_color,
+        buf,
+    )?;
+    color(writer, b"NotRunningColor", settings.not_running_color, buf)?;
+    color(writer, b"PausedColor", settings.paused_color, buf)?;
+    text(writer, new_tag(b"BackgroundType"), kind)?;
+    image(
+        writer,
+        new_tag(b"BackgroundImage"),
+        &settings.background_image,
+        buf,
+        &mut Cow::Borrowed(&LSS_IMAGE_HEADER[..]),
+    )?;
+    number(
+        writer,
+        b"ImageOpacity",
+        settings.background_image_style.opacity,
+        buf,
+    )?;
+    number(
+        writer,
+        b"ImageBlur",
+        settings.background_image_style.blur,
+        buf,
+    )?;
+    number(writer, b"Opacity", 1, buf)?;
+    write_end(writer, b"Settings")
+}
+
+/// Saves the layout as a layout file of the original LiveSplit (*.lsl).
+pub fn save_layout<W: Write>(layout: &Layout, writer: W) -> Result<()> {
+    save_settings(&layout.settings(), writer)
+}
+
+/// Saves the settings of a layout as a layout file of the original LiveSplit
+/// (*.lsl).
+pub fn save_settings<W: Write>(settings: &LayoutSettings, writer: W) -> Result<()> {
+    let writer = &mut Writer::new(writer);
+    let buf = &mut Vec::new();
+
+    writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+    writer.write_event(Event::Start(BytesStart::borrowed(
+        br#"Layout version="1.6.1""#,
+        6,
+    )))?;
+
+    let mode = match settings.general.direction {
+        LayoutDirection::Vertical => "Vertical",
+        LayoutDirection::Horizontal => "Horizontal",
+    };
+    text(writer, new_tag(b"Mode"), mode)?;
+
+    // The position and size of the window are not known, so we let the
+    // original LiveSplit choose them.
+    number(writer, b"X", 0, buf)?;
+    number(writer, b"Y", 0, buf)?;
+    number(writer, b"VerticalWidth", -1, buf)?;
+    number(writer, b"VerticalHeight", -1, buf)?;
+    number(writer, b"HorizontalWidth", -1, buf)?;
+    number(writer, b"HorizontalHeight", -1, buf)?;
+
+    general_settings(writer, &settings.general, buf)?;
+
+    write_start(writer, new_tag(b"Components"))?;
+    for settings in &settings.components {
+        component(writer, settings, buf)?;
+    }
+    write_end(writer, b"Components")?;
+
+    write_end(writer, b"Layout")?;
+    Ok(())
+}
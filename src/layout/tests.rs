@@ -0,0 +1,206 @@
+use super::{
+    Component, ComponentSettings, ComponentState, GeneralSettings, Layout, LayoutSettings,
+    PaceCondition, RunningCondition,
+};
+use crate::component::{image, separator, text};
+use crate::tests_helper::{create_timer, start_run};
+use crate::{Image, Timer};
+
+fn text_component(value: &str) -> text::Component {
+    text::Component::with_settings(text::Settings {
+        text: text::Text::Center(value.into()),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn running_condition() {
+    let mut timer = create_timer(&["A", "B"]);
+    let mut layout = Layout::new();
+    layout.push(text_component("Always"));
+    layout.push(text_component("Running"));
+    layout.push(text_component("Not Running"));
+    layout.components[1].visibility.running = RunningCondition::WhileRunning;
+    layout.components[2].visibility.running = RunningCondition::WhileNotRunning;
+
+    assert_eq!(layout.state(&timer).components.len(), 2);
+
+    start_run(&mut timer);
+    let state = layout.state(&timer);
+    assert_eq!(state.components.len(), 2);
+    match &state.components[1].state {
+        ComponentState::Text(state) => match &state.text {
+            text::Text::Center(text) => assert_eq!(text, "Running"),
+            _ => panic!("Unexpected text"),
+        },
+        _ => panic!("Unexpected component"),
+    }
+}
+
+#[test]
+fn pace_condition_is_not_met_without_an_attempt() {
+    let timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(text_component("Ahead"));
+    layout.components[0].visibility.pace = PaceCondition::WhileAhead;
+
+    assert!(layout.state(&timer).components.is_empty());
+}
+
+#[test]
+fn only_on_last_split() {
+    let mut timer = create_timer(&["A", "B"]);
+    let mut layout = Layout::new();
+    layout.push(text_component("Last"));
+    layout.components[0].visibility.only_on_last_split = true;
+
+    assert!(layout.state(&timer).components.is_empty());
+    start_run(&mut timer);
+    assert!(layout.state(&timer).components.is_empty());
+    timer.split();
+    assert_eq!(layout.state(&timer).components.len(), 1);
+}
+
+#[test]
+fn variable_condition() {
+    let mut timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(text_component("Any%"));
+    {
+        let visibility = &mut layout.components[0].visibility;
+        visibility.variable_name = Some("Category".into());
+        visibility.variable_value = "Any%".into();
+    }
+
+    assert!(layout.state(&timer).components.is_empty());
+
+    let mut run = timer.run().clone();
+    run.metadata_mut().set_variable("Category", "Any%");
+    timer.set_run(run).unwrap();
+    assert_eq!(layout.state(&timer).components.len(), 1);
+}
+
+#[test]
+fn hide_if_empty() {
+    let timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(text_component(""));
+    layout.push(text_component("Text"));
+    layout.components[0].visibility.hide_if_empty = true;
+    layout.components[1].visibility.hide_if_empty = true;
+
+    assert_eq!(layout.state(&timer).components.len(), 1);
+}
+
+#[test]
+fn components_get_remounted_when_they_reappear() {
+    let mut timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(image::Component::new());
+    layout.push(image::Component::new());
+    layout.components[0].visibility.running = RunningCondition::WhileNotRunning;
+
+    let image_changes = |layout: &mut Layout, timer: &Timer| {
+        layout
+            .state(timer)
+            .components
+            .iter()
+            .map(|component| match &component.state {
+                ComponentState::Image(state) => state.image_change.is_some(),
+                _ => panic!("Unexpected component"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(image_changes(&mut layout, &timer), [true, true]);
+    assert_eq!(image_changes(&mut layout, &timer), [false, false]);
+    start_run(&mut timer);
+    assert_eq!(image_changes(&mut layout, &timer), [false]);
+    timer.reset(true);
+    assert_eq!(image_changes(&mut layout, &timer), [true, false]);
+    assert_eq!(image_changes(&mut layout, &timer), [false, false]);
+}
+
+#[test]
+fn visibility_is_stored_with_the_components() {
+    let mut layout = Layout::new();
+    layout.push(text_component("Always"));
+    layout.push(text_component("Running"));
+    layout.push(separator::Component::new());
+    layout.components[1].visibility.running = RunningCondition::WhileRunning;
+    layout.components[2].visibility.running = RunningCondition::WhileNotRunning;
+    layout.components.swap(0, 1);
+
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    let layout = Layout::from_settings(LayoutSettings::from_json(&buf[..]).unwrap());
+
+    assert_eq!(
+        layout.components[0].visibility.running,
+        RunningCondition::WhileRunning,
+    );
+    assert_eq!(
+        layout.components[1].visibility.running,
+        RunningCondition::Always
+    );
+    assert_eq!(
+        layout.components[2].visibility.running,
+        RunningCondition::WhileNotRunning,
+    );
+}
+
+#[test]
+fn layouts_without_layout_specific_component_settings_can_be_loaded() {
+    let components = vec![
+        ComponentSettings::Text(text_component("Text").settings().clone()),
+        ComponentSettings::Separator,
+    ];
+    let json = serde_json::json!({
+        "components": components,
+        "general": GeneralSettings::default(),
+    });
+    assert_eq!(json["components"][1], "Separator");
+
+    let settings = LayoutSettings::from_json(json.to_string().as_bytes()).unwrap();
+    let layout = Layout::from_settings(settings);
+
+    assert_eq!(layout.components.len(), 2);
+    match layout.components[0].component {
+        Component::Text(_) => {}
+        _ => panic!("The first component should be a Text component"),
+    }
+    match layout.components[1].component {
+        Component::Separator(_) => {}
+        _ => panic!("The second component should be a Separator"),
+    }
+    assert_eq!(
+        layout.components[0].visibility.running,
+        RunningCondition::Always
+    );
+}
+
+#[test]
+fn background_images_are_stored_with_the_components() {
+    let timer = create_timer(&["A"]);
+    let mut layout = Layout::new();
+    layout.push(text_component("Text"));
+    layout.components[0].background_image = Image::new(b"image");
+    layout.components[0].background_image_style.opacity = 0.5;
+
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    let mut layout = Layout::from_settings(LayoutSettings::from_json(&buf[..]).unwrap());
+
+    let state = layout.state(&timer);
+    let component = &state.components[0];
+    let image = &layout.components[0].background_image;
+    assert_eq!(component.background_image_id, Some(image.id()));
+    assert_eq!(
+        component.background_image_change.as_deref(),
+        Some(image.url())
+    );
+    assert_eq!(component.background_image_style.opacity, 0.5);
+
+    let state = layout.state(&timer);
+    assert!(state.components[0].background_image_change.is_none());
+}
@@ -0,0 +1,204 @@
+use super::ComponentState;
+use crate::settings::{Field, SettingsDescription, Value};
+use crate::timing::formatter::DASH;
+use crate::{analysis, component::text, TimeSpan, Timer, TimerPhase};
+
+/// Describes when a component is shown based on whether there is an active
+/// attempt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RunningCondition {
+    /// The component is shown regardless of whether there is an active
+    /// attempt.
+    Always,
+    /// The component is only shown while there is an active attempt. This
+    /// includes the attempt being paused or having ended.
+    WhileRunning,
+    /// The component is only shown while there is no active attempt.
+    WhileNotRunning,
+}
+
+impl Default for RunningCondition {
+    fn default() -> Self {
+        RunningCondition::Always
+    }
+}
+
+/// Describes when a component is shown based on whether the current attempt is
+/// ahead of or behind the current comparison.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaceCondition {
+    /// The component is shown regardless of the current attempt's pace.
+    Always,
+    /// The component is only shown while the current attempt is ahead of the
+    /// current comparison.
+    WhileAhead,
+    /// The component is only shown while the current attempt is behind the
+    /// current comparison.
+    WhileBehind,
+}
+
+impl Default for PaceCondition {
+    fn default() -> Self {
+        PaceCondition::Always
+    }
+}
+
+/// Describes the conditions under which a component of a layout is shown. A
+/// component is only shown if all of its conditions are met. By default there
+/// are no conditions, so the component is always shown.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Visibility {
+    /// Specifies whether the component is only shown while there is or isn't
+    /// an active attempt.
+    pub running: RunningCondition,
+    /// Specifies whether the component is only shown while the current attempt
+    /// is ahead of or behind the current comparison.
+    pub pace: PaceCondition,
+    /// Specifies whether the component is only shown while the last segment is
+    /// the current segment.
+    pub only_on_last_split: bool,
+    /// The name of a variable of the run's metadata. If specified, the
+    /// component is only shown while the variable has the value specified by
+    /// `variable_value`.
+    pub variable_name: Option<String>,
+    /// The value the variable specified by `variable_name` needs to have for
+    /// the component to be shown.
+    pub variable_value: String,
+    /// Specifies whether the component is hidden whenever it has nothing to
+    /// show, such as a text without a value or a time that is not available.
+    pub hide_if_empty: bool,
+}
+
+impl Visibility {
+    /// Checks whether all the conditions that can be evaluated without knowing
+    /// the component's state are met.
+    pub fn is_met(&self, timer: &Timer) -> bool {
+        let phase = timer.current_phase();
+        let running = match self.running {
+            RunningCondition::Always => true,
+            RunningCondition::WhileRunning => phase != TimerPhase::NotRunning,
+            RunningCondition::WhileNotRunning => phase == TimerPhase::NotRunning,
+        };
+        if !running {
+            return false;
+        }
+
+        if self.pace != PaceCondition::Always {
+            let delta = if phase == TimerPhase::NotRunning {
+                None
+            } else {
+                analysis::delta::calculate(timer, timer.current_comparison()).0
+            };
+            let is_met = match (self.pace, delta) {
+                (PaceCondition::WhileAhead, Some(delta)) => delta < TimeSpan::zero(),
+                (PaceCondition::WhileBehind, Some(delta)) => delta > TimeSpan::zero(),
+                _ => false,
+            };
+            if !is_met {
+                return false;
+            }
+        }
+
+        if self.only_on_last_split {
+            let last_index = timer.run().len().checked_sub(1);
+            if timer.current_split_index() != last_index || phase == TimerPhase::NotRunning {
+                return false;
+            }
+        }
+
+        if let Some(name) = &self.variable_name {
+            let value = timer
+                .run()
+                .metadata()
+                .variables()
+                .find(|&(n, _)| n == name)
+                .map(|(_, value)| value.as_str());
+            if value != Some(self.variable_value.as_str()) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Checks whether the component with the state provided is allowed to be
+    /// shown.
+    pub fn allows(&self, state: &ComponentState) -> bool {
+        !self.hide_if_empty || !is_empty(state)
+    }
+
+    /// Accesses a generic description of the visibility settings and their
+    /// current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Running".into(), self.running.into()),
+            Field::new("Pace".into(), self.pace.into()),
+            Field::new("Only On Last Split".into(), self.only_on_last_split.into()),
+            Field::new("Variable".into(), self.variable_name.clone().into()),
+            Field::new("Variable Value".into(), self.variable_value.clone().into()),
+            Field::new("Hide If Empty".into(), self.hide_if_empty.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.running = value.into(),
+            1 => self.pace = value.into(),
+            2 => self.only_on_last_split = value.into(),
+            3 => self.variable_name = value.into(),
+            4 => self.variable_value = value.into(),
+            5 => self.hide_if_empty = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn is_empty_value(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty() || value == DASH
+}
+
+/// A component is considered empty if the value it is supposed to show is not
+/// available. Components that don't show any value are never considered empty.
+fn is_empty(state: &ComponentState) -> bool {
+    match state {
+        ComponentState::Counter(state) => is_empty_value(&state.value),
+        ComponentState::CurrentComparison(state) => is_empty_value(&state.comparison),
+        ComponentState::CurrentPace(state) => is_empty_value(&state.time),
+        ComponentState::Delta(state) => {
+            if state.comparisons.is_empty() {
+                is_empty_value(&state.time)
+            } else {
+                state.comparisons.iter().all(|c| is_empty_value(&c.time))
+            }
+        }
+        ComponentState::Notes(state) => state.notes.trim().is_empty(),
+        ComponentState::PossibleTimeSave(state) => is_empty_value(&state.time),
+        ComponentState::PreviousSegment(state) => {
+            if state.comparisons.is_empty() {
+                is_empty_value(&state.time)
+            } else {
+                state.comparisons.iter().all(|c| is_empty_value(&c.time))
+            }
+        }
+        ComponentState::RunStatistics(state) => state.statistics.is_empty(),
+        ComponentState::SegmentHistory(state) => state.times.is_empty(),
+        ComponentState::Splits(state) => state.splits.is_empty(),
+        ComponentState::SumOfBest(state) => is_empty_value(&state.time),
+        ComponentState::Text(state) => match &state.text {
+            text::Text::Center(text) => text.trim().is_empty(),
+            text::Text::Split(_, value) => is_empty_value(value),
+        },
+        ComponentState::Title(state) => state.line1.trim().is_empty() && state.line2.is_none(),
+        ComponentState::TotalPlaytime(state) => is_empty_value(&state.time),
+        _ => false,
+    }
+}
@@ -83,6 +83,7 @@ pub mod settings;
 pub mod tests_helper;
 pub mod timing;
 mod xml_util;
+mod xml_writer;
 
 pub use {
     crate::{
@@ -94,8 +95,8 @@ pub use {
         },
         run::{Attempt, Editor as RunEditor, Run, RunMetadata, Segment, SegmentHistory},
         timing::{
-            AtomicDateTime, GameTime, RealTime, SharedTimer, Time, TimeSpan, TimeStamp, Timer,
-            TimerPhase, TimingMethod,
+            AtomicDateTime, Counter, GameTime, RealTime, SharedTimer, Time, TimeSpan, TimeStamp,
+            Timer, TimerPhase, TimingMethod,
         },
     },
     chrono::{DateTime, Utc},
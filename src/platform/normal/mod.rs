@@ -2,8 +2,12 @@
 
 pub use std::time::{Duration, Instant};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
 
 pub fn utc_now() -> DateTime<Utc> {
     Utc::now()
 }
+
+pub fn local_utc_offset(time: DateTime<Utc>) -> FixedOffset {
+    Local.offset_from_utc_datetime(&time.naive_utc())
+}
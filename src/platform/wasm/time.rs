@@ -1,8 +1,10 @@
+use chrono::{DateTime, FixedOffset, Utc};
 use ordered_float::OrderedFloat;
 use std::ops::Sub;
 
 extern "C" {
     fn Instant_now() -> f64;
+    fn Date_timezone_offset(milliseconds: f64) -> f64;
 }
 
 #[derive(Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Debug)]
@@ -26,3 +28,9 @@ impl Sub for Instant {
 }
 
 pub use std::time::Duration;
+
+pub fn local_utc_offset(time: DateTime<Utc>) -> FixedOffset {
+    // JavaScript provides the offset in minutes, with the opposite sign.
+    let minutes = unsafe { Date_timezone_offset(time.timestamp_millis() as f64) };
+    FixedOffset::east_opt(-minutes as i32 * 60).unwrap_or_else(|| FixedOffset::east(0))
+}
@@ -0,0 +1,46 @@
+use crate::{
+    component::clock::State,
+    layout::LayoutState,
+    rendering::{
+        Backend, RenderContext, BOTH_VERTICAL_PADDINGS, DEFAULT_COMPONENT_HEIGHT,
+        DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_TOP,
+    },
+};
+
+pub(in crate::rendering) fn height(component: &State) -> f32 {
+    if component.times.is_empty() {
+        DEFAULT_COMPONENT_HEIGHT
+    } else {
+        component.times.len() as f32 * DEFAULT_TEXT_SIZE + BOTH_VERTICAL_PADDINGS
+    }
+}
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let label_color = component.label_color.unwrap_or(layout_state.text_color);
+    let value_color = component.value_color.unwrap_or(layout_state.text_color);
+    let mut y = TEXT_ALIGN_TOP;
+
+    for time in &component.times {
+        let left_of_value_x = context.render_text_right_align(
+            &time.value,
+            [width - PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [value_color; 2],
+        );
+        context.render_text_ellipsis(
+            &time.name,
+            [PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [label_color; 2],
+            left_of_value_x - PADDING,
+        );
+        y += DEFAULT_TEXT_SIZE;
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    component::custom::{Primitive, State},
+    rendering::{Backend, Canvas, DrawCallback, Pos, RenderContext},
+    settings::{Color, Gradient},
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    dim: [f32; 2],
+    component: &State,
+    draw_callbacks: &mut [(String, DrawCallback)],
+) {
+    for primitive in &component.primitives {
+        match primitive {
+            Primitive::Rectangle {
+                top_left,
+                bottom_right,
+                background,
+            } => context.render_rectangle(*top_left, *bottom_right, background),
+            Primitive::Text {
+                text,
+                position,
+                size,
+                align,
+                color,
+            } => Canvas::render_text(context, text, *position, *size, *align, *color),
+        }
+    }
+
+    if let Some((_, draw)) = draw_callbacks
+        .iter_mut()
+        .find(|(id, _)| *id == component.id)
+    {
+        draw(context, dim, component);
+    }
+}
+
+impl<B: Backend> Canvas for RenderContext<'_, B> {
+    fn render_rectangle(&mut self, top_left: Pos, bottom_right: Pos, gradient: &Gradient) {
+        RenderContext::render_rectangle(self, top_left, bottom_right, gradient);
+    }
+
+    fn render_text(&mut self, text: &str, pos: Pos, scale: f32, align: f32, color: Color) {
+        self.render_text_align(
+            text,
+            f32::NEG_INFINITY,
+            f32::INFINITY,
+            pos,
+            scale,
+            align,
+            color,
+        );
+    }
+
+    fn measure_text(&self, text: &str, scale: f32) -> f32 {
+        RenderContext::measure_text(self, text, scale)
+    }
+}
@@ -2,22 +2,53 @@ use crate::{
     comparison,
     component::delta::State,
     layout::{LayoutDirection, LayoutState},
-    rendering::{Backend, RenderContext},
+    rendering::{Backend, RenderContext, DEFAULT_COMPONENT_HEIGHT, TWO_ROW_HEIGHT},
 };
 
+pub(in crate::rendering) fn height(component: &State) -> f32 {
+    let row_height = if component.display_two_rows {
+        TWO_ROW_HEIGHT
+    } else {
+        DEFAULT_COMPONENT_HEIGHT
+    };
+    component.comparisons.len().max(1) as f32 * row_height
+}
+
 pub(in crate::rendering) fn render(
     context: &mut RenderContext<'_, impl Backend>,
-    dim: [f32; 2],
+    [width, height]: [f32; 2],
     component: &State,
     layout_state: &LayoutState,
 ) {
-    context.render_rectangle([0.0, 0.0], dim, &component.background);
-    context.render_info_time_component(
-        &[&component.text, comparison::shorten(&component.text)],
-        &component.time,
-        dim,
-        component.label_color.unwrap_or(layout_state.text_color),
-        component.visual_color,
-        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
-    );
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+    let label_color = component.label_color.unwrap_or(layout_state.text_color);
+    let display_two_rows =
+        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal;
+
+    if component.comparisons.is_empty() {
+        context.render_info_time_component(
+            &[&component.text, comparison::shorten(&component.text)],
+            &component.time,
+            [width, height],
+            label_color,
+            component.visual_color,
+            display_two_rows,
+        );
+        return;
+    }
+
+    let row_height = height / component.comparisons.len() as f32;
+    for (index, row) in component.comparisons.iter().enumerate() {
+        let y = index as f32 * row_height;
+        context.translate(0.0, y);
+        context.render_info_time_component(
+            &[&row.text],
+            &row.time,
+            [width, row_height],
+            label_color,
+            row.visual_color,
+            display_two_rows,
+        );
+        context.translate(0.0, -y);
+    }
 }
@@ -1,6 +1,6 @@
 use {
     crate::{
-        component::graph::State,
+        component::graph::{GraphMode, State},
         layout::LayoutState,
         rendering::{
             mesh::{fill_builder, stroke_builder},
@@ -28,6 +28,8 @@ pub(in crate::rendering) fn render(
     const GRID_LINE_WIDTH: f32 = 0.015;
     const LINE_WIDTH: f32 = 0.025;
     const CIRCLE_RADIUS: f32 = 0.035;
+    const OVERLAY_LINE_WIDTH: f32 = 0.015;
+    const BAR_GAP: f32 = 0.1;
 
     context.render_rectangle(
         [0.0, 0.0],
@@ -56,8 +58,51 @@ pub(in crate::rendering) fn render(
         );
     }
 
+    if component.mode == GraphMode::Histogram {
+        let bar_width = width / component.bars.len() as f32;
+        for (index, &bar) in component.bars.iter().enumerate() {
+            let left = index as f32 * bar_width + 0.5 * BAR_GAP * bar_width;
+            let right = left + (1.0 - BAR_GAP) * bar_width;
+            let (top, bottom) = if component.is_flipped {
+                (0.0, bar)
+            } else {
+                (1.0 - bar, 1.0)
+            };
+            let color = if component.highlighted_bar == Some(index) {
+                component.graph_lines_color
+            } else {
+                component.complete_fill_color
+            };
+            context.render_rectangle([left, top], [right, bottom], &Gradient::Plain(color));
+        }
+
+        context.transform = old_transform;
+        return;
+    }
+
     let mut mesh = Mesh::new();
 
+    for overlay in component.overlays.iter().filter(|o| o.len() > 1) {
+        mesh.clear();
+
+        stroke_polyline(
+            overlay.iter().map(|p| [width * p.x, p.y].into()),
+            false,
+            &StrokeOptions::default().with_line_width(OVERLAY_LINE_WIDTH),
+            &mut stroke_builder(&mut mesh),
+        )
+        .unwrap();
+
+        let overlay_mesh = context.create_mesh(&mesh);
+        context.render_mesh(&overlay_mesh, component.overlay_lines_color);
+        context.free_mesh(overlay_mesh);
+    }
+
+    if component.points.is_empty() {
+        context.transform = old_transform;
+        return;
+    }
+
     let len = if component.is_live_delta_active {
         let p1 = &component.points[component.points.len() - 2];
         let p2 = &component.points[component.points.len() - 1];
@@ -0,0 +1,21 @@
+use crate::{
+    component::image::State,
+    rendering::{icon::ImageCache, Backend, RenderContext},
+};
+
+pub(in crate::rendering) fn render<B: Backend>(
+    context: &mut RenderContext<'_, B>,
+    dim: [f32; 2],
+    component: &State,
+    images: &mut ImageCache<B::Texture>,
+) {
+    context.render_rectangle([0.0, 0.0], dim, &component.background);
+
+    let style = &component.style;
+    if let Some(id) = component.image_id {
+        let image_change = component.image_change.as_deref();
+        if let Some(image) = context.cached_image(images, id, image_change, style.blur) {
+            context.render_image([0.0, 0.0], dim, image, style);
+        }
+    }
+}
@@ -1,11 +1,18 @@
 pub mod blank_space;
+pub mod clock;
+pub mod counter;
 pub mod current_comparison;
 pub mod current_pace;
+pub mod custom;
 pub mod delta;
 pub mod detailed_timer;
 pub mod graph;
+pub mod image;
+pub mod notes;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod run_statistics;
+pub mod segment_history;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
@@ -0,0 +1,95 @@
+use crate::{
+    component::notes::State,
+    layout::LayoutState,
+    rendering::{
+        Backend, RenderContext, BOTH_PADDINGS, BOTH_VERTICAL_PADDINGS, DEFAULT_TEXT_SIZE, PADDING,
+        TEXT_ALIGN_TOP,
+    },
+};
+
+pub(in crate::rendering) fn height(component: &State) -> f32 {
+    let lines = component.lines as f32
+        + if component.segment_name.is_some() {
+            1.0
+        } else {
+            0.0
+        };
+    lines * DEFAULT_TEXT_SIZE + BOTH_VERTICAL_PADDINGS
+}
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let text_color = component.text_color.unwrap_or(layout_state.text_color);
+    let mut y = TEXT_ALIGN_TOP;
+
+    if let Some(segment_name) = &component.segment_name {
+        context.render_text_ellipsis(
+            segment_name,
+            [PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [component.label_color.unwrap_or(layout_state.text_color); 2],
+            width - PADDING,
+        );
+        y += DEFAULT_TEXT_SIZE;
+    }
+
+    let lines = wrap(context, &component.notes, width - BOTH_PADDINGS);
+    let visible = component.lines as usize;
+
+    for (index, line) in lines.iter().enumerate().take(visible) {
+        // The last visible line shows the beginning of all the remaining lines,
+        // so it gets cut off with an ellipsis if there's more to show.
+        let line = if index + 1 == visible && lines.len() > visible {
+            lines[index..].join(" ")
+        } else {
+            line.clone()
+        };
+
+        context.render_text_ellipsis(
+            &line,
+            [PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [text_color; 2],
+            width - PADDING,
+        );
+        y += DEFAULT_TEXT_SIZE;
+    }
+}
+
+/// Wraps the text onto as many lines as necessary for each of them to fit into
+/// the maximum width. Words are only split up if they don't fit onto a line on
+/// their own, in which case they get cut off when rendered.
+fn wrap(context: &RenderContext<'_, impl Backend>, text: &str, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            if line.is_empty() {
+                line.push_str(word);
+                continue;
+            }
+
+            let len = line.len();
+            line.push(' ');
+            line.push_str(word);
+
+            if context.measure_text(&line, DEFAULT_TEXT_SIZE) > max_width {
+                line.truncate(len);
+                lines.push(line);
+                line = String::from(word);
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
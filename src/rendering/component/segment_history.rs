@@ -0,0 +1,101 @@
+use crate::{
+    component::segment_history::State,
+    layout::LayoutState,
+    rendering::{
+        mesh::stroke_builder, Backend, Mesh, RenderContext, BOTH_PADDINGS, BOTH_VERTICAL_PADDINGS,
+        DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_TOP,
+    },
+};
+use lyon::tessellation::{basic_shapes::stroke_polyline, StrokeOptions};
+
+const SPARKLINE_HEIGHT: f32 = 2.0 * DEFAULT_TEXT_SIZE;
+const SPARKLINE_LINE_WIDTH: f32 = 0.05;
+
+pub(in crate::rendering) fn height(component: &State) -> f32 {
+    let rows = component.times.len() + component.segment_name.is_some() as usize;
+    let sparkline = if component.sparkline.is_some() {
+        SPARKLINE_HEIGHT
+    } else {
+        0.0
+    };
+
+    if rows == 0 && sparkline == 0.0 {
+        DEFAULT_COMPONENT_HEIGHT
+    } else {
+        rows as f32 * DEFAULT_TEXT_SIZE + sparkline + BOTH_VERTICAL_PADDINGS
+    }
+}
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let label_color = component.label_color.unwrap_or(layout_state.text_color);
+    let mut y = TEXT_ALIGN_TOP;
+
+    if let Some(segment_name) = &component.segment_name {
+        context.render_text_ellipsis(
+            segment_name,
+            [PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [label_color; 2],
+            width - PADDING,
+        );
+        y += DEFAULT_TEXT_SIZE;
+    }
+
+    for time in &component.times {
+        let left_of_delta_x = context.render_numbers(
+            &time.delta,
+            [width - PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [time.visual_color; 2],
+        );
+        context.render_text_ellipsis(
+            &time.time,
+            [PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [label_color; 2],
+            left_of_delta_x - PADDING,
+        );
+        y += DEFAULT_TEXT_SIZE;
+    }
+
+    if let Some(sparkline) = &component.sparkline {
+        if sparkline.len() < 2 {
+            return;
+        }
+
+        let top = height - BOTH_VERTICAL_PADDINGS / 2.0 - SPARKLINE_HEIGHT;
+        let line_height = SPARKLINE_HEIGHT - SPARKLINE_LINE_WIDTH;
+        let line_width = width - BOTH_PADDINGS;
+        let last = (sparkline.len() - 1) as f32;
+
+        // Slower segment times are drawn higher up, just like in the graph.
+        let points = sparkline.iter().enumerate().map(|(i, &value)| {
+            [
+                PADDING + line_width * i as f32 / last,
+                top + SPARKLINE_LINE_WIDTH / 2.0 + line_height * (1.0 - value),
+            ]
+            .into()
+        });
+
+        let mut mesh = Mesh::new();
+        stroke_polyline(
+            points,
+            false,
+            &StrokeOptions::default().with_line_width(SPARKLINE_LINE_WIDTH),
+            &mut stroke_builder(&mut mesh),
+        )
+        .unwrap();
+
+        let sparkline_color = component.sparkline_color.unwrap_or(layout_state.text_color);
+        let mesh = context.create_mesh(&mesh);
+        context.render_mesh(&mesh, sparkline_color);
+        context.free_mesh(mesh);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    component::splits::State,
+    component::splits::{SplitKind, State},
     layout::{LayoutDirection, LayoutState},
     rendering::{
         icon::Icon, vertical_padding, Backend, RenderContext, BOTH_PADDINGS,
@@ -10,6 +10,7 @@ use crate::{
 };
 
 pub const COLUMN_WIDTH: f32 = 3.0;
+const SUBSPLIT_INDENT: f32 = 2.0 * PADDING;
 
 pub(in crate::rendering) fn render<B: Backend>(
     context: &mut RenderContext<'_, B>,
@@ -115,14 +116,35 @@ pub(in crate::rendering) fn render<B: Backend>(
                 [split_width, split_height],
                 &component.current_split_gradient,
             );
-        } else if let Some((even, odd)) = &split_background {
-            let color = if split.index % 2 == 0 { even } else { odd };
-            context.render_rectangle([0.0, 0.0], split_background_bottom_right, color);
+        } else if split.kind == SplitKind::SectionHeader {
+            context.render_rectangle(
+                [0.0, 0.0],
+                split_background_bottom_right,
+                &component.section_header_gradient,
+            );
+        } else {
+            if let Some((even, odd)) = &split_background {
+                let color = if split.index % 2 == 0 { even } else { odd };
+                context.render_rectangle([0.0, 0.0], split_background_bottom_right, color);
+            }
+            if split.kind == SplitKind::Subsplit {
+                context.render_rectangle(
+                    [0.0, 0.0],
+                    split_background_bottom_right,
+                    &component.subsplit_gradient,
+                );
+            }
         }
 
         {
+            let indent = if component.indent_subsplits && split.kind == SplitKind::Subsplit {
+                SUBSPLIT_INDENT
+            } else {
+                0.0
+            };
+
             if let Some(Some(icon)) = split_icons.get(split.index) {
-                context.render_icon([PADDING, icon_y], [icon_size, icon_size], icon);
+                context.render_icon([PADDING + indent, icon_y], [icon_size, icon_size], icon);
             }
 
             let mut left_x = split_width - PADDING;
@@ -143,11 +165,19 @@ pub(in crate::rendering) fn render<B: Backend>(
                 left_x = split_width;
             }
 
+            let text_color = if split.kind == SplitKind::SectionHeader {
+                component
+                    .section_header_text_color
+                    .unwrap_or(layout_state.text_color)
+            } else {
+                layout_state.text_color
+            };
+
             context.render_text_ellipsis(
                 &split.name,
-                [icon_right, TEXT_ALIGN_TOP],
+                [icon_right + indent, TEXT_ALIGN_TOP],
                 DEFAULT_TEXT_SIZE,
-                [layout_state.text_color; 2],
+                [text_color; 2],
                 left_x - PADDING,
             );
         }
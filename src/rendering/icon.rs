@@ -1,4 +1,124 @@
+use image::{imageops, RgbaImage};
+
 pub struct Icon<T> {
     pub texture: T,
     pub aspect_ratio: f32,
 }
+
+/// An image that can be shown with a varying amount of blur applied to it. The
+/// decoded image is kept around so that the texture can be recreated whenever
+/// the amount of blur changes.
+pub struct BlurredImage<T> {
+    pub icon: Icon<T>,
+    pub image: RgbaImage,
+    pub blur: f32,
+}
+
+/// Caches the images shown by the components. The images are identified by
+/// their IDs rather than by the components showing them, so they stay cached
+/// when the components move around within the layout. The same image may be
+/// cached with different amounts of blur.
+pub struct ImageCache<T> {
+    images: Vec<CachedImage<T>>,
+}
+
+struct CachedImage<T> {
+    id: usize,
+    image: BlurredImage<T>,
+    used: bool,
+}
+
+impl<T> Default for ImageCache<T> {
+    fn default() -> Self {
+        Self { images: Vec::new() }
+    }
+}
+
+impl<T> ImageCache<T> {
+    fn position(&self, id: usize, blur: f32) -> Option<usize> {
+        self.images
+            .iter()
+            .position(|c| c.id == id && c.image.blur == blur)
+    }
+
+    /// Checks whether the image with the ID provided is cached with the amount
+    /// of blur provided.
+    pub fn contains(&self, id: usize, blur: f32) -> bool {
+        self.position(id, blur).is_some()
+    }
+
+    /// Accesses the decoded image with the ID provided, regardless of the
+    /// amount of blur it is cached with.
+    pub fn source(&self, id: usize) -> Option<&RgbaImage> {
+        self.images
+            .iter()
+            .find(|c| c.id == id)
+            .map(|c| &c.image.image)
+    }
+
+    /// Adds the image with the ID provided to the cache.
+    pub fn insert(&mut self, id: usize, image: BlurredImage<T>) {
+        self.images.push(CachedImage {
+            id,
+            image,
+            used: false,
+        });
+    }
+
+    /// Accesses the image with the ID and the amount of blur provided and marks
+    /// it as used.
+    pub fn get(&mut self, id: usize, blur: f32) -> Option<&BlurredImage<T>> {
+        let index = self.position(id, blur)?;
+        let cached = &mut self.images[index];
+        cached.used = true;
+        Some(&cached.image)
+    }
+
+    /// Removes all the images that weren't used since the last time this was
+    /// called. The textures of the removed images are passed to the function
+    /// provided, so that they can be freed.
+    pub fn remove_unused(&mut self, mut free_texture: impl FnMut(T)) {
+        let mut index = 0;
+        while index < self.images.len() {
+            if self.images[index].used {
+                self.images[index].used = false;
+                index += 1;
+            } else {
+                free_texture(self.images.swap_remove(index).image.icon.texture);
+            }
+        }
+    }
+}
+
+/// The standard deviation of the gaussian blur at maximum blur, relative to the
+/// larger dimension of the image.
+const MAX_BLUR_SIGMA: f32 = 0.05;
+
+/// The standard deviation, in pixels, that the blur is applied with. Instead of
+/// blurring the image at its full resolution with a larger standard deviation,
+/// the image is shrunk down first, which is a lot cheaper and looks the same,
+/// as the texture gets scaled back up when it is rendered.
+const WORKING_SIGMA: f32 = 2.0;
+
+/// Applies the amount of blur specified to the image. The amount of blur ranges
+/// from 0 (no blur) to 1 (maximum blur). The resulting image may have a lower
+/// resolution than the original image.
+pub fn blur(image: &RgbaImage, blur: f32) -> Option<RgbaImage> {
+    let (width, height) = image.dimensions();
+    let sigma = blur.min(1.0) * MAX_BLUR_SIGMA * width.max(height) as f32;
+    if sigma <= 0.0 {
+        return None;
+    }
+
+    let shrink_factor = (sigma / WORKING_SIGMA).max(1.0);
+    let shrunk_width = ((width as f32 / shrink_factor).ceil() as u32).max(1);
+    let shrunk_height = ((height as f32 / shrink_factor).ceil() as u32).max(1);
+    let shrunk = imageops::resize(
+        image,
+        shrunk_width,
+        shrunk_height,
+        imageops::FilterType::Triangle,
+    );
+
+    Some(imageops::blur(&shrunk, sigma / shrink_factor))
+}
@@ -73,13 +73,19 @@ mod mesh;
 pub mod software;
 
 use {
-    self::{glyph_cache::GlyphCache, icon::Icon},
+    self::{
+        glyph_cache::GlyphCache,
+        icon::{BlurredImage, Icon, ImageCache},
+    },
     crate::{
-        layout::{ComponentState, LayoutDirection, LayoutState},
-        settings::{Color, Gradient},
+        component::custom,
+        layout::{ComponentState, LayoutComponentState, LayoutDirection, LayoutState},
+        settings::{Color, Gradient, ImageScaling, ImageStyle},
     },
     euclid::Transform2D,
+    image::RgbaImage,
     rusttype::Font,
+    std::mem,
 };
 
 pub use self::mesh::{Mesh, Vertex};
@@ -175,6 +181,29 @@ pub trait Backend {
     fn resize(&mut self, width: f32, height: f32);
 }
 
+/// A canvas that custom components can be drawn onto by a draw callback. All
+/// the coordinates are in the component coordinate space, so (0, 0) is the top
+/// left corner of the component.
+pub trait Canvas {
+    /// Fills the rectangle with the gradient provided.
+    fn render_rectangle(&mut self, top_left: Pos, bottom_right: Pos, gradient: &Gradient);
+
+    /// Renders a line of text with its baseline at the position provided. The
+    /// alignment specifies how the text is aligned around the position. 0
+    /// means the text starts at the position, 0.5 means it is centered and 1
+    /// means it ends at the position.
+    fn render_text(&mut self, text: &str, pos: Pos, scale: f32, align: f32, color: Color);
+
+    /// Measures the width of a line of text rendered at the scale provided.
+    fn measure_text(&self, text: &str, scale: f32) -> f32;
+}
+
+/// A callback that draws a custom component onto a canvas. It receives the
+/// dimensions of the component in the component coordinate space and the
+/// state of the component. The callback is called after the component's
+/// primitives are drawn.
+pub type DrawCallback = Box<dyn FnMut(&mut dyn Canvas, [f32; 2], &custom::State) + Send>;
+
 enum CachedSize {
     Vertical(f32),
     Horizontal(f32),
@@ -189,12 +218,15 @@ pub struct Renderer<M, T> {
     rectangle: Option<M>,
     cached_size: Option<CachedSize>,
     icons: IconCache<T>,
+    draw_callbacks: Vec<(String, DrawCallback)>,
 }
 
 struct IconCache<T> {
     game_icon: Option<Icon<T>>,
     split_icons: Vec<Option<Icon<T>>>,
     detailed_timer_icon: Option<Icon<T>>,
+    background_image: Option<BlurredImage<T>>,
+    images: ImageCache<T>,
 }
 
 impl<M, T> Default for Renderer<M, T> {
@@ -216,8 +248,28 @@ impl<M, T> Renderer<M, T> {
                 game_icon: None,
                 split_icons: Vec::new(),
                 detailed_timer_icon: None,
+                background_image: None,
+                images: ImageCache::default(),
             },
             cached_size: None,
+            draw_callbacks: Vec::new(),
+        }
+    }
+
+    /// Sets the callback that draws the custom components with the ID
+    /// provided. Custom components without a draw callback are drawn solely
+    /// based on the primitives in their state.
+    pub fn set_draw_callback<S, F>(&mut self, id: S, callback: F)
+    where
+        S: Into<String>,
+        F: FnMut(&mut dyn Canvas, [f32; 2], &custom::State) + Send + 'static,
+    {
+        let id = id.into();
+        let callback: DrawCallback = Box::new(callback);
+        if let Some((_, c)) = self.draw_callbacks.iter_mut().find(|(i, _)| *i == id) {
+            *c = callback;
+        } else {
+            self.draw_callbacks.push((id, callback));
         }
     }
 
@@ -230,10 +282,46 @@ impl<M, T> Renderer<M, T> {
         resolution: (f32, f32),
         state: &LayoutState,
     ) {
-        match state.direction {
-            LayoutDirection::Vertical => self.render_vertical(backend, resolution, state),
-            LayoutDirection::Horizontal => self.render_horizontal(backend, resolution, state),
+        if state.components.is_empty() {
+            self.render_empty(backend, resolution, state);
+        } else {
+            match state.direction {
+                LayoutDirection::Vertical => self.render_vertical(backend, resolution, state),
+                LayoutDirection::Horizontal => self.render_horizontal(backend, resolution, state),
+            }
         }
+
+        // The images of components that disappeared aren't needed anymore.
+        self.icons
+            .images
+            .remove_unused(|texture| backend.free_texture(texture));
+    }
+
+    /// All of the components may be hidden by their visibility conditions. In
+    /// that case only the background is rendered. The size of the render target
+    /// is kept as is, so that the components show up at their previous size
+    /// once they appear again.
+    fn render_empty<B: Backend<Mesh = M, Texture = T>>(
+        &mut self,
+        backend: &mut B,
+        resolution: (f32, f32),
+        state: &LayoutState,
+    ) {
+        let aspect_ratio = resolution.0 / resolution.1;
+
+        let mut context = RenderContext {
+            backend,
+            transform: Transform::identity(),
+            rectangle: &mut self.rectangle,
+            timer_font: &mut self.timer_font,
+            timer_glyph_cache: &mut self.timer_glyph_cache,
+            text_font: &mut self.text_font,
+            text_glyph_cache: &mut self.text_glyph_cache,
+        };
+
+        context.render_background(&state.background);
+        context.scale_non_uniform_x(aspect_ratio.recip());
+        context.render_background_image(aspect_ratio, state, &mut self.icons.background_image);
     }
 
     fn render_vertical<B: Backend<Mesh = M, Texture = T>>(
@@ -242,7 +330,11 @@ impl<M, T> Renderer<M, T> {
         resolution: (f32, f32),
         state: &LayoutState,
     ) {
-        let total_height = state.components.iter().map(component_height).sum::<f32>();
+        let total_height = state
+            .components
+            .iter()
+            .map(|c| component_height(&c.state))
+            .sum::<f32>();
 
         let cached_total_size = self
             .cached_size
@@ -288,6 +380,10 @@ impl<M, T> Renderer<M, T> {
         // non-uniformly adjusting for the aspect ratio.
         context.scale_non_uniform_x(aspect_ratio.recip());
 
+        // The background image is rendered in Renderer Coordinate Space, so
+        // that its aspect ratio can be kept intact if it is supposed to fit.
+        context.render_background_image(aspect_ratio, state, &mut self.icons.background_image);
+
         // We scale the coordinate space uniformly such that we have the same
         // scaling as the Component Coordinate Space. This also already is the
         // Component Coordinate Space for the component at (0, 0).
@@ -298,9 +394,16 @@ impl<M, T> Renderer<M, T> {
         let width = aspect_ratio * total_height;
 
         for component in &state.components {
-            let height = component_height(component);
+            let height = component_height(&component.state);
             let dim = [width, height];
-            render_component(&mut context, &mut self.icons, component, state, dim);
+            render_component(
+                &mut context,
+                &mut self.icons,
+                &mut self.draw_callbacks,
+                component,
+                state,
+                dim,
+            );
             // We translate the coordinate space to the Component Coordinate
             // Space of the next component by shifting by the height of the
             // current component in the Component Coordinate Space.
@@ -314,7 +417,11 @@ impl<M, T> Renderer<M, T> {
         resolution: (f32, f32),
         state: &LayoutState,
     ) {
-        let total_width = state.components.iter().map(component_width).sum::<f32>();
+        let total_width = state
+            .components
+            .iter()
+            .map(|c| component_width(&c.state))
+            .sum::<f32>();
 
         let cached_total_size = self
             .cached_size
@@ -359,6 +466,10 @@ impl<M, T> Renderer<M, T> {
         // non-uniformly adjusting for the aspect ratio.
         context.scale_non_uniform_x(aspect_ratio.recip());
 
+        // The background image is rendered in Renderer Coordinate Space, so
+        // that its aspect ratio can be kept intact if it is supposed to fit.
+        context.render_background_image(aspect_ratio, state, &mut self.icons.background_image);
+
         // We scale the coordinate space uniformly such that we have the same
         // scaling as the Component Coordinate Space. This also already is the
         // Component Coordinate Space for the component at (0, 0). Since all the
@@ -372,10 +483,17 @@ impl<M, T> Renderer<M, T> {
         let width_scaling = TWO_ROW_HEIGHT * aspect_ratio / total_width;
 
         for component in &state.components {
-            let width = component_width(component) * width_scaling;
+            let width = component_width(&component.state) * width_scaling;
             let height = TWO_ROW_HEIGHT;
             let dim = [width, height];
-            render_component(&mut context, &mut self.icons, component, state, dim);
+            render_component(
+                &mut context,
+                &mut self.icons,
+                &mut self.draw_callbacks,
+                component,
+                state,
+                dim,
+            );
             // We translate the coordinate space to the Component Coordinate
             // Space of the next component by shifting by the width of the
             // current component in the Component Coordinate Space.
@@ -387,11 +505,22 @@ impl<M, T> Renderer<M, T> {
 fn render_component<B: Backend>(
     context: &mut RenderContext<'_, B>,
     icons: &mut IconCache<B::Texture>,
-    component: &ComponentState,
+    draw_callbacks: &mut [(String, DrawCallback)],
+    component: &LayoutComponentState,
     state: &LayoutState,
     dim: [f32; 2],
 ) {
-    match component {
+    // The background image is shown behind the component, including the
+    // component's own background.
+    if let Some(id) = component.background_image_id {
+        let style = &component.background_image_style;
+        let image_change = component.background_image_change.as_deref();
+        if let Some(image) = context.cached_image(&mut icons.images, id, image_change, style.blur) {
+            context.render_image([0.0, 0.0], dim, image, style);
+        }
+    }
+
+    match &component.state {
         ComponentState::BlankSpace(state) => component::blank_space::render(context, dim, state),
         ComponentState::Title(component) => {
             component::title::render(context, dim, component, state, &mut icons.game_icon)
@@ -409,21 +538,42 @@ fn render_component<B: Backend>(
             state,
             &mut icons.detailed_timer_icon,
         ),
+        ComponentState::Clock(component) => {
+            component::clock::render(context, dim, component, state)
+        }
+        ComponentState::Counter(component) => {
+            component::counter::render(context, dim, component, state)
+        }
         ComponentState::CurrentComparison(component) => {
             component::current_comparison::render(context, dim, component, state)
         }
         ComponentState::CurrentPace(component) => {
             component::current_pace::render(context, dim, component, state)
         }
+        ComponentState::Custom(component) => {
+            component::custom::render(context, dim, component, draw_callbacks)
+        }
         ComponentState::Delta(component) => {
             component::delta::render(context, dim, component, state)
         }
+        ComponentState::Image(component) => {
+            component::image::render(context, dim, component, &mut icons.images)
+        }
+        ComponentState::Notes(component) => {
+            component::notes::render(context, dim, component, state)
+        }
         ComponentState::PossibleTimeSave(component) => {
             component::possible_time_save::render(context, dim, component, state)
         }
         ComponentState::PreviousSegment(component) => {
             component::previous_segment::render(context, dim, component, state)
         }
+        ComponentState::RunStatistics(component) => {
+            component::run_statistics::render(context, dim, component, state)
+        }
+        ComponentState::SegmentHistory(component) => {
+            component::segment_history::render(context, dim, component, state)
+        }
         ComponentState::Separator(component) => {
             component::separator::render(context, dim, component, state)
         }
@@ -475,21 +625,79 @@ impl<'b, B: Backend> RenderContext<'b, B> {
     }
 
     fn create_icon(&mut self, image_url: &str) -> Option<Icon<B::Texture>> {
-        if !image_url.starts_with("data:;base64,") {
-            return None;
-        }
+        let image = decode_image(image_url)?;
+        Some(self.create_texture(&image))
+    }
 
-        let url = &image_url["data:;base64,".len()..];
-        let image_data = base64::decode(url).ok()?;
-        let image = image::load_from_memory(&image_data).ok()?.to_rgba();
+    fn create_texture(&mut self, image: &RgbaImage) -> Icon<B::Texture> {
         let texture = self
             .backend
-            .create_texture(image.width(), image.height(), &image);
+            .create_texture(image.width(), image.height(), image);
 
-        Some(Icon {
+        Icon {
             texture,
             aspect_ratio: image.width() as f32 / image.height() as f32,
-        })
+        }
+    }
+
+    /// Updates the image based on the image change and the amount of blur
+    /// provided. The image's texture gets recreated whenever either of them
+    /// changes.
+    fn update_image(
+        &mut self,
+        image: &mut Option<BlurredImage<B::Texture>>,
+        image_change: Option<&str>,
+        blur: f32,
+    ) {
+        if let Some(url) = image_change {
+            if let Some(old_image) = image.take() {
+                self.backend.free_texture(old_image.icon.texture);
+            }
+            if let Some(decoded) = decode_image(url) {
+                *image = Some(BlurredImage {
+                    icon: self.create_texture(&decoded),
+                    image: decoded,
+                    blur: 0.0,
+                });
+            }
+        }
+
+        if let Some(image) = image {
+            if image.blur != blur {
+                let icon = match icon::blur(&image.image, blur) {
+                    Some(blurred) => self.create_texture(&blurred),
+                    None => self.create_texture(&image.image),
+                };
+                let old_icon = mem::replace(&mut image.icon, icon);
+                self.backend.free_texture(old_icon.texture);
+                image.blur = blur;
+            }
+        }
+    }
+
+    /// Looks up the image with the ID provided in the cache, blurred by the
+    /// amount provided. If the image isn't cached with that amount of blur yet,
+    /// it gets created from either the same image cached with a different
+    /// amount of blur or the image change provided.
+    fn cached_image<'i>(
+        &mut self,
+        images: &'i mut ImageCache<B::Texture>,
+        id: usize,
+        image_change: Option<&str>,
+        blur: f32,
+    ) -> Option<&'i BlurredImage<B::Texture>> {
+        if !images.contains(id, blur) {
+            let image = match images.source(id) {
+                Some(image) => image.clone(),
+                None => decode_image(image_change?)?,
+            };
+            let icon = match icon::blur(&image, blur) {
+                Some(blurred) => self.create_texture(&blurred),
+                None => self.create_texture(&image),
+            };
+            images.insert(id, BlurredImage { icon, image, blur });
+        }
+        images.get(id, blur)
     }
 
     fn free_mesh(&mut self, mesh: B::Mesh) {
@@ -514,14 +722,32 @@ impl<'b, B: Backend> RenderContext<'b, B> {
         }
     }
 
-    fn render_icon(
+    fn render_icon(&mut self, pos: Pos, size: Pos, icon: &Icon<B::Texture>) {
+        self.render_texture(pos, size, icon, ImageScaling::Fit, 1.0);
+    }
+
+    fn render_image(
+        &mut self,
+        pos: Pos,
+        size: Pos,
+        image: &BlurredImage<B::Texture>,
+        style: &ImageStyle,
+    ) {
+        self.render_texture(pos, size, &image.icon, style.scaling, style.opacity);
+    }
+
+    fn render_texture(
         &mut self,
         [mut x, mut y]: Pos,
         [mut width, mut height]: Pos,
         icon: &Icon<B::Texture>,
+        scaling: ImageScaling,
+        opacity: f32,
     ) {
-        let box_aspect_ratio = width / height;
-        let aspect_ratio_diff = box_aspect_ratio / icon.aspect_ratio;
+        let aspect_ratio_diff = match scaling {
+            ImageScaling::Stretch => 1.0,
+            ImageScaling::Fit => width / height / icon.aspect_ratio,
+        };
 
         if aspect_ratio_diff > 1.0 {
             let new_width = width / aspect_ratio_diff;
@@ -545,14 +771,31 @@ impl<'b, B: Backend> RenderContext<'b, B> {
             move || backend.create_mesh(&mesh::rectangle())
         });
 
-        self.backend
-            .render_mesh(rectangle, transform, [[1.0; 4]; 4], Some(&icon.texture));
+        self.backend.render_mesh(
+            rectangle,
+            transform,
+            [[1.0, 1.0, 1.0, opacity]; 4],
+            Some(&icon.texture),
+        );
     }
 
     fn render_background(&mut self, background: &Gradient) {
         self.render_rectangle([0.0, 0.0], [1.0, 1.0], background);
     }
 
+    fn render_background_image(
+        &mut self,
+        width: f32,
+        state: &LayoutState,
+        image: &mut Option<BlurredImage<B::Texture>>,
+    ) {
+        let style = &state.background_image_style;
+        self.update_image(image, state.background_image_change.as_deref(), style.blur);
+        if let Some(image) = image {
+            self.render_image([0.0, 0.0], [width, 1.0], image, style);
+        }
+    }
+
     fn render_info_time_component(
         &mut self,
         texts: &[&str],
@@ -792,6 +1035,16 @@ impl<'b, B: Backend> RenderContext<'b, B> {
     }
 }
 
+fn decode_image(image_url: &str) -> Option<RgbaImage> {
+    if !image_url.starts_with("data:;base64,") {
+        return None;
+    }
+
+    let url = &image_url["data:;base64,".len()..];
+    let image_data = base64::decode(url).ok()?;
+    Some(image::load_from_memory(&image_data).ok()?.to_rgba())
+}
+
 fn decode_gradient(gradient: &Gradient) -> Option<[[f32; 4]; 4]> {
     Some(match gradient {
         Gradient::Transparent => return None,
@@ -820,9 +1073,14 @@ fn decode_color(color: &Color) -> [f32; 4] {
 fn component_width(component: &ComponentState) -> f32 {
     match component {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Clock(_) => 8.0,
+        ComponentState::Counter(_) => 6.0,
         ComponentState::CurrentComparison(_) => 6.0,
         ComponentState::CurrentPace(_) => 6.0,
+        ComponentState::Custom(state) => state.width,
         ComponentState::Delta(_) => 6.0,
+        ComponentState::Image(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Notes(_) => 8.0,
         ComponentState::PossibleTimeSave(_) => 6.0,
         ComponentState::PreviousSegment(_) => 6.0,
         ComponentState::SumOfBest(_) => 6.0,
@@ -837,6 +1095,8 @@ fn component_width(component: &ComponentState) -> f32 {
         ComponentState::DetailedTimer(_) => 7.0,
         ComponentState::Timer(_) => 8.25,
         ComponentState::Graph(_) => 7.0,
+        ComponentState::RunStatistics(_) => 8.0,
+        ComponentState::SegmentHistory(_) => 7.0,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
     }
 }
@@ -844,30 +1104,37 @@ fn component_width(component: &ComponentState) -> f32 {
 fn component_height(component: &ComponentState) -> f32 {
     match component {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
-        ComponentState::CurrentComparison(state) => {
+        ComponentState::Clock(state) => component::clock::height(state),
+        ComponentState::Counter(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
             } else {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
-        ComponentState::CurrentPace(state) => {
+        ComponentState::CurrentComparison(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
             } else {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
-        ComponentState::DetailedTimer(_) => 2.5,
-        ComponentState::Delta(state) => {
+        ComponentState::CurrentPace(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
             } else {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
+        ComponentState::Custom(state) => state.height,
+        ComponentState::DetailedTimer(_) => 2.5,
+        ComponentState::Delta(state) => component::delta::height(state),
         ComponentState::Graph(state) => state.height as f32 * PSEUDO_PIXELS,
+        ComponentState::RunStatistics(state) => component::run_statistics::height(state),
+        ComponentState::SegmentHistory(state) => component::segment_history::height(state),
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
+        ComponentState::Image(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Notes(state) => component::notes::height(state),
         ComponentState::PossibleTimeSave(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
@@ -875,13 +1142,7 @@ fn component_height(component: &ComponentState) -> f32 {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
-        ComponentState::PreviousSegment(state) => {
-            if state.display_two_rows {
-                TWO_ROW_HEIGHT
-            } else {
-                DEFAULT_COMPONENT_HEIGHT
-            }
-        }
+        ComponentState::PreviousSegment(state) => component::previous_segment::height(state),
         ComponentState::Splits(state) => {
             state.splits.len() as f32
                 * if state.display_two_rows {
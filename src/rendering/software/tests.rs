@@ -88,11 +88,12 @@ fn score_split() {
 
     let mut state = layout.state(&timer);
     let prev_seg = state.components.pop().unwrap();
-    state.components.pop();
+    let mut timer_component = state.components.pop().unwrap();
     let mut timer_state = timer::Component::new().state(&timer, layout.general_settings());
     timer_state.time = "50346".into();
     timer_state.fraction = "PTS".into();
-    state.components.push(ComponentState::Timer(timer_state));
+    timer_component.state = ComponentState::Timer(timer_state);
+    state.components.push(timer_component);
     state.components.push(prev_seg);
 
     check_dims(&state, [300, 400], 0x9a328136, "score_split");
@@ -122,7 +123,7 @@ fn subsplits_layout() {
     check_dims(
         &layout.state(&timer),
         [300, 800],
-        0x9d1fe04,
+        0x264e6f86,
         "subsplits_layout",
     );
 }
@@ -179,7 +180,7 @@ fn horizontal() {
     let mut timer = Timer::new(run).unwrap();
     let mut layout = Layout::default_layout();
     layout.general_settings_mut().direction = LayoutDirection::Horizontal;
-    match &mut layout.components[1] {
+    match &mut layout.components[1].component {
         Component::Splits(splits) => splits.settings_mut().visual_split_count = 4,
         _ => unreachable!("We wanted to configure the splits"),
     }
@@ -10,6 +10,19 @@ pub struct Attempt {
     started: Option<AtomicDateTime>,
     ended: Option<AtomicDateTime>,
     pause_time: Option<TimeSpan>,
+    notes: String,
+    tags: Vec<String>,
+    reset_reason: Option<ResetReason>,
+}
+
+/// Describes why an attempt got reset. The reason is tied to the segment the
+/// attempt got reset on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResetReason {
+    /// The index of the segment the attempt got reset on.
+    pub segment_index: usize,
+    /// The reason the attempt got reset, like "died to the boss".
+    pub reason: String,
 }
 
 impl Attempt {
@@ -35,6 +48,9 @@ impl Attempt {
             started,
             ended,
             pause_time,
+            notes: String::new(),
+            tags: Vec::new(),
+            reset_reason: None,
         }
     }
 
@@ -87,4 +103,57 @@ impl Attempt {
     pub fn ended(&self) -> Option<AtomicDateTime> {
         self.ended
     }
+
+    /// Accesses the notes the runner wrote about the attempt.
+    #[inline]
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Sets the notes the runner wrote about the attempt.
+    pub fn set_notes<S>(&mut self, notes: S)
+    where
+        S: AsRef<str>,
+    {
+        self.notes.clear();
+        self.notes.push_str(notes.as_ref());
+    }
+
+    /// Accesses the tags the attempt is tagged with, like "new strat" or
+    /// "bad RNG".
+    #[inline]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Checks whether the attempt is tagged with the tag provided.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Tags the attempt with the tag provided. If the attempt is already
+    /// tagged with it, the tag is not added again.
+    pub fn add_tag<S: Into<String>>(&mut self, tag: S) {
+        let tag = tag.into();
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    /// Removes the tag provided from the attempt.
+    pub fn remove_tag(&mut self, tag: &str) {
+        self.tags.retain(|t| t != tag);
+    }
+
+    /// Accesses the reason the attempt got reset. This returns `None` if no
+    /// reason is known.
+    #[inline]
+    pub fn reset_reason(&self) -> Option<&ResetReason> {
+        self.reset_reason.as_ref()
+    }
+
+    /// Sets the reason the attempt got reset.
+    pub fn set_reset_reason(&mut self, reset_reason: Option<ResetReason>) {
+        self.reset_reason = reset_reason;
+    }
 }
@@ -4,15 +4,32 @@
 //! split, the combined segment time might be faster than the sum of the
 //! individual best segments. The Sum of Best Cleaner will point out all
 //! occurrences of this and allows you to delete them individually if any of
-//! them seem wrong.
+//! them seem wrong. Additionally the Outlier Cleaner is provided, which points
+//! out segment times that are unusually fast or slow compared to all the other
+//! segment times of the same segment, as they may have been caused by
+//! mis-splits.
 
+use crate::analysis::consistency::segment_times;
 use crate::analysis::sum_of_segments::{best, track_branch, Prediction};
 use crate::timing::formatter::{Short, TimeFormatter};
 use crate::{Attempt, Run, Segment, TimeSpan, TimingMethod};
 use chrono::Local;
+use ordered_float::OrderedFloat;
 use std::fmt;
 use std::mem::replace;
 
+/// The default threshold for the robust z-score of a segment time, above
+/// which the segment time is considered an outlier.
+const DEFAULT_OUTLIER_THRESHOLD: f64 = 3.5;
+
+/// The minimum amount of segment times a segment needs to have, before any
+/// of them can be considered an outlier.
+const MIN_OUTLIER_SAMPLES: usize = 5;
+
+/// Scales the median absolute deviation so that it is consistent with the
+/// standard deviation of normally distributed segment times.
+const MEDIAN_ABSOLUTE_DEVIATION_SCALE: f64 = 1.4826;
+
 /// A Sum of Best Cleaner allows you to interactively remove potential issues in
 /// the Segment History that lead to an inaccurate Sum of Best. If you skip a
 /// split, whenever you get to the next split, the combined segment time might
@@ -44,6 +61,27 @@ struct IteratingHistoryState {
     skip_count: usize,
 }
 
+/// An Outlier Cleaner allows you to interactively remove segment times from
+/// the Segment History that are unusually fast or slow compared to all the
+/// other segment times of the same segment. Such segment times are often caused
+/// by splitting too early or too late. A segment time is considered an outlier
+/// if its robust z-score, which is based on the median and the median absolute
+/// deviation of all the segment times, exceeds a threshold. The Outlier Cleaner
+/// will point out all of these and allows you to delete them individually if
+/// any of them seem wrong.
+pub struct OutlierCleaner<'r> {
+    run: &'r mut Run,
+    threshold: f64,
+    state: OutlierState,
+}
+
+struct OutlierState {
+    method: TimingMethod,
+    segment_index: usize,
+    outliers: Vec<(i32, TimeSpan)>,
+    median: TimeSpan,
+}
+
 /// Describes a potential clean up that could be applied. You can use the
 /// Display implementation to print out the details of this potential clean up.
 /// A potential clean up can then be turned into an actual clean up in order to
@@ -52,12 +90,17 @@ pub struct PotentialCleanUp<'r> {
     starting_segment: Option<&'r Segment>,
     ending_segment: &'r Segment,
     time_between: TimeSpan,
-    combined_sum_of_best: Option<TimeSpan>,
-    attempt: &'r Attempt,
+    reason: Reason,
+    attempt: Option<&'r Attempt>,
     method: TimingMethod,
     clean_up: CleanUp,
 }
 
+enum Reason {
+    FasterThanCombinedBestSegments(Option<TimeSpan>),
+    Outlier { median: TimeSpan },
+}
+
 /// Describes an actual clean up that is about to be applied.
 pub struct CleanUp {
     ending_index: usize,
@@ -75,39 +118,68 @@ impl<'r> fmt::Display for PotentialCleanUp<'r> {
 
         write!(
             f,
-            "You had a {} segment time of {} between ",
+            "You had a {} segment time of {} ",
             method,
             short.format(self.time_between)
         )?;
 
-        if let Some(starting_segment) = self.starting_segment {
-            write!(f, "{}", starting_segment.name())?;
-        } else {
-            write!(f, "the start of the run")?;
-        }
+        match self.reason {
+            Reason::FasterThanCombinedBestSegments(combined) => {
+                write!(f, "between ")?;
 
-        write!(f, " and {}", self.ending_segment.name())?;
+                if let Some(starting_segment) = self.starting_segment {
+                    write!(f, "{}", starting_segment.name())?;
+                } else {
+                    write!(f, "the start of the run")?;
+                }
 
-        if let Some(combined) = self.combined_sum_of_best {
-            write!(
-                f,
-                ", which is faster than the Combined Best Segments of {}",
-                short.format(combined)
-            )?;
+                write!(f, " and {}", self.ending_segment.name())?;
+
+                if let Some(combined) = combined {
+                    write!(
+                        f,
+                        ", which is faster than the Combined Best Segments of {}",
+                        short.format(combined)
+                    )?;
+                }
+
+                self.fmt_date(f)?;
+            }
+            Reason::Outlier { median } => {
+                write!(f, "for {}", self.ending_segment.name())?;
+
+                self.fmt_date(f)?;
+
+                write!(
+                    f,
+                    ", which is much {} than your median segment time of {}",
+                    if self.time_between < median {
+                        "faster"
+                    } else {
+                        "slower"
+                    },
+                    short.format(median)
+                )?;
+            }
         }
 
-        if let Some(ended) = self.attempt.ended() {
+        write!(
+            f,
+            ". Do you think that this segment time is inaccurate and should be removed?"
+        )
+    }
+}
+
+impl<'r> PotentialCleanUp<'r> {
+    fn fmt_date(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ended) = self.attempt.and_then(Attempt::ended) {
             write!(
                 f,
                 " in a run on {}",
                 ended.time.with_timezone(&Local).format("%F")
             )?;
         }
-
-        write!(
-            f,
-            ". Do you think that this segment time is inaccurate and should be removed?"
-        )
+        Ok(())
     }
 }
 
@@ -237,17 +309,20 @@ fn check_prediction<'a>(
                     ending_segment: run.segment(ending_index),
                     time_between: segment_history_element[method]
                         .expect("Cleanup path is shorter but doesn't have a time"),
-                    combined_sum_of_best: predictions[ending_index + 1].map(|p| {
-                        p.time
-                            - predictions[(starting_index + 1) as usize]
-                                .expect("Start time must not be empty")
-                                .time
-                    }),
-                    attempt: run
-                        .attempt_history()
-                        .iter()
-                        .find(|attempt| attempt.index() == run_index)
-                        .expect("The attempt has to exist"),
+                    reason: Reason::FasterThanCombinedBestSegments(
+                        predictions[ending_index + 1].map(|p| {
+                            p.time
+                                - predictions[(starting_index + 1) as usize]
+                                    .expect("Start time must not be empty")
+                                    .time
+                        }),
+                    ),
+                    attempt: Some(
+                        run.attempt_history()
+                            .iter()
+                            .find(|attempt| attempt.index() == run_index)
+                            .expect("The attempt has to exist"),
+                    ),
                     method,
                     clean_up: CleanUp {
                         ending_index,
@@ -267,3 +342,193 @@ fn next_timing_method(run: &Run, predictions: &mut Vec<Option<Prediction>>, meth
     predictions.resize(segments.len() + 1, None);
     best::calculate(segments, predictions, true, false, method);
 }
+
+/// Calculates the fastest segment time in the Segment History of the segment,
+/// leaving out the combined segment times where the previous segment got
+/// skipped.
+fn best_segment_time(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let previous_segment = segment_index.checked_sub(1).map(|i| &segments[i]);
+
+    segments[segment_index]
+        .segment_history()
+        .iter()
+        .filter(|&&(index, _)| {
+            !catch! {
+                previous_segment?.segment_history().get(index)?[method].is_none()
+            }
+            .unwrap_or(false)
+        })
+        .filter_map(|&(_, time)| time[method])
+        .min()
+}
+
+impl<'r> OutlierCleaner<'r> {
+    /// Creates a new Outlier Cleaner for the provided Run object.
+    pub fn new(run: &'r mut Run) -> Self {
+        Self::with_threshold(run, DEFAULT_OUTLIER_THRESHOLD)
+    }
+
+    /// Creates a new Outlier Cleaner for the provided Run object that uses the
+    /// threshold provided. A segment time is considered an outlier if its
+    /// robust z-score exceeds this threshold. The lower the threshold, the more
+    /// segment times are pointed out.
+    pub fn with_threshold(run: &'r mut Run, threshold: f64) -> Self {
+        let mut cleaner = Self {
+            run,
+            threshold,
+            state: OutlierState {
+                method: TimingMethod::RealTime,
+                segment_index: 0,
+                outliers: Vec::new(),
+                median: TimeSpan::zero(),
+            },
+        };
+        cleaner.find_outliers();
+        cleaner
+    }
+
+    /// Applies a clean up to the Run. The whole segment time is removed from
+    /// the Segment History, as keeping only one of the timing methods would
+    /// turn it into a skipped segment. If the segment time was the best
+    /// segment in any of the timing methods, the best segment time is
+    /// recalculated from the remaining Segment History.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn apply(&mut self, clean_up: CleanUp) {
+        let CleanUp {
+            ending_index,
+            run_index,
+        } = clean_up;
+
+        let removed = self
+            .run
+            .segment(ending_index)
+            .segment_history()
+            .get(run_index);
+
+        if let Some(removed) = removed {
+            self.run
+                .segment_mut(ending_index)
+                .segment_history_mut()
+                .remove(run_index);
+
+            for &method in &TimingMethod::all() {
+                let best = self.run.segment(ending_index).best_segment_time()[method];
+                if removed[method].map_or(false, |removed| best.map_or(true, |b| removed <= b)) {
+                    let best_segment_time =
+                        best_segment_time(self.run.segments(), ending_index, method);
+                    self.run.segment_mut(ending_index).best_segment_time_mut()[method] =
+                        best_segment_time;
+                }
+            }
+        }
+
+        self.run.mark_as_modified();
+    }
+
+    /// Returns the next potential clean up. If there are no more potential
+    /// clean ups, `None` is returned.
+    pub fn next_potential_clean_up(&mut self) -> Option<PotentialCleanUp<'_>> {
+        loop {
+            if self.state.segment_index >= self.run.len() {
+                if self.state.method == TimingMethod::RealTime {
+                    self.state.method = TimingMethod::GameTime;
+                    self.state.segment_index = 0;
+                    self.find_outliers();
+                    continue;
+                }
+                return None;
+            }
+
+            if let Some((run_index, time)) = self.state.outliers.pop() {
+                let segment_index = self.state.segment_index;
+                let run = &*self.run;
+
+                return Some(PotentialCleanUp {
+                    starting_segment: segment_index.checked_sub(1).map(|i| run.segment(i)),
+                    ending_segment: run.segment(segment_index),
+                    time_between: time,
+                    reason: Reason::Outlier {
+                        median: self.state.median,
+                    },
+                    attempt: run
+                        .attempt_history()
+                        .iter()
+                        .find(|attempt| attempt.index() == run_index),
+                    method: self.state.method,
+                    clean_up: CleanUp {
+                        ending_index: segment_index,
+                        run_index,
+                    },
+                });
+            }
+
+            self.state.segment_index += 1;
+            self.find_outliers();
+        }
+    }
+
+    fn find_outliers(&mut self) {
+        let state = &mut self.state;
+        state.outliers.clear();
+
+        if state.segment_index >= self.run.len() {
+            return;
+        }
+
+        state.outliers.extend(segment_times(
+            self.run.segments(),
+            state.segment_index,
+            state.method,
+        ));
+
+        if state.outliers.len() < MIN_OUTLIER_SAMPLES {
+            state.outliers.clear();
+            return;
+        }
+
+        let mut times = state
+            .outliers
+            .iter()
+            .map(|&(_, time)| time.total_seconds())
+            .collect::<Vec<_>>();
+        let median = calculate_median(&mut times);
+        let mut deviations = times;
+        for deviation in &mut deviations {
+            *deviation = (*deviation - median).abs();
+        }
+        let median_absolute_deviation =
+            MEDIAN_ABSOLUTE_DEVIATION_SCALE * calculate_median(&mut deviations);
+
+        // If most of the segment times are the same, there is no meaningful
+        // way to tell which of the other ones are outliers.
+        if median_absolute_deviation == 0.0 {
+            state.outliers.clear();
+            return;
+        }
+
+        let threshold = self.threshold;
+        state.median = TimeSpan::from_seconds(median);
+        state.outliers.retain(|&(_, time)| {
+            let robust_z_score = (time.total_seconds() - median) / median_absolute_deviation;
+            robust_z_score.abs() > threshold
+        });
+
+        // The outliers are popped from the back, so we reverse them to point
+        // them out in the order they were achieved in.
+        state.outliers.reverse();
+    }
+}
+
+fn calculate_median(values: &mut [f64]) -> f64 {
+    values.sort_unstable_by_key(|&v| OrderedFloat(v));
+    let middle = values.len() / 2;
+    if values.len() % 2 == 0 {
+        0.5 * (values[middle - 1] + values[middle])
+    } else {
+        values[middle]
+    }
+}
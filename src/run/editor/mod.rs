@@ -4,7 +4,8 @@
 //! current state of the editor as state objects that can be visualized by any
 //! kind of User Interface.
 
-use super::{ComparisonError, ComparisonResult};
+use super::{Attempt, ComparisonError, ComparisonResult, ResetReason};
+use crate::analysis::pb_history;
 use crate::timing::ParseError as ParseTimeSpanError;
 use crate::{comparison, CachedImageId, Image, Run, Segment, Time, TimeSpan, TimingMethod};
 use odds::slice::rotate_left;
@@ -18,7 +19,7 @@ mod state;
 #[cfg(test)]
 mod tests;
 
-pub use self::cleaning::SumOfBestCleaner;
+pub use self::cleaning::{OutlierCleaner, SumOfBestCleaner};
 pub use self::fuzzy_list::FuzzyList;
 pub use self::segment_row::SegmentRow;
 pub use self::state::{Buttons as ButtonsState, Segment as SegmentState, State};
@@ -61,6 +62,19 @@ quick_error! {
     }
 }
 
+quick_error! {
+    /// Error type for a failed import of an earlier Personal Best.
+    #[derive(PartialEq, Debug)]
+    pub enum ImportError {
+        /// The attempt is not one of the Personal Bests in the Attempt History.
+        NotAPersonalBest {}
+        /// Name was invalid.
+        InvalidName(err: ComparisonError) {
+            from()
+        }
+    }
+}
+
 /// The Run Editor allows modifying Runs while ensuring that all the different
 /// invariants of the Run objects are upheld no matter what kind of operations
 /// are being applied to the Run. It provides the current state of the editor as
@@ -260,6 +274,57 @@ impl Editor {
         Ok(())
     }
 
+    /// Accesses the history of all the runs that have been attempted.
+    pub fn attempt_history(&self) -> &[Attempt] {
+        self.run.attempt_history()
+    }
+
+    /// Sets the notes of the attempt with the index provided. If there is no
+    /// attempt with that index, nothing happens.
+    pub fn set_attempt_notes<S>(&mut self, index: i32, notes: S)
+    where
+        S: AsRef<str>,
+    {
+        if let Some(attempt) = self.run.attempt_mut(index) {
+            attempt.set_notes(notes);
+            self.raise_run_edited();
+        }
+    }
+
+    /// Tags the attempt with the index provided. If there is no attempt with
+    /// that index, nothing happens.
+    pub fn add_attempt_tag<S: Into<String>>(&mut self, index: i32, tag: S) {
+        if let Some(attempt) = self.run.attempt_mut(index) {
+            attempt.add_tag(tag);
+            self.raise_run_edited();
+        }
+    }
+
+    /// Removes the tag from the attempt with the index provided. If there is
+    /// no attempt with that index, nothing happens.
+    pub fn remove_attempt_tag(&mut self, index: i32, tag: &str) {
+        if let Some(attempt) = self.run.attempt_mut(index) {
+            attempt.remove_tag(tag);
+            self.raise_run_edited();
+        }
+    }
+
+    /// Sets the reason the attempt with the index provided got reset. If
+    /// there is no attempt with that index or the reason refers to a segment
+    /// that doesn't exist, nothing happens.
+    pub fn set_attempt_reset_reason(&mut self, index: i32, reset_reason: Option<ResetReason>) {
+        if reset_reason
+            .as_ref()
+            .map_or(false, |r| r.segment_index >= self.run.len())
+        {
+            return;
+        }
+        if let Some(attempt) = self.run.attempt_mut(index) {
+            attempt.set_reset_reason(reset_reason);
+            self.raise_run_edited();
+        }
+    }
+
     /// Accesses the game's icon.
     pub fn game_icon(&self) -> &Image {
         self.run.game_icon()
@@ -376,6 +441,24 @@ impl Editor {
         self.raise_run_edited();
     }
 
+    /// Updates the segment indices of the reset reasons after the segments got
+    /// changed. If the segment of a reset reason doesn't exist anymore, the
+    /// function returns `None` and the reset reason is cleared.
+    fn remap_reset_reasons<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize) -> Option<usize>,
+    {
+        for attempt in self.run.attempt_history_mut() {
+            if let Some(reset_reason) = attempt.reset_reason() {
+                let reset_reason = f(reset_reason.segment_index).map(|segment_index| ResetReason {
+                    segment_index,
+                    reason: reset_reason.reason.clone(),
+                });
+                attempt.set_reset_reason(reset_reason);
+            }
+        }
+    }
+
     fn update_segment_list(&mut self) {
         let method = self.selected_method;
         let mut previous_time = Some(TimeSpan::zero());
@@ -423,6 +506,7 @@ impl Editor {
             segment.segment_history_mut().insert(x, Default::default());
         }
         self.run.segments_mut().insert(active_segment, segment);
+        self.remap_reset_reasons(|i| Some(if i >= active_segment { i + 1 } else { i }));
 
         self.select_only(active_segment);
 
@@ -448,6 +532,7 @@ impl Editor {
             segment.segment_history_mut().insert(x, Default::default());
         }
         self.run.segments_mut().insert(next_segment, segment);
+        self.remap_reset_reasons(|i| Some(if i >= next_segment { i + 1 } else { i }));
 
         self.select_only(next_segment);
 
@@ -547,6 +632,18 @@ impl Editor {
                 let segment_index = i - removed;
                 self.fix_after_deletion(segment_index);
                 self.run.segments_mut().remove(segment_index);
+                // Attempts reset on the removed segment are now reset on the
+                // segment it got combined with.
+                let len = self.run.len();
+                self.remap_reset_reasons(|i| {
+                    if i > segment_index {
+                        Some(i - 1)
+                    } else if i < segment_index || segment_index < len {
+                        Some(i)
+                    } else {
+                        None
+                    }
+                });
                 removed += 1;
             }
         }
@@ -609,6 +706,16 @@ impl Editor {
         }
 
         swap(first, second);
+
+        self.remap_reset_reasons(|i| {
+            Some(if i == index {
+                index + 1
+            } else if i == index + 1 {
+                index
+            } else {
+                i
+            })
+        });
     }
 
     /// Checks if the currently selected segments can be moved up. If any one of
@@ -713,6 +820,38 @@ impl Editor {
         Ok(())
     }
 
+    /// Imports the split times of an earlier Personal Best as a comparison.
+    /// The attempt index refers to one of the attempts in the Attempt History,
+    /// usually one of the Personal Bests that are part of the Personal Best
+    /// progression. The split times are reconstructed from the Segment History.
+    /// The comparison can't be added if the attempt is not a Personal Best of
+    /// either timing method, its name starts with `[Race]` or it already exists.
+    pub fn import_historical_personal_best<S: Into<String>>(
+        &mut self,
+        attempt_index: i32,
+        comparison: S,
+    ) -> Result<(), ImportError> {
+        let is_personal_best = TimingMethod::all().iter().any(|&method| {
+            pb_history::calculate(&self.run, method)
+                .iter()
+                .any(|pb| pb.attempt_index == attempt_index)
+        });
+        if !is_personal_best {
+            return Err(ImportError::NotAPersonalBest);
+        }
+
+        let comparison = comparison.into();
+        self.run.add_custom_comparison(comparison.as_str())?;
+
+        let split_times = pb_history::split_times(&self.run, attempt_index);
+        for (segment, split_time) in self.run.segments_mut().iter_mut().zip(split_times) {
+            *segment.comparison_mut(&comparison) = split_time;
+        }
+
+        self.fix();
+        Ok(())
+    }
+
     /// Removes the chosen custom comparison. You can't remove a Comparison
     /// Generator's Comparison or the Personal Best.
     pub fn remove_comparison(&mut self, comparison: &str) {
@@ -871,6 +1010,16 @@ impl Editor {
     pub fn clean_sum_of_best(&mut self) -> SumOfBestCleaner<'_> {
         SumOfBestCleaner::new(&mut self.run)
     }
+
+    /// Creates an Outlier Cleaner which allows you to interactively remove
+    /// segment times from the segment history that are unusually fast or slow
+    /// compared to all the other segment times of the same segment. These are
+    /// often caused by splitting too early or too late. The Outlier Cleaner
+    /// will point out all of these and allows you to delete them individually
+    /// if any of them seem wrong.
+    pub fn clean_outliers(&mut self) -> OutlierCleaner<'_> {
+        OutlierCleaner::new(&mut self.run)
+    }
 }
 
 fn parse_positive<S>(time: S) -> Result<Option<TimeSpan>, ParseError>
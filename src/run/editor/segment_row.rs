@@ -44,6 +44,20 @@ impl<'a> SegmentRow<'a> {
         self.editor.raise_run_edited();
     }
 
+    /// Accesses the notes of the segment.
+    pub fn notes(&self) -> &str {
+        self.editor.run.segment(self.index).notes()
+    }
+
+    /// Sets the notes of the segment.
+    pub fn set_notes<S>(&mut self, notes: S)
+    where
+        S: AsRef<str>,
+    {
+        self.editor.run.segment_mut(self.index).set_notes(notes);
+        self.editor.raise_run_edited();
+    }
+
     /// Accesses the split time of the segment for the active timing method.
     pub fn split_time(&self) -> Option<TimeSpan> {
         let method = self.editor.selected_method;
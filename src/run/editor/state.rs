@@ -66,6 +66,8 @@ pub struct Segment {
     pub icon_change: Option<String>,
     /// The name of the segment.
     pub name: String,
+    /// The notes of the segment.
+    pub notes: String,
     /// The segment's split time for the active timing method.
     pub split_time: String,
     /// The segment time for the active timing method.
@@ -134,10 +136,11 @@ impl Editor {
             .resize(self.run.len(), CachedImageId::default());
 
         for segment_index in 0..self.run.len() {
-            let (name, split_time, segment_time, best_segment_time, comparison_times);
+            let (name, notes, split_time, segment_time, best_segment_time, comparison_times);
             {
                 let row = SegmentRow::new(segment_index, self);
                 name = row.name().to_string();
+                notes = row.notes().to_string();
                 split_time = formatter.format(row.split_time()).to_string();
                 segment_time = formatter.format(row.segment_time()).to_string();
                 best_segment_time = formatter.format(row.best_segment_time()).to_string();
@@ -162,6 +165,7 @@ impl Editor {
             segments.push(Segment {
                 icon_change,
                 name,
+                notes,
                 split_time,
                 segment_time,
                 best_segment_time,
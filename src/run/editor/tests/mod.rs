@@ -3,6 +3,7 @@ use crate::{Run, Segment};
 
 mod dissociate_run;
 mod mark_as_modified;
+mod outlier_cleaner;
 
 #[test]
 fn new_best_segment() {
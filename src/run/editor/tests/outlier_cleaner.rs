@@ -0,0 +1,134 @@
+use super::super::cleaning::CleanUp;
+use super::Editor;
+use crate::analysis::sum_of_segments::calculate_best;
+use crate::{Run, Segment, Time, TimeSpan, TimingMethod};
+
+fn run_with_segment_times(times: &[f64]) -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Forest"));
+
+    for (index, &time) in (1..).zip(times) {
+        let time = Time::new().with_game_time(Some(TimeSpan::from_seconds(time)));
+        run.add_attempt(time, None, None, None);
+        run.segment_mut(0).segment_history_mut().insert(index, time);
+    }
+
+    let best = times.iter().cloned().fold(times[0], f64::min);
+    run.segment_mut(0)
+        .set_best_segment_time(Time::new().with_game_time(Some(TimeSpan::from_seconds(best))));
+
+    run
+}
+
+#[test]
+fn finds_outliers() {
+    let run = run_with_segment_times(&[10.0, 10.5, 9.8, 10.2, 1.0, 10.1, 30.0]);
+    let mut editor = Editor::new(run).unwrap();
+    let mut cleaner = editor.clean_outliers();
+
+    let potential_clean_up = cleaner.next_potential_clean_up().unwrap();
+    let message = potential_clean_up.to_string();
+    assert!(message.contains("Game Time segment time of 1.00 for Forest"));
+    assert!(message.contains("much faster than your median segment time of 10.10"));
+    let clean_up: CleanUp = potential_clean_up.into();
+    cleaner.apply(clean_up);
+
+    let potential_clean_up = cleaner.next_potential_clean_up().unwrap();
+    assert!(potential_clean_up.to_string().contains("much slower"));
+    let clean_up: CleanUp = potential_clean_up.into();
+    cleaner.apply(clean_up);
+
+    assert!(cleaner.next_potential_clean_up().is_none());
+
+    let history = editor.run().segment(0).segment_history();
+    assert!(history.get(5).is_none());
+    assert!(history.get(7).is_none());
+    assert!(history.get(1).is_some());
+
+    let best_segment_time = editor.run().segment(0).best_segment_time().game_time;
+    assert_eq!(best_segment_time, Some(TimeSpan::from_seconds(9.8)));
+    let sum_of_best = calculate_best(editor.run().segments(), false, true, TimingMethod::GameTime);
+    assert_eq!(sum_of_best, Some(TimeSpan::from_seconds(9.8)));
+}
+
+#[test]
+fn keeps_the_sum_of_best_of_multiple_segments_accurate() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Forest"));
+    run.push_segment(Segment::new("Castle"));
+
+    let forest_times = [10.0, 10.5, 9.8, 10.2, 1.0, 10.1];
+    for (index, &forest_time) in (1..).zip(&forest_times) {
+        let forest_time = TimeSpan::from_seconds(forest_time);
+        let castle_time = TimeSpan::from_seconds(5.0);
+        run.add_attempt(Time::default(), None, None, None);
+        run.segment_mut(0).segment_history_mut().insert(
+            index,
+            Time::new()
+                .with_real_time(Some(forest_time))
+                .with_game_time(Some(forest_time + TimeSpan::from_seconds(1.0))),
+        );
+        run.segment_mut(1).segment_history_mut().insert(
+            index,
+            Time::new()
+                .with_real_time(Some(castle_time))
+                .with_game_time(Some(castle_time)),
+        );
+    }
+    run.segment_mut(0).set_best_segment_time(
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(1.0)))
+            .with_game_time(Some(TimeSpan::from_seconds(2.0))),
+    );
+    run.segment_mut(1).set_best_segment_time(
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(5.0)))
+            .with_game_time(Some(TimeSpan::from_seconds(5.0))),
+    );
+
+    let mut editor = Editor::new(run).unwrap();
+    let mut cleaner = editor.clean_outliers();
+    let potential_clean_up = cleaner.next_potential_clean_up().unwrap();
+    assert!(potential_clean_up
+        .to_string()
+        .contains("Real Time segment time of 1.00 for Forest"));
+    let clean_up: CleanUp = potential_clean_up.into();
+    cleaner.apply(clean_up);
+    assert!(cleaner.next_potential_clean_up().is_none());
+
+    let run = editor.run();
+    assert!(run.segment(0).segment_history().get(5).is_none());
+    assert!(run.segment(1).segment_history().get(5).is_some());
+    assert_eq!(
+        run.segment(0).best_segment_time().real_time,
+        Some(TimeSpan::from_seconds(9.8)),
+    );
+    assert_eq!(
+        run.segment(0).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(10.8)),
+    );
+
+    for &(method, sum_of_best) in &[
+        (TimingMethod::RealTime, 14.8),
+        (TimingMethod::GameTime, 15.8),
+    ] {
+        assert_eq!(
+            calculate_best(run.segments(), false, true, method),
+            Some(TimeSpan::from_seconds(sum_of_best)),
+        );
+    }
+}
+
+#[test]
+fn no_outliers_with_few_segment_times() {
+    let run = run_with_segment_times(&[10.0, 10.5, 1.0]);
+    let mut editor = Editor::new(run).unwrap();
+    assert!(editor.clean_outliers().next_potential_clean_up().is_none());
+}
+
+#[test]
+fn no_outliers_for_consistent_segment_times() {
+    let run = run_with_segment_times(&[10.0, 10.0, 10.0, 10.0, 11.0]);
+    let mut editor = Editor::new(run).unwrap();
+    assert!(editor.clean_outliers().next_potential_clean_up().is_none());
+}
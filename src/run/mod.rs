@@ -26,8 +26,8 @@ mod segment_history;
 #[cfg(test)]
 mod tests;
 
-pub use attempt::Attempt;
-pub use editor::{Editor, RenameError};
+pub use attempt::{Attempt, ResetReason};
+pub use editor::{Editor, ImportError, RenameError};
 pub use run_metadata::RunMetadata;
 pub use segment::Segment;
 pub use segment_history::SegmentHistory;
@@ -262,6 +262,27 @@ impl Run {
         &self.attempt_history
     }
 
+    /// Grants mutable access to the history of all the runs that have been
+    /// attempted. This allows annotating the attempts with notes, tags and
+    /// reset reasons.
+    #[inline]
+    pub fn attempt_history_mut(&mut self) -> &mut [Attempt] {
+        &mut self.attempt_history
+    }
+
+    /// Grants mutable access to the attempt with the index provided. If there
+    /// is no attempt with that index in the Attempt History, `None` is
+    /// returned.
+    pub fn attempt_mut(&mut self, index: i32) -> Option<&mut Attempt> {
+        self.attempt_history.iter_mut().find(|a| a.index() == index)
+    }
+
+    /// Iterates over all the attempts in the Attempt History that are tagged
+    /// with the tag provided.
+    pub fn attempts_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a Attempt> + 'a {
+        self.attempt_history.iter().filter(move |a| a.has_tag(tag))
+    }
+
     /// Accesses the custom comparisons that are stored in this Run. This
     /// includes `Personal Best` but excludes all the other Comparison
     /// Generators.
@@ -1,9 +1,10 @@
 //! Provides the parser for LiveSplit splits files.
 
 use super::super::ComparisonError;
+use crate::run::ResetReason;
 use crate::xml_util::{
-    attribute, attribute_err, end_tag, optional_attribute_err, parse_attributes, parse_base,
-    parse_children, reencode_children, text, text_as_bytes_err, text_err, text_parsed,
+    attribute, attribute_err, end_tag, image, optional_attribute_err, parse_attributes, parse_base,
+    parse_children, reencode_children, text, text_err, text_parsed,
 };
 use crate::{AtomicDateTime, Run, RunMetadata, Segment, Time, TimeSpan};
 use chrono::{DateTime, TimeZone, Utc};
@@ -83,29 +84,6 @@ fn parse_date_time<S: AsRef<str>>(text: S) -> Result<DateTime<Utc>> {
         .map_err(Into::into)
 }
 
-fn image<R, F>(
-    reader: &mut Reader<R>,
-    result: &mut Vec<u8>,
-    image_buf: &mut Vec<u8>,
-    f: F,
-) -> Result<()>
-where
-    R: BufRead,
-    F: FnOnce(&[u8]),
-{
-    text_as_bytes_err(reader, result, |text| {
-        if text.len() >= 216 {
-            image_buf.clear();
-            if base64::decode_config_buf(&text[212..], base64::STANDARD, image_buf).is_ok() {
-                f(&image_buf[2..image_buf.len() - 1]);
-                return Ok(());
-            }
-        }
-        f(&[]);
-        Ok(())
-    })
-}
-
 fn time_span<R, F>(reader: &mut Reader<R>, buf: &mut Vec<u8>, f: F) -> Result<()>
 where
     R: BufRead,
@@ -248,6 +226,8 @@ fn parse_segment<R: BufRead>(
             text(reader, tag.into_buf(), |t| segment.set_name(t))
         } else if tag.name() == b"Icon" {
             image(reader, tag.into_buf(), buf2, |i| segment.set_icon(i))
+        } else if tag.name() == b"Notes" {
+            text(reader, tag.into_buf(), |t| segment.set_notes(t))
         } else if tag.name() == b"SplitTimes" {
             if version >= Version(1, 3, 0, 0) {
                 parse_children(reader, tag.into_buf(), |reader, tag| {
@@ -384,6 +364,9 @@ fn parse_attempt_history<R: BufRead>(
             }))?;
 
             let index = index.ok_or(Error::Xml(XmlError::AttributeNotFound))?;
+            let mut notes = String::new();
+            let mut tags = Vec::new();
+            let mut reset_reason = None;
 
             parse_children(reader, tag.into_buf(), |reader, tag| {
                 if tag.name() == b"RealTime" {
@@ -398,6 +381,28 @@ fn parse_attempt_history<R: BufRead>(
                     time_span_opt(reader, tag.into_buf(), |t| {
                         pause_time = t;
                     })
+                } else if tag.name() == b"Notes" {
+                    text(reader, tag.into_buf(), |t| notes = t.into_owned())
+                } else if tag.name() == b"Tags" {
+                    parse_children(reader, tag.into_buf(), |reader, tag| {
+                        if tag.name() == b"Tag" {
+                            text(reader, tag.into_buf(), |t| tags.push(t.into_owned()))
+                        } else {
+                            end_tag(reader, tag.into_buf())
+                        }
+                    })
+                } else if tag.name() == b"ResetReason" {
+                    let mut segment_index = 0;
+                    type_hint(attribute_err(&tag, b"segment", |t| {
+                        segment_index = t.parse()?;
+                        Ok(())
+                    }))?;
+                    text(reader, tag.into_buf(), |t| {
+                        reset_reason = Some(ResetReason {
+                            segment_index,
+                            reason: t.into_owned(),
+                        });
+                    })
                 } else {
                     end_tag(reader, tag.into_buf())
                 }
@@ -414,6 +419,14 @@ fn parse_attempt_history<R: BufRead>(
 
             run.add_attempt_with_index(time, index, started, ended, pause_time);
 
+            if let Some(attempt) = run.attempt_history_mut().last_mut() {
+                attempt.set_notes(notes);
+                for tag in tags {
+                    attempt.add_tag(tag);
+                }
+                attempt.set_reset_reason(reset_reason);
+            }
+
             Ok(())
         })
     } else {
@@ -0,0 +1,218 @@
+//! The History Saver exports the Attempt History and the Segment History of a
+//! Run as a table that is suitable for external analysis. The table has a row
+//! for each segment that each attempt made it to. It can be saved either as CSV
+//! or as JSON Lines, where each line is a JSON object describing a single row.
+//!
+//! All the times are stored in seconds, while the dates are stored in the
+//! RFC 3339 format. Missing values are left empty in CSV and are `null` in
+//! JSON Lines.
+//!
+//! # Examples
+//!
+//! Exporting the history of a Run as a CSV file.
+//!
+//! ```no_run
+//! use livesplit_core::run::saver::history;
+//! use livesplit_core::{Run, Segment};
+//! use std::fs::File;
+//! use std::io::BufWriter;
+//!
+//! // Create a run object that we can use.
+//! let mut run = Run::new();
+//! run.push_segment(Segment::new("Cap Kingdom"));
+//!
+//! // Create the file.
+//! let file = File::create("path/to/history.csv");
+//! let writer = BufWriter::new(file.expect("Failed creating the file"));
+//!
+//! // Export the history as CSV.
+//! history::save_csv(&run, writer).expect("Couldn't export the history");
+//! ```
+
+use crate::analysis::{attempts, consistency, pb_history};
+use crate::{Run, TimeSpan, TimingMethod};
+use serde_json::Error as JsonError;
+use std::collections::HashSet;
+use std::io::{self, Write};
+use std::result::Result as StdResult;
+
+quick_error! {
+    #[derive(Debug)]
+    /// The Error type for histories that couldn't be saved by the History
+    /// Saver.
+    pub enum Error {
+        /// Failed writing the table.
+        Io(err: io::Error) {
+            from()
+        }
+        /// Failed writing a row as JSON.
+        Json(err: JsonError) {
+            from()
+        }
+    }
+}
+
+/// The Result type for the History Saver.
+pub type Result<T> = StdResult<T, Error>;
+
+/// A single row of the exported table. It describes the times of a single
+/// attempt on a single segment.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Row {
+    /// The index of the attempt.
+    pub attempt_index: i32,
+    /// The point in time the attempt started at.
+    pub started: Option<String>,
+    /// The point in time the attempt ended at.
+    pub ended: Option<String>,
+    /// The index of the segment.
+    pub segment_index: usize,
+    /// The name of the segment.
+    pub segment_name: String,
+    /// The Real Time segment time in seconds. This is empty if the segment got
+    /// skipped or the segment before it got skipped.
+    pub real_time_segment: Option<f64>,
+    /// The Real Time split time in seconds. This is empty if the segment got
+    /// skipped.
+    pub real_time_split: Option<f64>,
+    /// The Game Time segment time in seconds. This is empty if the segment got
+    /// skipped or the segment before it got skipped.
+    pub game_time_segment: Option<f64>,
+    /// The Game Time split time in seconds. This is empty if the segment got
+    /// skipped.
+    pub game_time_split: Option<f64>,
+    /// Whether the Real Time segment time was a new best segment at the time it
+    /// was achieved.
+    pub real_time_gold: bool,
+    /// Whether the Game Time segment time was a new best segment at the time it
+    /// was achieved.
+    pub game_time_gold: bool,
+    /// Whether the attempt was a new Personal Best for Real Time at the time
+    /// it was achieved.
+    pub real_time_pb: bool,
+    /// Whether the attempt was a new Personal Best for Game Time at the time
+    /// it was achieved.
+    pub game_time_pb: bool,
+}
+
+const HEADER: [&str; 13] = [
+    "attempt_index",
+    "started",
+    "ended",
+    "segment_index",
+    "segment_name",
+    "real_time_segment",
+    "real_time_split",
+    "game_time_segment",
+    "game_time_split",
+    "real_time_gold",
+    "game_time_gold",
+    "real_time_pb",
+    "game_time_pb",
+];
+
+/// Builds the table that describes the history of the Run provided. The rows
+/// are ordered by the attempts and then by the segments.
+pub fn rows(run: &Run) -> Vec<Row> {
+    let real_time_golds = consistency::golds(run.segments(), TimingMethod::RealTime);
+    let game_time_golds = consistency::golds(run.segments(), TimingMethod::GameTime);
+    let real_time_pbs = personal_bests(run, TimingMethod::RealTime);
+    let game_time_pbs = personal_bests(run, TimingMethod::GameTime);
+
+    let mut rows = Vec::new();
+
+    for attempt in attempts::query(run, Default::default()) {
+        let index = attempt.index();
+        let started = attempt.started().map(|d| d.time.to_rfc3339());
+        let ended = attempt.ended().map(|d| d.time.to_rfc3339());
+
+        for (segment_index, (segment, times)) in run
+            .segments()
+            .iter()
+            .zip(attempt.segments())
+            .take(attempt.reached_segments())
+            .enumerate()
+        {
+            rows.push(Row {
+                attempt_index: index,
+                started: started.clone(),
+                ended: ended.clone(),
+                segment_index,
+                segment_name: segment.name().to_owned(),
+                real_time_segment: seconds(times.segment_time.real_time),
+                real_time_split: seconds(times.split_time.real_time),
+                game_time_segment: seconds(times.segment_time.game_time),
+                game_time_split: seconds(times.split_time.game_time),
+                real_time_gold: real_time_golds.contains(&(segment_index, index)),
+                game_time_gold: game_time_golds.contains(&(segment_index, index)),
+                real_time_pb: real_time_pbs.contains(&index),
+                game_time_pb: game_time_pbs.contains(&index),
+            });
+        }
+    }
+
+    rows
+}
+
+/// Saves the history of the Run provided as CSV. The first line is a header
+/// with the names of the columns.
+pub fn save_csv<W: Write>(run: &Run, mut writer: W) -> Result<()> {
+    writeln!(writer, "{}", HEADER.join(","))?;
+
+    for row in rows(run) {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            row.attempt_index,
+            row.started.unwrap_or_default(),
+            row.ended.unwrap_or_default(),
+            row.segment_index,
+            escape(&row.segment_name),
+            optional(row.real_time_segment),
+            optional(row.real_time_split),
+            optional(row.game_time_segment),
+            optional(row.game_time_split),
+            row.real_time_gold,
+            row.game_time_gold,
+            row.real_time_pb,
+            row.game_time_pb,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Saves the history of the Run provided as JSON Lines. Each line is a JSON
+/// object that describes a single row.
+pub fn save_json_lines<W: Write>(run: &Run, mut writer: W) -> Result<()> {
+    for row in rows(run) {
+        serde_json::to_writer(&mut writer, &row)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Collects the attempt indices of all the attempts that were a Personal Best
+/// at the time they were achieved.
+fn personal_bests(run: &Run, method: TimingMethod) -> HashSet<i32> {
+    pb_history::calculate(run, method)
+        .into_iter()
+        .map(|pb| pb.attempt_index)
+        .collect()
+}
+
+fn seconds(time: Option<TimeSpan>) -> Option<f64> {
+    time.map(|t| t.total_seconds())
+}
+
+fn optional(value: Option<f64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+fn escape(text: &str) -> String {
+    if text.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
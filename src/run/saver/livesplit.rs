@@ -25,19 +25,18 @@
 //! ```
 
 use crate::timing::formatter::{Complete, TimeFormatter};
-use crate::{Image, Run, Time, TimeSpan, Timer, TimerPhase};
-use byteorder::{WriteBytesExt, LE};
+use crate::xml_writer::{
+    bool, fmt_buf, image, new_tag, scoped, scoped_iter, text, write_display, write_end,
+    write_start, LSS_IMAGE_HEADER,
+};
+use crate::{Run, Time, TimeSpan, Timer, TimerPhase};
 use chrono::{DateTime, Utc};
-use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
-use quick_xml::{Error as XmlError, Writer};
+use quick_xml::events::{BytesDecl, BytesStart, Event};
+use quick_xml::{Error as XmlError, Result as XmlResult, Writer};
 use std::borrow::Cow;
-use std::fmt::Display;
 use std::io::Write;
-use std::mem::replace;
 use std::result::Result as StdResult;
 
-static LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
-
 quick_error! {
     #[derive(Debug)]
     /// The Error type for splits files that couldn't be saved by the LiveSplit
@@ -53,158 +52,20 @@ quick_error! {
 /// The Result type for the LiveSplit Saver.
 pub type Result<T> = StdResult<T, Error>;
 
-fn new_tag(name: &[u8]) -> BytesStart<'_> {
-    BytesStart::borrowed(name, name.len())
-}
-
-fn write_start<W: Write>(writer: &mut Writer<W>, tag: BytesStart<'_>) -> Result<()> {
-    writer.write_event(Event::Start(tag))?;
-    Ok(())
-}
-
-fn write_end<W: Write>(writer: &mut Writer<W>, tag: &[u8]) -> Result<()> {
-    writer.write_event(Event::End(BytesEnd::borrowed(tag)))?;
-    Ok(())
-}
-
-fn split_tag<'a>(tag: &'a BytesStart<'a>) -> (BytesStart<'a>, BytesEnd<'a>) {
-    (
-        BytesStart::borrowed(&tag, tag.name().len()),
-        BytesEnd::borrowed(tag.name()),
-    )
-}
-
-fn bool(value: bool) -> &'static [u8] {
-    if value {
-        b"True"
-    } else {
-        b"False"
-    }
-}
-
-fn scoped<W, F>(writer: &mut Writer<W>, tag: BytesStart<'_>, is_empty: bool, scope: F) -> Result<()>
-where
-    W: Write,
-    F: FnOnce(&mut Writer<W>) -> Result<()>,
-{
-    if is_empty {
-        writer.write_event(Event::Empty(tag))?;
-    } else {
-        let (start, end) = split_tag(&tag);
-        writer.write_event(Event::Start(start))?;
-        scope(writer)?;
-        writer.write_event(Event::End(end))?;
-    }
-    Ok(())
-}
-
-fn scoped_iter<W, F, I>(
-    writer: &mut Writer<W>,
-    tag: BytesStart<'_>,
-    iter: I,
-    mut scope: F,
-) -> Result<()>
-where
-    W: Write,
-    I: IntoIterator,
-    F: FnMut(&mut Writer<W>, <I as IntoIterator>::Item) -> Result<()>,
-{
-    let mut iter = iter.into_iter().peekable();
-    scoped(writer, tag, iter.peek().is_none(), |writer| {
-        for item in iter {
-            scope(writer, item)?;
-        }
-        Ok(())
-    })
-}
-
-fn text<W: Write, T: AsRef<[u8]>>(
-    writer: &mut Writer<W>,
-    tag: BytesStart<'_>,
-    text: T,
-) -> Result<()> {
-    let text = text.as_ref();
-    scoped(writer, tag, text.is_empty(), |writer| {
-        writer.write_event(Event::Text(BytesText::from_plain(text)))?;
-        Ok(())
-    })
-}
-
-fn vec_as_string<F, R>(vec: &mut Vec<u8>, f: F) -> R
-where
-    F: FnOnce(&mut String) -> R,
-{
-    let taken = replace(vec, Vec::new());
-    let mut string = String::from_utf8(taken).unwrap();
-    let result = f(&mut string);
-    let bytes = string.into_bytes();
-    replace(vec, bytes);
-    result
-}
-
-fn image<W: Write>(
-    writer: &mut Writer<W>,
-    tag: BytesStart<'_>,
-    image: &Image,
-    buf: &mut Vec<u8>,
-    image_buf: &mut Cow<'_, [u8]>,
-) -> Result<()> {
-    let url = image.url();
-    if url.starts_with("data:;base64,") {
-        let src = &url["data:;base64,".len()..];
-        buf.clear();
-        if base64::decode_config_buf(src, base64::STANDARD, buf).is_ok() {
-            let len = buf.len();
-            let image_buf = image_buf.to_mut();
-            image_buf.truncate(LSS_IMAGE_HEADER.len());
-            image_buf.reserve(len + 6);
-            image_buf.write_u32::<LE>(len as u32).unwrap();
-            image_buf.push(0x2);
-            image_buf.append(buf);
-            image_buf.push(0xB);
-            buf.clear();
-            vec_as_string(buf, |s| {
-                base64::encode_config_buf(image_buf, base64::STANDARD, s)
-            });
-            return scoped(writer, tag, buf.is_empty(), |writer| {
-                writer.write_event(Event::CData(BytesText::from_plain(buf)))?;
-                Ok(())
-            });
-        }
-    }
-    writer.write_event(Event::Empty(tag))?;
-    Ok(())
-}
-
 fn fmt_date(date: DateTime<Utc>, buf: &mut Vec<u8>) -> &[u8] {
     fmt_buf(date.format("%m/%d/%Y %T"), buf)
 }
 
-fn fmt_buf<D: Display>(value: D, buf: &mut Vec<u8>) -> &[u8] {
-    buf.clear();
-    write!(buf, "{}", value).unwrap();
-    buf
-}
-
-fn write_display<W: Write, D: Display>(
-    writer: &mut Writer<W>,
-    tag: BytesStart<'_>,
-    value: D,
-    buf: &mut Vec<u8>,
-) -> Result<()> {
-    text(writer, tag, fmt_buf(value, buf))
-}
-
 fn time_span<W: Write>(
     writer: &mut Writer<W>,
     tag: BytesStart<'_>,
     time: TimeSpan,
     buf: &mut Vec<u8>,
-) -> Result<()> {
+) -> XmlResult<()> {
     write_display(writer, tag, Complete.format(time), buf)
 }
 
-fn time_inner<W: Write>(writer: &mut Writer<W>, time: Time, buf: &mut Vec<u8>) -> Result<()> {
+fn time_inner<W: Write>(writer: &mut Writer<W>, time: Time, buf: &mut Vec<u8>) -> XmlResult<()> {
     if let Some(time) = time.real_time {
         time_span(writer, new_tag(b"RealTime"), time, buf)?;
     }
@@ -221,7 +82,7 @@ fn time<W: Write>(
     tag: BytesStart<'_>,
     time: Time,
     buf: &mut Vec<u8>,
-) -> Result<()> {
+) -> XmlResult<()> {
     scoped(
         writer,
         tag,
@@ -319,7 +180,10 @@ pub fn save_run<W: Write>(run: &Run, writer: W) -> Result<()> {
 
             let is_empty = attempt.time().real_time.is_none()
                 && attempt.time().game_time.is_none()
-                && attempt.pause_time().is_none();
+                && attempt.pause_time().is_none()
+                && attempt.notes().is_empty()
+                && attempt.tags().is_empty()
+                && attempt.reset_reason().is_none();
 
             scoped(writer, tag, is_empty, |writer| {
                 time_inner(writer, attempt.time(), buf)?;
@@ -328,6 +192,22 @@ pub fn save_run<W: Write>(run: &Run, writer: W) -> Result<()> {
                     time_span(writer, new_tag(b"PauseTime"), pause_time, buf)?;
                 }
 
+                if !attempt.notes().is_empty() {
+                    text(writer, new_tag(b"Notes"), attempt.notes())?;
+                }
+
+                if !attempt.tags().is_empty() {
+                    scoped_iter(writer, new_tag(b"Tags"), attempt.tags(), |writer, tag| {
+                        text(writer, new_tag(b"Tag"), tag)
+                    })?;
+                }
+
+                if let Some(reset_reason) = attempt.reset_reason() {
+                    let mut tag = new_tag(b"ResetReason");
+                    tag.push_attribute((&b"segment"[..], fmt_buf(reset_reason.segment_index, buf)));
+                    text(writer, tag, &reset_reason.reason)?;
+                }
+
                 Ok(())
             })
         },
@@ -343,6 +223,10 @@ pub fn save_run<W: Write>(run: &Run, writer: W) -> Result<()> {
             text(writer, new_tag(b"Name"), segment.name())?;
             image(writer, new_tag(b"Icon"), segment.icon(), buf, image_buf)?;
 
+            if !segment.notes().is_empty() {
+                text(writer, new_tag(b"Notes"), segment.notes())?;
+            }
+
             scoped_iter(
                 writer,
                 new_tag(b"SplitTimes"),
@@ -1,5 +1,6 @@
 //! The saver module provides all the different ways to save Runs as splits
-//! files.
+//! files. Additionally, the history of a Run can be exported for external
+//! analysis.
 //!
 //! # Examples
 //!
@@ -25,4 +26,5 @@
 //! livesplit::save_run(&run, writer).expect("Couldn't save the splits file");
 //! ```
 
+pub mod history;
 pub mod livesplit;
@@ -4,7 +4,8 @@ use std::collections::HashMap;
 
 /// A Segment describes a point in a speedrun that is suitable for storing a
 /// split time. This stores the name of that segment, an icon, the split times
-/// of different comparisons, and a history of segment times.
+/// of different comparisons, a history of segment times and notes about the
+/// segment.
 ///
 /// # Examples
 ///
@@ -24,6 +25,7 @@ pub struct Segment {
     split_time: Time,
     segment_history: SegmentHistory,
     comparisons: HashMap<String, Time>,
+    notes: String,
 }
 
 impl Segment {
@@ -54,6 +56,24 @@ impl Segment {
         self.name.push_str(name.as_ref());
     }
 
+    /// Accesses the notes of the segment. These are usually route notes that
+    /// describe what to do during the segment. They may be written as plain
+    /// text or as Markdown.
+    #[inline]
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Sets the notes of the segment.
+    #[inline]
+    pub fn set_notes<S>(&mut self, notes: S)
+    where
+        S: AsRef<str>,
+    {
+        self.notes.clear();
+        self.notes.push_str(notes.as_ref());
+    }
+
     /// Accesses the icon of the segment.
     #[inline]
     pub fn icon(&self) -> &Image {
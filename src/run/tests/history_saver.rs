@@ -0,0 +1,59 @@
+use crate::run::saver::history::{rows, save_csv, save_json_lines};
+use crate::tests_helper::{create_timer, run_with_splits, run_with_splits_opt};
+
+#[test]
+fn table() {
+    let mut timer = create_timer(&["A", "B, the second"]);
+
+    run_with_splits(&mut timer, &[5.0, 20.0]);
+    run_with_splits_opt(&mut timer, &[Some(4.0)]);
+    run_with_splits(&mut timer, &[6.0, 18.0]);
+
+    let run = timer.run();
+    let rows = rows(run);
+
+    let summary = rows
+        .iter()
+        .map(|r| {
+            (
+                r.attempt_index,
+                r.segment_index,
+                r.game_time_segment,
+                r.game_time_split,
+                r.game_time_gold,
+                r.game_time_pb,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        [
+            (1, 0, Some(5.0), Some(5.0), true, true),
+            (1, 1, Some(15.0), Some(20.0), true, true),
+            (2, 0, Some(4.0), Some(4.0), true, false),
+            (3, 0, Some(6.0), Some(6.0), false, true),
+            (3, 1, Some(12.0), Some(18.0), true, true),
+        ]
+    );
+    assert!(rows
+        .iter()
+        .all(|r| r.started.is_some() && r.ended.is_some()));
+    assert!(rows[0].real_time_split.is_some());
+
+    let mut csv = Vec::new();
+    save_csv(run, &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 6);
+    assert!(lines[0].starts_with("attempt_index,started,ended,segment_index,segment_name,"));
+    assert!(lines[2].contains(",1,\"B, the second\","));
+    assert!(lines[3].ends_with(",true,false,false"));
+
+    let mut json = Vec::new();
+    save_json_lines(run, &mut json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    let lines = json.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[1].contains(r#""segment_name":"B, the second""#));
+    assert!(lines[1].contains(r#""game_time_segment":15.0"#));
+}
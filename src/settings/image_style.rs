@@ -0,0 +1,33 @@
+/// Describes how an image is scaled to fill the area it is shown in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageScaling {
+    /// Stretch the image to fill the whole area, ignoring its aspect ratio.
+    Stretch,
+    /// Scale the image to be as large as possible while keeping its aspect
+    /// ratio, so that it fits entirely into the area.
+    Fit,
+}
+
+/// Describes how an image, such as a background image, is drawn.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImageStyle {
+    /// The opacity of the image, ranging from 0 (fully transparent) to 1 (fully
+    /// opaque).
+    pub opacity: f32,
+    /// The amount of blur to apply to the image, ranging from 0 (no blur) to 1
+    /// (maximum blur).
+    pub blur: f32,
+    /// How the image is scaled to fill the area it is shown in.
+    pub scaling: ImageScaling,
+}
+
+impl Default for ImageStyle {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            blur: 0.0,
+            scaling: ImageScaling::Stretch,
+        }
+    }
+}
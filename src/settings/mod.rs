@@ -5,6 +5,7 @@ mod alignment;
 mod color;
 mod field;
 mod gradient;
+mod image_style;
 mod semantic_color;
 mod settings_description;
 mod value;
@@ -13,6 +14,7 @@ pub use self::alignment::Alignment;
 pub use self::color::Color;
 pub use self::field::Field;
 pub use self::gradient::{Gradient, ListGradient};
+pub use self::image_style::{ImageScaling, ImageStyle};
 pub use self::semantic_color::SemanticColor;
 pub use self::settings_description::SettingsDescription;
 pub use self::value::{Error as ValueError, Result as ValueResult, Value};
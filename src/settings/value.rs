@@ -1,10 +1,14 @@
 use crate::{
-    component::splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+    component::{
+        graph::GraphMode,
+        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, SubsplitVisibility},
+        timer::TimerMode,
+    },
     hotkey::KeyCode,
-    layout::LayoutDirection,
-    settings::{Alignment, Color, Gradient, ListGradient},
+    layout::{LayoutDirection, PaceCondition, RunningCondition},
+    settings::{Alignment, Color, Gradient, ImageScaling, ListGradient},
     timing::formatter::{Accuracy, DigitsFormat},
-    TimingMethod,
+    Image, TimingMethod,
 };
 use std::result::Result as StdResult;
 
@@ -50,10 +54,28 @@ pub enum Value {
     ColumnUpdateWith(ColumnUpdateWith),
     /// A value describing when to update a column of the Splits Component.
     ColumnUpdateTrigger(ColumnUpdateTrigger),
+    /// A value describing when the subsplits of a section of the Splits
+    /// Component are shown.
+    SubsplitVisibility(SubsplitVisibility),
     /// A value describing what hotkey to press to trigger a certain action.
     Hotkey(Option<KeyCode>),
     /// A value describing the direction of a layout.
     LayoutDirection(LayoutDirection),
+    /// An image. An empty image means that no image is used.
+    Image(Image),
+    /// A value describing how an image is scaled to fill the area it is shown
+    /// in.
+    ImageScaling(ImageScaling),
+    /// A value describing when a component is shown based on whether there is
+    /// an active attempt.
+    RunningCondition(RunningCondition),
+    /// A value describing when a component is shown based on whether the
+    /// current attempt is ahead of or behind the current comparison.
+    PaceCondition(PaceCondition),
+    /// A value describing what a graph visualizes.
+    GraphMode(GraphMode),
+    /// A value describing which time a timer shows.
+    TimerMode(TimerMode),
 }
 
 quick_error! {
@@ -210,6 +232,15 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into a value describing when the subsplits
+    /// of a section of a splits component are shown.
+    pub fn into_subsplit_visibility(self) -> Result<SubsplitVisibility> {
+        match self {
+            Value::SubsplitVisibility(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into a hotkey.
     pub fn into_hotkey(self) -> Result<Option<KeyCode>> {
         match self {
@@ -229,6 +260,55 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    /// Tries to convert the value into an image.
+    pub fn into_image(self) -> Result<Image> {
+        match self {
+            Value::Image(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a value describing how an image is
+    /// scaled.
+    pub fn into_image_scaling(self) -> Result<ImageScaling> {
+        match self {
+            Value::ImageScaling(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a running condition.
+    pub fn into_running_condition(self) -> Result<RunningCondition> {
+        match self {
+            Value::RunningCondition(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a pace condition.
+    pub fn into_pace_condition(self) -> Result<PaceCondition> {
+        match self {
+            Value::PaceCondition(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a graph mode.
+    pub fn into_graph_mode(self) -> Result<GraphMode> {
+        match self {
+            Value::GraphMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a timer mode.
+    pub fn into_timer_mode(self) -> Result<TimerMode> {
+        match self {
+            Value::TimerMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
 }
 
 impl Into<bool> for Value {
@@ -333,6 +413,12 @@ impl Into<ColumnUpdateTrigger> for Value {
     }
 }
 
+impl Into<SubsplitVisibility> for Value {
+    fn into(self) -> SubsplitVisibility {
+        self.into_subsplit_visibility().unwrap()
+    }
+}
+
 impl Into<Option<KeyCode>> for Value {
     fn into(self) -> Option<KeyCode> {
         self.into_hotkey().unwrap()
@@ -344,3 +430,39 @@ impl Into<LayoutDirection> for Value {
         self.into_layout_direction().unwrap()
     }
 }
+
+impl Into<Image> for Value {
+    fn into(self) -> Image {
+        self.into_image().unwrap()
+    }
+}
+
+impl Into<ImageScaling> for Value {
+    fn into(self) -> ImageScaling {
+        self.into_image_scaling().unwrap()
+    }
+}
+
+impl Into<RunningCondition> for Value {
+    fn into(self) -> RunningCondition {
+        self.into_running_condition().unwrap()
+    }
+}
+
+impl Into<PaceCondition> for Value {
+    fn into(self) -> PaceCondition {
+        self.into_pace_condition().unwrap()
+    }
+}
+
+impl Into<GraphMode> for Value {
+    fn into(self) -> GraphMode {
+        self.into_graph_mode().unwrap()
+    }
+}
+
+impl Into<TimerMode> for Value {
+    fn into(self) -> TimerMode {
+        self.into_timer_mode().unwrap()
+    }
+}
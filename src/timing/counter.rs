@@ -0,0 +1,41 @@
+use crate::hotkey::KeyCode;
+
+/// A Counter is a named integer that is kept track of alongside the attempts,
+/// such as the amount of deaths or collectibles. The counters of a Timer are
+/// controlled with the Timer's counter methods, which the Hotkey System uses
+/// for its counter hotkeys.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Counter {
+    /// The name of the counter. It is also used as the name of the Run's
+    /// metadata variable if the counter's value is stored in there.
+    pub name: String,
+    /// The current value of the counter.
+    pub value: i64,
+    /// Specifies whether the counter is reset to 0 whenever the Timer is reset.
+    pub reset_on_timer_reset: bool,
+    /// Specifies whether the value of the counter is stored in the Run's
+    /// metadata variables, so that it persists across sessions when the
+    /// splits are saved.
+    pub store_in_metadata: bool,
+    /// The key to use for incrementing only this counter.
+    pub increment_hotkey: Option<KeyCode>,
+    /// The key to use for decrementing only this counter.
+    pub decrement_hotkey: Option<KeyCode>,
+    /// The key to use for resetting only this counter to 0.
+    pub reset_hotkey: Option<KeyCode>,
+}
+
+impl Counter {
+    /// Creates a new Counter with the name provided that starts at 0.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            value: 0,
+            reset_on_timer_reset: false,
+            store_in_metadata: false,
+            increment_hotkey: None,
+            decrement_hotkey: None,
+            reset_hotkey: None,
+        }
+    }
+}
@@ -186,8 +186,32 @@ impl Display for FractionInner {
     }
 }
 
+/// Rounds the time provided up to the accuracy provided. Countdowns use this so
+/// that they only show zero once the time actually ran out, instead of as soon
+/// as less than the smallest unit shown is left.
+pub fn round_up(time: TimeSpan, accuracy: Accuracy) -> TimeSpan {
+    let units_per_second = match accuracy {
+        Accuracy::Seconds => 1.0,
+        Accuracy::Tenths => 10.0,
+        Accuracy::Hundredths => 100.0,
+        Accuracy::Milliseconds => 1000.0,
+    };
+    TimeSpan::from_seconds((time.total_seconds() * units_per_second).ceil() / units_per_second)
+}
+
 #[test]
 fn test() {
     let time = "4:20.999999".parse::<TimeSpan>().unwrap();
     assert_eq!(Fraction::new().format(Some(time)).to_string(), ".99");
 }
+
+#[test]
+fn rounding_up() {
+    let time = TimeSpan::from_seconds(59.991);
+    let time = round_up(time, Accuracy::Hundredths);
+    assert_eq!(Time::new().format(time).to_string(), "1:00");
+    assert_eq!(Fraction::new().format(time).to_string(), ".00");
+
+    let time = round_up(TimeSpan::from_seconds(0.4), Accuracy::Seconds);
+    assert_eq!(Time::new().format(time).to_string(), "1");
+}
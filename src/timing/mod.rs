@@ -2,6 +2,7 @@
 //! measuring them.
 
 mod atomic_date_time;
+mod counter;
 pub mod formatter;
 mod time;
 mod time_span;
@@ -11,6 +12,7 @@ mod timer_phase;
 mod timing_method;
 
 pub use self::atomic_date_time::AtomicDateTime;
+pub use self::counter::Counter;
 pub use self::time::{GameTime, RealTime, Time};
 pub use self::time_span::{ParseError, TimeSpan};
 pub use self::time_stamp::TimeStamp;
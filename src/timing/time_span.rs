@@ -123,8 +123,18 @@ impl SubAssign for TimeSpan {
 }
 
 use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
 use std::fmt;
 
+impl Serialize for TimeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&self.total_seconds())
+    }
+}
+
 impl<'de> Deserialize<'de> for TimeSpan {
     fn deserialize<D>(deserializer: D) -> Result<TimeSpan, D::Error>
     where
@@ -1,6 +1,8 @@
 use crate::comparison::personal_best;
 use crate::TimerPhase::*;
-use crate::{AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp, TimerPhase, TimingMethod};
+use crate::{
+    AtomicDateTime, Counter, Run, Segment, Time, TimeSpan, TimeStamp, TimerPhase, TimingMethod,
+};
 use parking_lot::RwLock;
 use std::mem;
 use std::sync::Arc;
@@ -57,6 +59,7 @@ pub struct Timer {
     is_game_time_paused: bool,
     game_time_pause_time: Option<TimeSpan>,
     loading_times: Option<TimeSpan>,
+    counters: Vec<Counter>,
 }
 
 /// A Shared Timer is a wrapper around the Timer that can be shared across
@@ -101,6 +104,7 @@ impl Timer {
             is_game_time_paused: false,
             game_time_pause_time: None,
             loading_times: None,
+            counters: Vec::new(),
         })
     }
 
@@ -137,7 +141,17 @@ impl Timer {
 
         run.regenerate_comparisons();
 
-        Ok(mem::replace(&mut self.run, run))
+        let run = mem::replace(&mut self.run, run);
+
+        // The counters that are stored in the metadata belong to the Run, so
+        // they need to be loaded from the new one.
+        for index in 0..self.counters.len() {
+            if self.counters[index].store_in_metadata {
+                self.counters[index].value = self.counter_value_in_metadata(index);
+            }
+        }
+
+        Ok(run)
     }
 
     /// Sets the Run object used by the Timer with the Run object provided. If
@@ -386,6 +400,12 @@ impl Timer {
 
         // FIXME: OnReset
 
+        for index in 0..self.counters.len() {
+            if self.counters[index].reset_on_timer_reset {
+                self.set_counter_value_by_index(index, 0);
+            }
+        }
+
         self.run.fix_splits();
         self.run.regenerate_comparisons();
     }
@@ -507,6 +527,16 @@ impl Timer {
         }
     }
 
+    /// Returns the moment the current attempt ended. This is only available
+    /// while the Timer is in the `Ended` phase.
+    pub fn attempt_ended(&self) -> Option<AtomicDateTime> {
+        if self.phase == Ended {
+            self.attempt_ended
+        } else {
+            None
+        }
+    }
+
     /// Returns the total amount of time the current attempt has been paused
     /// for. None is returned if there have not been any pauses.
     pub fn get_pause_time(&self) -> Option<TimeSpan> {
@@ -597,6 +627,128 @@ impl Timer {
         }
     }
 
+    /// Accesses all the counters of the Timer.
+    #[inline]
+    pub fn counters(&self) -> &[Counter] {
+        &self.counters
+    }
+
+    /// Accesses the counter with the name provided, if there is one.
+    pub fn counter(&self, name: &str) -> Option<&Counter> {
+        self.counters.iter().find(|c| c.name == name)
+    }
+
+    /// Adds a counter to the Timer. If there already is a counter with the
+    /// same name, it is replaced, but its value is kept. If the counter's
+    /// value is stored in the Run's metadata variables, the value is loaded
+    /// from there instead.
+    pub fn add_counter(&mut self, mut counter: Counter) {
+        let index = match self.counters.iter().position(|c| c.name == counter.name) {
+            Some(index) => {
+                counter.value = self.counters[index].value;
+                self.counters[index] = counter;
+                index
+            }
+            None => {
+                self.counters.push(counter);
+                self.counters.len() - 1
+            }
+        };
+
+        if self.counters[index].store_in_metadata {
+            self.counters[index].value = self.counter_value_in_metadata(index);
+        }
+    }
+
+    /// Removes the counter with the name provided. The value stored in the
+    /// Run's metadata variables is kept.
+    pub fn remove_counter(&mut self, name: &str) {
+        self.counters.retain(|c| c.name != name);
+    }
+
+    /// Sets the value of the counter with the name provided. If there is no
+    /// such counter, nothing happens.
+    pub fn set_counter_value(&mut self, name: &str, value: i64) {
+        if let Some(index) = self.counters.iter().position(|c| c.name == name) {
+            self.set_counter_value_by_index(index, value);
+        }
+    }
+
+    /// Increments all the counters by 1.
+    pub fn increment_counters(&mut self) {
+        for index in 0..self.counters.len() {
+            self.increment_counter_by_index(index);
+        }
+    }
+
+    /// Decrements all the counters by 1.
+    pub fn decrement_counters(&mut self) {
+        for index in 0..self.counters.len() {
+            self.decrement_counter_by_index(index);
+        }
+    }
+
+    /// Resets all the counters to 0.
+    pub fn reset_counters(&mut self) {
+        for index in 0..self.counters.len() {
+            self.set_counter_value_by_index(index, 0);
+        }
+    }
+
+    /// Increments the counter with the name provided by 1. If there is no such
+    /// counter, nothing happens.
+    pub fn increment_counter(&mut self, name: &str) {
+        if let Some(index) = self.counters.iter().position(|c| c.name == name) {
+            self.increment_counter_by_index(index);
+        }
+    }
+
+    /// Decrements the counter with the name provided by 1. If there is no such
+    /// counter, nothing happens.
+    pub fn decrement_counter(&mut self, name: &str) {
+        if let Some(index) = self.counters.iter().position(|c| c.name == name) {
+            self.decrement_counter_by_index(index);
+        }
+    }
+
+    /// Resets the counter with the name provided to 0. If there is no such
+    /// counter, nothing happens.
+    pub fn reset_counter(&mut self, name: &str) {
+        self.set_counter_value(name, 0);
+    }
+
+    fn increment_counter_by_index(&mut self, index: usize) {
+        let value = self.counters[index].value.saturating_add(1);
+        self.set_counter_value_by_index(index, value);
+    }
+
+    fn decrement_counter_by_index(&mut self, index: usize) {
+        let value = self.counters[index].value.saturating_sub(1);
+        self.set_counter_value_by_index(index, value);
+    }
+
+    fn set_counter_value_by_index(&mut self, index: usize, value: i64) {
+        self.counters[index].value = value;
+        if self.counters[index].store_in_metadata && self.counter_value_in_metadata(index) != value
+        {
+            let name = self.counters[index].name.as_str();
+            self.run
+                .metadata_mut()
+                .set_variable(name, value.to_string());
+            self.run.mark_as_modified();
+        }
+    }
+
+    fn counter_value_in_metadata(&self, index: usize) -> i64 {
+        let name = &self.counters[index].name;
+        self.run
+            .metadata()
+            .variables()
+            .find(|&(n, _)| n == name)
+            .and_then(|(_, value)| value.trim().parse().ok())
+            .unwrap_or(0)
+    }
+
     fn update_attempt_history(&mut self) {
         let time = if self.phase == Ended {
             self.current_time()
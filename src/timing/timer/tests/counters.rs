@@ -0,0 +1,107 @@
+use super::{run, timer};
+use crate::{Counter, Timer};
+
+fn stored_value(timer: &Timer, name: &str) -> Option<String> {
+    timer
+        .run()
+        .metadata()
+        .variables()
+        .find(|&(n, _)| n == name)
+        .map(|(_, value)| value.clone())
+}
+
+#[test]
+fn hotkey_actions_apply_to_all_counters() {
+    let mut timer = timer();
+    timer.add_counter(Counter::new("Deaths"));
+    timer.add_counter(Counter::new("Coins"));
+
+    timer.increment_counters();
+    timer.increment_counters();
+    timer.decrement_counters();
+    assert_eq!(timer.counter("Deaths").unwrap().value, 1);
+    assert_eq!(timer.counter("Coins").unwrap().value, 1);
+
+    timer.set_counter_value("Coins", 5);
+    timer.reset_counters();
+    assert_eq!(timer.counter("Deaths").unwrap().value, 0);
+    assert_eq!(timer.counter("Coins").unwrap().value, 0);
+}
+
+#[test]
+fn actions_can_apply_to_a_single_counter() {
+    let mut timer = timer();
+    timer.add_counter(Counter::new("Deaths"));
+    timer.add_counter(Counter::new("Coins"));
+
+    timer.increment_counter("Deaths");
+    timer.increment_counter("Deaths");
+    timer.decrement_counter("Coins");
+    timer.increment_counter("Unknown");
+    assert_eq!(timer.counter("Deaths").unwrap().value, 2);
+    assert_eq!(timer.counter("Coins").unwrap().value, -1);
+
+    timer.reset_counter("Deaths");
+    assert_eq!(timer.counter("Deaths").unwrap().value, 0);
+    assert_eq!(timer.counter("Coins").unwrap().value, -1);
+}
+
+#[test]
+fn adding_existing_counter_keeps_value() {
+    let mut timer = timer();
+    timer.add_counter(Counter::new("Deaths"));
+    timer.set_counter_value("Deaths", 3);
+
+    let mut counter = Counter::new("Deaths");
+    counter.reset_on_timer_reset = true;
+    timer.add_counter(counter);
+
+    assert_eq!(timer.counters().len(), 1);
+    assert_eq!(timer.counter("Deaths").unwrap().value, 3);
+    assert!(timer.counter("Deaths").unwrap().reset_on_timer_reset);
+
+    timer.remove_counter("Deaths");
+    assert!(timer.counter("Deaths").is_none());
+}
+
+#[test]
+fn only_resets_counters_on_timer_reset_if_configured() {
+    let mut timer = timer();
+    let mut deaths = Counter::new("Deaths");
+    deaths.reset_on_timer_reset = true;
+    timer.add_counter(deaths);
+    timer.add_counter(Counter::new("Coins"));
+
+    timer.start();
+    timer.increment_counters();
+    timer.reset(true);
+
+    assert_eq!(timer.counter("Deaths").unwrap().value, 0);
+    assert_eq!(timer.counter("Coins").unwrap().value, 1);
+}
+
+#[test]
+fn stores_counters_in_metadata() {
+    let mut timer = timer();
+    let mut deaths = Counter::new("Deaths");
+    deaths.store_in_metadata = true;
+    timer.add_counter(deaths.clone());
+    timer.add_counter(Counter::new("Coins"));
+    timer.mark_as_unmodified();
+
+    timer.set_counter_value("Coins", 2);
+    assert!(!timer.run().has_been_modified());
+    timer.set_counter_value("Deaths", 7);
+    assert!(timer.run().has_been_modified());
+    assert_eq!(stored_value(&timer, "Deaths").as_deref(), Some("7"));
+    assert_eq!(stored_value(&timer, "Coins"), None);
+
+    let mut stored_run = timer.run().clone();
+    stored_run.metadata_mut().set_variable("Deaths", "12");
+    timer.replace_run(run(), true).unwrap();
+    assert_eq!(timer.counter("Deaths").unwrap().value, 0);
+    assert_eq!(timer.counter("Coins").unwrap().value, 2);
+
+    timer.replace_run(stored_run, true).unwrap();
+    assert_eq!(timer.counter("Deaths").unwrap().value, 12);
+}
@@ -2,6 +2,7 @@ use crate::run::Editor;
 use crate::tests_helper::{run_with_splits, run_with_splits_opt, start_run};
 use crate::{Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod};
 
+mod counters;
 mod mark_as_modified;
 
 fn run() -> Run {
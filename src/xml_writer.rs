@@ -0,0 +1,153 @@
+use crate::Image;
+use byteorder::{WriteBytesExt, LE};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Result, Writer};
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::io::Write;
+use std::mem::replace;
+
+pub static LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
+
+pub fn new_tag(name: &[u8]) -> BytesStart<'_> {
+    BytesStart::borrowed(name, name.len())
+}
+
+pub fn write_start<W: Write>(writer: &mut Writer<W>, tag: BytesStart<'_>) -> Result<()> {
+    writer.write_event(Event::Start(tag))?;
+    Ok(())
+}
+
+pub fn write_end<W: Write>(writer: &mut Writer<W>, tag: &[u8]) -> Result<()> {
+    writer.write_event(Event::End(BytesEnd::borrowed(tag)))?;
+    Ok(())
+}
+
+fn split_tag<'a>(tag: &'a BytesStart<'a>) -> (BytesStart<'a>, BytesEnd<'a>) {
+    (
+        BytesStart::borrowed(&tag, tag.name().len()),
+        BytesEnd::borrowed(tag.name()),
+    )
+}
+
+pub fn bool(value: bool) -> &'static [u8] {
+    if value {
+        b"True"
+    } else {
+        b"False"
+    }
+}
+
+pub fn scoped<W, F>(
+    writer: &mut Writer<W>,
+    tag: BytesStart<'_>,
+    is_empty: bool,
+    scope: F,
+) -> Result<()>
+where
+    W: Write,
+    F: FnOnce(&mut Writer<W>) -> Result<()>,
+{
+    if is_empty {
+        writer.write_event(Event::Empty(tag))?;
+    } else {
+        let (start, end) = split_tag(&tag);
+        writer.write_event(Event::Start(start))?;
+        scope(writer)?;
+        writer.write_event(Event::End(end))?;
+    }
+    Ok(())
+}
+
+pub fn scoped_iter<W, F, I>(
+    writer: &mut Writer<W>,
+    tag: BytesStart<'_>,
+    iter: I,
+    mut scope: F,
+) -> Result<()>
+where
+    W: Write,
+    I: IntoIterator,
+    F: FnMut(&mut Writer<W>, <I as IntoIterator>::Item) -> Result<()>,
+{
+    let mut iter = iter.into_iter().peekable();
+    scoped(writer, tag, iter.peek().is_none(), |writer| {
+        for item in iter {
+            scope(writer, item)?;
+        }
+        Ok(())
+    })
+}
+
+pub fn text<W: Write, T: AsRef<[u8]>>(
+    writer: &mut Writer<W>,
+    tag: BytesStart<'_>,
+    text: T,
+) -> Result<()> {
+    let text = text.as_ref();
+    scoped(writer, tag, text.is_empty(), |writer| {
+        writer.write_event(Event::Text(BytesText::from_plain(text)))?;
+        Ok(())
+    })
+}
+
+fn vec_as_string<F, R>(vec: &mut Vec<u8>, f: F) -> R
+where
+    F: FnOnce(&mut String) -> R,
+{
+    let taken = replace(vec, Vec::new());
+    let mut string = String::from_utf8(taken).unwrap();
+    let result = f(&mut string);
+    let bytes = string.into_bytes();
+    replace(vec, bytes);
+    result
+}
+
+pub fn image<W: Write>(
+    writer: &mut Writer<W>,
+    tag: BytesStart<'_>,
+    image: &Image,
+    buf: &mut Vec<u8>,
+    image_buf: &mut Cow<'_, [u8]>,
+) -> Result<()> {
+    let url = image.url();
+    if url.starts_with("data:;base64,") {
+        let src = &url["data:;base64,".len()..];
+        buf.clear();
+        if base64::decode_config_buf(src, base64::STANDARD, buf).is_ok() {
+            let len = buf.len();
+            let image_buf = image_buf.to_mut();
+            image_buf.truncate(LSS_IMAGE_HEADER.len());
+            image_buf.reserve(len + 6);
+            image_buf.write_u32::<LE>(len as u32).unwrap();
+            image_buf.push(0x2);
+            image_buf.append(buf);
+            image_buf.push(0xB);
+            buf.clear();
+            vec_as_string(buf, |s| {
+                base64::encode_config_buf(image_buf, base64::STANDARD, s)
+            });
+            return scoped(writer, tag, buf.is_empty(), |writer| {
+                writer.write_event(Event::CData(BytesText::from_plain(buf)))?;
+                Ok(())
+            });
+        }
+    }
+    writer.write_event(Event::Empty(tag))?;
+    Ok(())
+}
+
+pub fn fmt_buf<D: Display>(value: D, buf: &mut Vec<u8>) -> &[u8] {
+    buf.clear();
+    write!(buf, "{}", value).unwrap();
+    buf
+}
+
+pub fn write_display<W: Write, D: Display>(
+    writer: &mut Writer<W>,
+    tag: BytesStart<'_>,
+    value: D,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    text(writer, tag, fmt_buf(value, buf))
+}
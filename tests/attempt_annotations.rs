@@ -0,0 +1,138 @@
+use livesplit_core::analysis::attempts::{self, Filter};
+use livesplit_core::run::{parser, saver, ResetReason};
+use livesplit_core::{Run, RunEditor, Segment, Time};
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.add_attempt(Time::default(), None, None, None);
+    run.add_attempt(Time::default(), None, None, None);
+    run
+}
+
+#[test]
+fn annotations_can_be_edited() {
+    let mut editor = RunEditor::new(run()).unwrap();
+
+    editor.set_attempt_notes(1, "New strat & stuff");
+    editor.add_attempt_tag(1, "new strat");
+    editor.add_attempt_tag(1, "bad RNG");
+    editor.add_attempt_tag(1, "new strat");
+    editor.add_attempt_tag(2, "bad RNG");
+    editor.remove_attempt_tag(2, "bad RNG");
+    editor.set_attempt_reset_reason(
+        2,
+        Some(ResetReason {
+            segment_index: 1,
+            reason: String::from("Died to the boss"),
+        }),
+    );
+    editor.set_attempt_reset_reason(
+        1,
+        Some(ResetReason {
+            segment_index: 2,
+            reason: String::from("Doesn't exist"),
+        }),
+    );
+    editor.set_attempt_notes(3, "Doesn't exist");
+
+    let run = editor.close();
+    let first = &run.attempt_history()[0];
+    assert_eq!(first.notes(), "New strat & stuff");
+    assert_eq!(first.tags(), ["new strat", "bad RNG"]);
+    assert!(first.reset_reason().is_none());
+
+    let second = &run.attempt_history()[1];
+    assert!(second.tags().is_empty());
+    assert_eq!(second.reset_reason().unwrap().segment_index, 1);
+
+    let tagged = run
+        .attempts_with_tag("bad RNG")
+        .map(|a| a.index())
+        .collect::<Vec<_>>();
+    assert_eq!(tagged, [1]);
+
+    let filter = Filter {
+        tag: Some(String::from("new strat")),
+        ..Default::default()
+    };
+    assert_eq!(attempts::query(&run, filter).count(), 1);
+}
+
+#[test]
+fn reset_reasons_follow_their_segments() {
+    let mut run = run();
+    run.push_segment(Segment::new("C"));
+    for (attempt, segment_index) in [(1, 1), (2, 2)].iter().cloned() {
+        run.attempt_mut(attempt)
+            .unwrap()
+            .set_reset_reason(Some(ResetReason {
+                segment_index,
+                reason: String::new(),
+            }));
+    }
+    let mut editor = RunEditor::new(run).unwrap();
+    let segment_index = |editor: &RunEditor, attempt: i32| {
+        editor.run().attempt_history()[attempt as usize - 1]
+            .reset_reason()
+            .map(|r| r.segment_index)
+    };
+
+    editor.select_only(0);
+    editor.insert_segment_above();
+    assert_eq!(segment_index(&editor, 1), Some(2));
+    assert_eq!(segment_index(&editor, 2), Some(3));
+
+    editor.select_only(2);
+    editor.move_segments_up();
+    assert_eq!(segment_index(&editor, 1), Some(1));
+    assert_eq!(segment_index(&editor, 2), Some(3));
+
+    editor.select_only(0);
+    editor.remove_segments();
+    assert_eq!(segment_index(&editor, 1), Some(0));
+    assert_eq!(segment_index(&editor, 2), Some(2));
+
+    editor.select_only(2);
+    editor.remove_segments();
+    assert_eq!(segment_index(&editor, 1), Some(0));
+    assert_eq!(segment_index(&editor, 2), None);
+}
+
+#[test]
+fn annotations_are_stored_in_splits_files() {
+    let mut run = run();
+    {
+        let attempt = run.attempt_mut(1).unwrap();
+        attempt.set_notes("A < B");
+        attempt.add_tag("new strat");
+        attempt.add_tag("bad RNG");
+        attempt.set_reset_reason(Some(ResetReason {
+            segment_index: 1,
+            reason: String::from("Died to the boss"),
+        }));
+    }
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(buf.as_slice(), None).unwrap();
+
+    assert_eq!(parsed.attempt_history(), run.attempt_history());
+    assert!(parsed.attempt_history()[1].notes().is_empty());
+}
+
+#[test]
+fn only_tag_elements_are_parsed_as_tags() {
+    let mut run = run();
+    run.attempt_mut(1).unwrap().add_tag("new strat");
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let lss = String::from_utf8(buf)
+        .unwrap()
+        .replace("</Tags>", "<Note>Not a tag</Note></Tags>");
+    let parsed = parser::livesplit::parse(lss.as_bytes(), None).unwrap();
+
+    assert_eq!(parsed.attempt_history()[0].tags(), ["new strat"]);
+}
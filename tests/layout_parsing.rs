@@ -25,6 +25,54 @@ mod parse {
         livesplit("tests/layout_files/subsplits.lsl");
     }
 
+    #[test]
+    fn subsplits_settings() {
+        use livesplit_core::component::splits::SubsplitVisibility;
+        use livesplit_core::layout::Component;
+
+        let layout = livesplit("tests/layout_files/subsplits.lsl");
+        let settings = layout
+            .components
+            .iter()
+            .find_map(|c| match &c.component {
+                Component::Splits(c) => Some(c.settings()),
+                _ => None,
+            })
+            .unwrap();
+
+        assert!(settings.group_subsplits);
+        assert!(settings.indent_subsplits);
+        assert_eq!(
+            settings.subsplit_visibility,
+            SubsplitVisibility::CurrentSection
+        );
+        assert!(!settings.current_section_only);
+        assert!(settings.show_section_headers);
+        assert!(!settings.show_section_header_times);
+        assert_eq!(settings.section_header_text_color, None);
+    }
+
+    #[test]
+    fn image_background() {
+        use livesplit_core::settings::ImageScaling;
+
+        let layout = livesplit("tests/layout_files/image_background.lsl");
+        let settings = layout.general_settings();
+        assert!(!settings.background_image.is_empty());
+        assert_eq!(settings.background_image_style.opacity, 0.5);
+        assert_eq!(settings.background_image_style.blur, 0.25);
+        assert_eq!(
+            settings.background_image_style.scaling,
+            ImageScaling::Stretch
+        );
+    }
+
+    #[test]
+    fn no_image_background() {
+        let layout = livesplit("tests/layout_files/All.lsl");
+        assert!(layout.general_settings().background_image.is_empty());
+    }
+
     #[test]
     fn wsplit() {
         livesplit("tests/layout_files/WSplit.lsl");
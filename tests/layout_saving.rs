@@ -0,0 +1,167 @@
+use livesplit_core::component::{clock, delta, graph, separator, timer};
+use livesplit_core::layout::{
+    parser::parse, saver::save_layout, Component, Layout, RunningCondition,
+};
+use livesplit_core::settings::Color;
+use livesplit_core::{Image, TimeSpan};
+use std::{fs::File, io::BufReader};
+
+fn livesplit(path: &str) -> Layout {
+    parse(BufReader::new(File::open(path).unwrap())).unwrap()
+}
+
+fn save_and_load(layout: &Layout) -> Layout {
+    let mut buf = Vec::new();
+    save_layout(layout, &mut buf).unwrap();
+    parse(&buf[..]).unwrap()
+}
+
+/// Loads the layout the way the original LiveSplit sees it, without the
+/// settings that are only stored for livesplit-core.
+fn save_and_load_as_livesplit(layout: &Layout) -> Layout {
+    let mut buf = Vec::new();
+    save_layout(layout, &mut buf).unwrap();
+    let mut lsl = String::from_utf8(buf).unwrap();
+    while let Some(start) = lsl.find("<LiveSplitCoreSettings>") {
+        let end = lsl.find("</LiveSplitCoreSettings>").unwrap();
+        lsl.replace_range(start..end + "</LiveSplitCoreSettings>".len(), "");
+    }
+    parse(lsl.as_bytes()).unwrap()
+}
+
+fn json(layout: &Layout) -> String {
+    let mut buf = Vec::new();
+    layout.settings().write_json(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn round_trip(path: &str) {
+    let layout = livesplit(path);
+    assert_eq!(json(&save_and_load_as_livesplit(&layout)), json(&layout));
+    assert_eq!(json(&save_and_load(&layout)), json(&layout));
+}
+
+#[test]
+fn all() {
+    round_trip("tests/layout_files/All.lsl");
+}
+
+#[test]
+fn dark() {
+    round_trip("tests/layout_files/dark.lsl");
+}
+
+#[test]
+fn subsplits() {
+    round_trip("tests/layout_files/subsplits.lsl");
+}
+
+#[test]
+fn image_background() {
+    round_trip("tests/layout_files/image_background.lsl");
+}
+
+#[test]
+fn default_layout() {
+    // The colors get rounded to what the file format can represent, but
+    // saving the layout again doesn't change it any further.
+    let layout = save_and_load_as_livesplit(&Layout::default_layout());
+    assert_eq!(json(&save_and_load_as_livesplit(&layout)), json(&layout));
+    assert_eq!(json(&save_and_load(&layout)), json(&layout));
+}
+
+#[test]
+fn embedded_components() {
+    let mut layout = Layout::default_layout();
+    layout.push(clock::Component::with_settings(clock::Settings {
+        comparison_override: Some(String::from("Best Segments & <Average>")),
+        twelve_hour_format: true,
+        ..Default::default()
+    }));
+
+    let loaded = save_and_load(&layout);
+
+    assert_eq!(json(&save_and_load(&loaded)), json(&loaded));
+    match loaded.components.last().map(|c| &c.component) {
+        Some(Component::Clock(component)) => {
+            let settings = component.settings();
+            assert!(settings.twelve_hour_format);
+            assert_eq!(
+                settings.comparison_override.as_deref(),
+                Some("Best Segments & <Average>"),
+            );
+        }
+        _ => panic!("The clock component is missing"),
+    }
+}
+
+#[test]
+fn settings_unknown_to_livesplit() {
+    let mut layout = Layout::new();
+    layout.push(timer::Component::with_settings(timer::Settings {
+        mode: timer::TimerMode::Countdown,
+        countdown_goal: TimeSpan::from_seconds(90.0),
+        time_up_color: Some(Color::hsla(0.0, 1.0, 0.5, 1.0)),
+        ..Default::default()
+    }));
+    layout.push(delta::Component::with_settings(delta::Settings {
+        comparisons: vec![String::from("Best Segments")],
+        ..Default::default()
+    }));
+    layout.push(graph::Component::with_settings(graph::Settings {
+        mode: graph::GraphMode::Histogram,
+        overlay_comparisons: vec![String::from("Average Segments")],
+        histogram_bars: 7,
+        ..Default::default()
+    }));
+    layout.push(separator::Component::new());
+    layout.components[1].visibility.running = RunningCondition::WhileRunning;
+    layout.components[2].background_image = Image::new(b"image");
+    layout.components[2].background_image_style.opacity = 0.5;
+    layout.components[3].visibility.only_on_last_split = true;
+
+    // The general settings get rounded to what the file format can represent.
+    *layout.general_settings_mut() = save_and_load(&layout).general_settings().clone();
+
+    let loaded = save_and_load(&layout);
+    assert_eq!(json(&loaded), json(&layout));
+
+    match &loaded.components[0].component {
+        Component::Timer(component) => {
+            let settings = component.settings();
+            assert_eq!(settings.mode, timer::TimerMode::Countdown);
+            assert_eq!(settings.countdown_goal, TimeSpan::from_seconds(90.0));
+        }
+        _ => panic!("The timer component is missing"),
+    }
+    assert_eq!(
+        loaded.components[1].visibility.running,
+        RunningCondition::WhileRunning,
+    );
+    assert_eq!(loaded.components[2].background_image, Image::new(b"image"));
+    assert!(loaded.components[3].visibility.only_on_last_split);
+
+    // The original LiveSplit only sees the settings it knows about.
+    let loaded = save_and_load_as_livesplit(&layout);
+    match &loaded.components[0].component {
+        Component::Timer(component) => {
+            assert_eq!(component.settings().mode, timer::TimerMode::CountUp)
+        }
+        _ => panic!("The timer component is missing"),
+    }
+    assert_eq!(
+        loaded.components[1].visibility.running,
+        RunningCondition::Always,
+    );
+}
+
+#[test]
+fn malformed_embedded_components_are_errors() {
+    let mut layout = Layout::new();
+    layout.push(clock::Component::new());
+    let mut buf = Vec::new();
+    save_layout(&layout, &mut buf).unwrap();
+    let lsl = String::from_utf8(buf).unwrap().replace("Clock", "Clack");
+
+    assert!(parse(lsl.as_bytes()).is_err());
+}
@@ -0,0 +1,53 @@
+use livesplit_core::component::notes;
+use livesplit_core::run::{parser, saver};
+use livesplit_core::{Run, RunEditor, Segment, Timer};
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("Cap Kingdom"));
+    run.push_segment(Segment::new("Cascade Kingdom"));
+    run
+}
+
+#[test]
+fn notes_are_stored_in_splits_files() {
+    let mut editor = RunEditor::new(run()).unwrap();
+    editor.select_only(1);
+    editor
+        .active_segment()
+        .set_notes("Jump over the wall\n- Skip the moon");
+    let run = editor.close();
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let run = parser::livesplit::parse(buf.as_slice(), None).unwrap();
+
+    assert_eq!(run.segment(0).notes(), "");
+    assert_eq!(
+        run.segment(1).notes(),
+        "Jump over the wall\n- Skip the moon"
+    );
+}
+
+#[test]
+fn notes_component_shows_current_or_next_segment() {
+    let mut run = run();
+    run.segment_mut(0).set_notes("First");
+    run.segment_mut(1).set_notes("Second");
+    let mut timer = Timer::new(run).unwrap();
+
+    let mut component = notes::Component::new();
+    let state = component.state(&timer);
+    assert_eq!(state.segment_name.as_deref(), Some("Cap Kingdom"));
+    assert_eq!(state.notes, "First");
+
+    timer.start();
+    timer.split();
+    assert_eq!(component.state(&timer).notes, "Second");
+
+    component.settings_mut().show_next_segment = true;
+    component.settings_mut().show_segment_name = false;
+    let state = component.state(&timer);
+    assert_eq!(state.segment_name, None);
+    assert_eq!(state.notes, "");
+}